@@ -0,0 +1,46 @@
+// Writes bincode payloads encoded by the generated C++ bindings, which the
+// tests in `protocol/src/binary.rs` decode. Run from this directory after
+// regenerating the bindings:
+//
+//     g++ -std=c++17 -I ../langs/cpp generate.cpp -o /tmp/generate && /tmp/generate
+
+#include <fstream>
+
+#include "plump_message.hpp"
+
+using namespace plump_message;
+
+// The bindings key maps by player name without ordering the names.
+namespace plump_message {
+bool operator<(const PlayerName &a, const PlayerName &b) { return a.value < b.value; }
+} // namespace plump_message
+
+static void write(const char *path, const std::vector<uint8_t> &payload) {
+    std::ofstream file(path, std::ios::binary);
+    file.write(reinterpret_cast<const char *>(payload.data()), payload.size());
+}
+
+int main() {
+    write("rejected.bin",
+          Message{Message::Rejected{Rejection{Rejection::NoSuchCard{7, 3}}}}.bincodeSerialize());
+    write("request_guess.bin", Message{Message::RequestGuess{std::vector<uint64_t>{0, 2}}}.bincodeSerialize());
+    write("card.bin", Message{Message::Rejected{Rejection{Rejection::CardNotInHand{
+                          Card{Card::Suited{2, 12}}}}}}
+                          .bincodeSerialize());
+    write("joker.bin", Message{Message::Rejected{Rejection{Rejection::CardNotInHand{
+                           Card{Card::Joker{Colour{Colour::Black{}}}}}}}}
+                           .bincodeSerialize());
+    write("chat.bin",
+          Message{Message::Chat{PlayerName{"Åsa"}, "Good luck!"}}.bincodeSerialize());
+
+    write("hello.bin", ClientMessage{ClientMessage::Hello{
+                                         1,
+                                         Encoding{Encoding::Binary{}},
+                                         {Capability{Capability::Rejections{}},
+                                          Capability{Capability::LegalActions{}}},
+                                     }}
+                           .bincodeSerialize());
+    write("whisper.bin",
+          ClientMessage{ClientMessage::Chat{PlayerName{"Bob"}, "Lead hearts"}}.bincodeSerialize());
+    return 0;
+}
@@ -5,15 +5,170 @@
 
 namespace plump_message {
 
+    struct Capability {
+
+        struct Rejections {
+            friend bool operator==(const Rejections&, const Rejections&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Rejections bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct LegalActions {
+            friend bool operator==(const LegalActions&, const LegalActions&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static LegalActions bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Rejections, LegalActions> value;
+
+        friend bool operator==(const Capability&, const Capability&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Capability bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct Colour {
+
+        struct Red {
+            friend bool operator==(const Red&, const Red&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Red bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Black {
+            friend bool operator==(const Black&, const Black&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Black bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Red, Black> value;
+
+        friend bool operator==(const Colour&, const Colour&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Colour bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct Card {
-        uint64_t suit;
-        uint64_t value;
+
+        struct Suited {
+            uint64_t suit;
+            uint64_t value;
+
+            friend bool operator==(const Suited&, const Suited&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Suited bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Joker {
+            plump_message::Colour value;
+
+            friend bool operator==(const Joker&, const Joker&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Joker bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Suited, Joker> value;
 
         friend bool operator==(const Card&, const Card&);
         std::vector<uint8_t> bincodeSerialize() const;
         static Card bincodeDeserialize(std::vector<uint8_t>);
     };
 
+    struct Disconnect {
+
+        struct LineTooLong {
+            uint64_t max;
+
+            friend bool operator==(const LineTooLong&, const LineTooLong&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static LineTooLong bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct InvalidUtf8 {
+            friend bool operator==(const InvalidUtf8&, const InvalidUtf8&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static InvalidUtf8 bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Malformed {
+            std::string value;
+
+            friend bool operator==(const Malformed&, const Malformed&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Malformed bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Idle {
+            friend bool operator==(const Idle&, const Idle&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Idle bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NoSeat {
+            friend bool operator==(const NoSeat&, const NoSeat&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NoSeat bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<LineTooLong, InvalidUtf8, Malformed, Idle, NoSeat> value;
+
+        friend bool operator==(const Disconnect&, const Disconnect&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Disconnect bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct Encoding {
+
+        struct Text {
+            friend bool operator==(const Text&, const Text&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Text bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Json {
+            friend bool operator==(const Json&, const Json&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Json bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Binary {
+            friend bool operator==(const Binary&, const Binary&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Binary bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Text, Json, Binary> value;
+
+        friend bool operator==(const Encoding&, const Encoding&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Encoding bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct HandshakeRejection {
+
+        struct UnsupportedVersion {
+            uint32_t requested;
+            uint32_t supported;
+
+            friend bool operator==(const UnsupportedVersion&, const UnsupportedVersion&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static UnsupportedVersion bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Malformed {
+            std::string value;
+
+            friend bool operator==(const Malformed&, const Malformed&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Malformed bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<UnsupportedVersion, Malformed> value;
+
+        friend bool operator==(const HandshakeRejection&, const HandshakeRejection&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static HandshakeRejection bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct PlayerName {
         std::string value;
 
@@ -22,6 +177,54 @@ namespace plump_message {
         static PlayerName bincodeDeserialize(std::vector<uint8_t>);
     };
 
+    struct LobbyPlayer {
+        plump_message::PlayerName name;
+        bool ready;
+
+        friend bool operator==(const LobbyPlayer&, const LobbyPlayer&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static LobbyPlayer bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct NameRejection {
+
+        struct Empty {
+            friend bool operator==(const Empty&, const Empty&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Empty bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct TooLong {
+            uint64_t max;
+
+            friend bool operator==(const TooLong&, const TooLong&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static TooLong bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct InvalidCharacter {
+            char32_t value;
+
+            friend bool operator==(const InvalidCharacter&, const InvalidCharacter&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static InvalidCharacter bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Taken {
+            plump_message::PlayerName value;
+
+            friend bool operator==(const Taken&, const Taken&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Taken bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Empty, TooLong, InvalidCharacter, Taken> value;
+
+        friend bool operator==(const NameRejection&, const NameRejection&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static NameRejection bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct Player {
         plump_message::PlayerName name;
         bool human;
@@ -32,6 +235,15 @@ namespace plump_message {
         static Player bincodeDeserialize(std::vector<uint8_t>);
     };
 
+    struct PublicPlayer {
+        plump_message::PlayerName name;
+        bool human;
+
+        friend bool operator==(const PublicPlayer&, const PublicPlayer&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static PublicPlayer bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct PublicState {
         std::optional<uint64_t> guess;
         uint64_t wins;
@@ -42,8 +254,166 @@ namespace plump_message {
         static PublicState bincodeDeserialize(std::vector<uint8_t>);
     };
 
+    struct Rejection {
+
+        struct NotANumber {
+            std::string value;
+
+            friend bool operator==(const NotANumber&, const NotANumber&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NotANumber bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct GuessTooHigh {
+            uint64_t guess;
+            uint64_t hand_size;
+
+            friend bool operator==(const GuessTooHigh&, const GuessTooHigh&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static GuessTooHigh bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct ForbiddenGuess {
+            uint64_t guess;
+            uint64_t hand_size;
+
+            friend bool operator==(const ForbiddenGuess&, const ForbiddenGuess&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static ForbiddenGuess bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NoSuchCard {
+            uint64_t index;
+            uint64_t hand_size;
+
+            friend bool operator==(const NoSuchCard&, const NoSuchCard&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NoSuchCard bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct MustFollowSuit {
+            uint64_t suit;
+
+            friend bool operator==(const MustFollowSuit&, const MustFollowSuit&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static MustFollowSuit bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NotACard {
+            std::string value;
+
+            friend bool operator==(const NotACard&, const NotACard&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NotACard bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct CardNotInHand {
+            plump_message::Card value;
+
+            friend bool operator==(const CardNotInHand&, const CardNotInHand&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static CardNotInHand bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct ChatTooLong {
+            uint64_t length;
+            uint64_t max;
+
+            friend bool operator==(const ChatTooLong&, const ChatTooLong&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static ChatTooLong bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct UnknownPlayer {
+            plump_message::PlayerName value;
+
+            friend bool operator==(const UnknownPlayer&, const UnknownPlayer&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static UnknownPlayer bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NotHost {
+            plump_message::PlayerName value;
+
+            friend bool operator==(const NotHost&, const NotHost&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NotHost bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NotReady {
+            std::vector<plump_message::PlayerName> value;
+
+            friend bool operator==(const NotReady&, const NotReady&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NotReady bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct TooFewPlayers {
+            uint64_t min;
+
+            friend bool operator==(const TooFewPlayers&, const TooFewPlayers&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static TooFewPlayers bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<NotANumber, GuessTooHigh, ForbiddenGuess, NoSuchCard, MustFollowSuit, NotACard, CardNotInHand, ChatTooLong, UnknownPlayer, NotHost, NotReady, TooFewPlayers> value;
+
+        friend bool operator==(const Rejection&, const Rejection&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Rejection bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct RoundScore {
+        uint64_t guess;
+        uint64_t wins;
+        uint64_t points;
+
+        friend bool operator==(const RoundScore&, const RoundScore&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static RoundScore bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct ScoreSheetRound {
+        uint64_t hand_size;
+        std::vector<plump_message::RoundScore> scores;
+
+        friend bool operator==(const ScoreSheetRound&, const ScoreSheetRound&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static ScoreSheetRound bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct ScoreSheet {
+        std::vector<plump_message::PlayerName> players;
+        std::vector<plump_message::ScoreSheetRound> rounds;
+
+        friend bool operator==(const ScoreSheet&, const ScoreSheet&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static ScoreSheet bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct SeriesScore {
+        plump_message::PlayerName name;
+        uint32_t played;
+        uint32_t won;
+
+        friend bool operator==(const SeriesScore&, const SeriesScore&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static SeriesScore bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+    struct Play {
+        plump_message::PlayerName player;
+        plump_message::Card card;
+
+        friend bool operator==(const Play&, const Play&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static Play bincodeDeserialize(std::vector<uint8_t>);
+    };
+
     struct Trick {
-        std::vector<plump_message::Card> value;
+        std::vector<plump_message::Play> plays;
+        std::optional<uint64_t> led_suit;
+        std::optional<uint64_t> winning;
 
         friend bool operator==(const Trick&, const Trick&);
         std::vector<uint8_t> bincodeSerialize() const;
@@ -57,6 +427,7 @@ namespace plump_message {
             std::vector<plump_message::Card> hand;
             std::vector<uint64_t> guesses;
             uint64_t players;
+            std::optional<std::vector<uint64_t>> legal_guesses;
 
             friend bool operator==(const RequestGuessContext&, const RequestGuessContext&);
             std::vector<uint8_t> bincodeSerialize() const;
@@ -72,7 +443,7 @@ namespace plump_message {
         };
 
         struct Turn {
-            plump_message::Player whose;
+            plump_message::PublicPlayer whose;
 
             friend bool operator==(const Turn&, const Turn&);
             std::vector<uint8_t> bincodeSerialize() const;
@@ -107,7 +478,7 @@ namespace plump_message {
         };
 
         struct Winner {
-            plump_message::Player value;
+            plump_message::PublicPlayer value;
 
             friend bool operator==(const Winner&, const Winner&);
             std::vector<uint8_t> bincodeSerialize() const;
@@ -115,7 +486,7 @@ namespace plump_message {
         };
 
         struct Winners {
-            std::vector<plump_message::Player> players;
+            std::vector<plump_message::PublicPlayer> players;
             std::vector<uint64_t> winner_indices;
 
             friend bool operator==(const Winners&, const Winners&);
@@ -130,7 +501,8 @@ namespace plump_message {
         };
 
         struct PlayRequest {
-            plump_message::Player value;
+            plump_message::Player player;
+            std::optional<std::vector<uint64_t>> valid_cards;
 
             friend bool operator==(const PlayRequest&, const PlayRequest&);
             std::vector<uint8_t> bincodeSerialize() const;
@@ -138,6 +510,8 @@ namespace plump_message {
         };
 
         struct RequestGuess {
+            std::optional<std::vector<uint64_t>> legal_guesses;
+
             friend bool operator==(const RequestGuess&, const RequestGuess&);
             std::vector<uint8_t> bincodeSerialize() const;
             static RequestGuess bincodeDeserialize(std::vector<uint8_t>);
@@ -149,33 +523,3097 @@ namespace plump_message {
             static GameOver bincodeDeserialize(std::vector<uint8_t>);
         };
 
-        std::variant<RequestGuessContext, Guesses, Turn, PlayRequestContext, Trick, Scoreboard, Winner, Winners, RequestPlayerName, PlayRequest, RequestGuess, GameOver> value;
+        struct Rejected {
+            plump_message::Rejection value;
+
+            friend bool operator==(const Rejected&, const Rejected&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Rejected bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct HandshakeAccepted {
+            uint32_t version;
+            plump_message::Encoding encoding;
+            std::vector<plump_message::Capability> capabilities;
+
+            friend bool operator==(const HandshakeAccepted&, const HandshakeAccepted&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static HandshakeAccepted bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct HandshakeRejected {
+            plump_message::HandshakeRejection value;
+
+            friend bool operator==(const HandshakeRejected&, const HandshakeRejected&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static HandshakeRejected bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct ScoreSheet {
+            plump_message::ScoreSheet value;
+
+            friend bool operator==(const ScoreSheet&, const ScoreSheet&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static ScoreSheet bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct RoundStart {
+            uint64_t round;
+            uint64_t rounds;
+            uint64_t hand_size;
+            plump_message::PublicPlayer dealer;
+
+            friend bool operator==(const RoundStart&, const RoundStart&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static RoundStart bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct RoundEnd {
+            uint64_t round;
+            std::vector<plump_message::PublicPlayer> made;
+            std::vector<plump_message::PublicPlayer> missed;
+
+            friend bool operator==(const RoundEnd&, const RoundEnd&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static RoundEnd bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct LastTrick {
+            std::optional<plump_message::Trick> value;
+
+            friend bool operator==(const LastTrick&, const LastTrick&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static LastTrick bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct TrickHistory {
+            std::vector<plump_message::Trick> value;
+
+            friend bool operator==(const TrickHistory&, const TrickHistory&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static TrickHistory bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Chat {
+            plump_message::PlayerName sender;
+            std::string text;
+
+            friend bool operator==(const Chat&, const Chat&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Chat bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Whisper {
+            plump_message::PlayerName sender;
+            plump_message::PlayerName to;
+            std::string text;
+
+            friend bool operator==(const Whisper&, const Whisper&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Whisper bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Disconnected {
+            plump_message::Disconnect value;
+
+            friend bool operator==(const Disconnected&, const Disconnected&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Disconnected bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct NameRejected {
+            plump_message::NameRejection value;
+
+            friend bool operator==(const NameRejected&, const NameRejected&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static NameRejected bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Lobby {
+            std::vector<plump_message::LobbyPlayer> players;
+            uint64_t seats;
+
+            friend bool operator==(const Lobby&, const Lobby&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Lobby bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Series {
+            uint32_t games;
+            std::vector<plump_message::SeriesScore> scores;
+
+            friend bool operator==(const Series&, const Series&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Series bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct RejoinToken {
+            std::string value;
+
+            friend bool operator==(const RejoinToken&, const RejoinToken&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static RejoinToken bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<RequestGuessContext, Guesses, Turn, PlayRequestContext, Trick, Scoreboard, Winner, Winners, RequestPlayerName, PlayRequest, RequestGuess, GameOver, Rejected, HandshakeAccepted, HandshakeRejected, ScoreSheet, RoundStart, RoundEnd, LastTrick, TrickHistory, Chat, Whisper, Disconnected, NameRejected, Lobby, Series, RejoinToken> value;
 
         friend bool operator==(const Message&, const Message&);
         std::vector<uint8_t> bincodeSerialize() const;
         static Message bincodeDeserialize(std::vector<uint8_t>);
     };
 
-} // end of namespace plump_message
+    struct ClientMessage {
+
+        struct Reply {
+            std::string value;
+
+            friend bool operator==(const Reply&, const Reply&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Reply bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Hello {
+            uint32_t version;
+            plump_message::Encoding encoding;
+            std::vector<plump_message::Capability> capabilities;
+
+            friend bool operator==(const Hello&, const Hello&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Hello bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct LastTrick {
+            friend bool operator==(const LastTrick&, const LastTrick&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static LastTrick bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct TrickHistory {
+            friend bool operator==(const TrickHistory&, const TrickHistory&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static TrickHistory bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Chat {
+            std::optional<plump_message::PlayerName> to;
+            std::string text;
+
+            friend bool operator==(const Chat&, const Chat&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Chat bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Mute {
+            plump_message::PlayerName value;
+
+            friend bool operator==(const Mute&, const Mute&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Mute bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Unmute {
+            plump_message::PlayerName value;
+
+            friend bool operator==(const Unmute&, const Unmute&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Unmute bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Ready {
+            bool value;
+
+            friend bool operator==(const Ready&, const Ready&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Ready bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Start {
+            bool bots;
+
+            friend bool operator==(const Start&, const Start&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Start bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        struct Leave {
+            friend bool operator==(const Leave&, const Leave&);
+            std::vector<uint8_t> bincodeSerialize() const;
+            static Leave bincodeDeserialize(std::vector<uint8_t>);
+        };
+
+        std::variant<Reply, Hello, LastTrick, TrickHistory, Chat, Mute, Unmute, Ready, Start, Leave> value;
+
+        friend bool operator==(const ClientMessage&, const ClientMessage&);
+        std::vector<uint8_t> bincodeSerialize() const;
+        static ClientMessage bincodeDeserialize(std::vector<uint8_t>);
+    };
+
+} // end of namespace plump_message
+
+
+namespace plump_message {
+
+    inline bool operator==(const Capability &lhs, const Capability &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Capability::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Capability>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Capability Capability::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Capability>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Capability>::serialize(const plump_message::Capability &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Capability serde::Deserializable<plump_message::Capability>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Capability obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Capability::Rejections &lhs, const Capability::Rejections &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Capability::Rejections::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Capability::Rejections>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Capability::Rejections Capability::Rejections::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Capability::Rejections>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Capability::Rejections>::serialize(const plump_message::Capability::Rejections &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Capability::Rejections serde::Deserializable<plump_message::Capability::Rejections>::deserialize(Deserializer &deserializer) {
+    plump_message::Capability::Rejections obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Capability::LegalActions &lhs, const Capability::LegalActions &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Capability::LegalActions::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Capability::LegalActions>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Capability::LegalActions Capability::LegalActions::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Capability::LegalActions>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Capability::LegalActions>::serialize(const plump_message::Capability::LegalActions &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Capability::LegalActions serde::Deserializable<plump_message::Capability::LegalActions>::deserialize(Deserializer &deserializer) {
+    plump_message::Capability::LegalActions obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Card &lhs, const Card &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Card::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Card>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Card Card::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Card>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Card>::serialize(const plump_message::Card &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Card serde::Deserializable<plump_message::Card>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Card obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Card::Suited &lhs, const Card::Suited &rhs) {
+        if (!(lhs.suit == rhs.suit)) { return false; }
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Card::Suited::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Card::Suited>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Card::Suited Card::Suited::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Card::Suited>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Card::Suited>::serialize(const plump_message::Card::Suited &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.suit)>::serialize(obj.suit, serializer);
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Card::Suited serde::Deserializable<plump_message::Card::Suited>::deserialize(Deserializer &deserializer) {
+    plump_message::Card::Suited obj;
+    obj.suit = serde::Deserializable<decltype(obj.suit)>::deserialize(deserializer);
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Card::Joker &lhs, const Card::Joker &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Card::Joker::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Card::Joker>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Card::Joker Card::Joker::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Card::Joker>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Card::Joker>::serialize(const plump_message::Card::Joker &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Card::Joker serde::Deserializable<plump_message::Card::Joker>::deserialize(Deserializer &deserializer) {
+    plump_message::Card::Joker obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage &lhs, const ClientMessage &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage ClientMessage::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage>::serialize(const plump_message::ClientMessage &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage serde::Deserializable<plump_message::ClientMessage>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::ClientMessage obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Reply &lhs, const ClientMessage::Reply &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Reply::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Reply>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Reply ClientMessage::Reply::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Reply>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Reply>::serialize(const plump_message::ClientMessage::Reply &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Reply serde::Deserializable<plump_message::ClientMessage::Reply>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Reply obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Hello &lhs, const ClientMessage::Hello &rhs) {
+        if (!(lhs.version == rhs.version)) { return false; }
+        if (!(lhs.encoding == rhs.encoding)) { return false; }
+        if (!(lhs.capabilities == rhs.capabilities)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Hello::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Hello>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Hello ClientMessage::Hello::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Hello>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Hello>::serialize(const plump_message::ClientMessage::Hello &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.version)>::serialize(obj.version, serializer);
+    serde::Serializable<decltype(obj.encoding)>::serialize(obj.encoding, serializer);
+    serde::Serializable<decltype(obj.capabilities)>::serialize(obj.capabilities, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Hello serde::Deserializable<plump_message::ClientMessage::Hello>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Hello obj;
+    obj.version = serde::Deserializable<decltype(obj.version)>::deserialize(deserializer);
+    obj.encoding = serde::Deserializable<decltype(obj.encoding)>::deserialize(deserializer);
+    obj.capabilities = serde::Deserializable<decltype(obj.capabilities)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::LastTrick &lhs, const ClientMessage::LastTrick &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::LastTrick::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::LastTrick>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::LastTrick ClientMessage::LastTrick::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::LastTrick>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::LastTrick>::serialize(const plump_message::ClientMessage::LastTrick &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::LastTrick serde::Deserializable<plump_message::ClientMessage::LastTrick>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::LastTrick obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::TrickHistory &lhs, const ClientMessage::TrickHistory &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::TrickHistory::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::TrickHistory>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::TrickHistory ClientMessage::TrickHistory::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::TrickHistory>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::TrickHistory>::serialize(const plump_message::ClientMessage::TrickHistory &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::TrickHistory serde::Deserializable<plump_message::ClientMessage::TrickHistory>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::TrickHistory obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Chat &lhs, const ClientMessage::Chat &rhs) {
+        if (!(lhs.to == rhs.to)) { return false; }
+        if (!(lhs.text == rhs.text)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Chat::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Chat>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Chat ClientMessage::Chat::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Chat>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Chat>::serialize(const plump_message::ClientMessage::Chat &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.to)>::serialize(obj.to, serializer);
+    serde::Serializable<decltype(obj.text)>::serialize(obj.text, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Chat serde::Deserializable<plump_message::ClientMessage::Chat>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Chat obj;
+    obj.to = serde::Deserializable<decltype(obj.to)>::deserialize(deserializer);
+    obj.text = serde::Deserializable<decltype(obj.text)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Mute &lhs, const ClientMessage::Mute &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Mute::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Mute>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Mute ClientMessage::Mute::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Mute>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Mute>::serialize(const plump_message::ClientMessage::Mute &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Mute serde::Deserializable<plump_message::ClientMessage::Mute>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Mute obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Unmute &lhs, const ClientMessage::Unmute &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Unmute::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Unmute>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Unmute ClientMessage::Unmute::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Unmute>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Unmute>::serialize(const plump_message::ClientMessage::Unmute &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Unmute serde::Deserializable<plump_message::ClientMessage::Unmute>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Unmute obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Ready &lhs, const ClientMessage::Ready &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Ready::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Ready>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Ready ClientMessage::Ready::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Ready>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Ready>::serialize(const plump_message::ClientMessage::Ready &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Ready serde::Deserializable<plump_message::ClientMessage::Ready>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Ready obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Start &lhs, const ClientMessage::Start &rhs) {
+        if (!(lhs.bots == rhs.bots)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Start::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Start>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Start ClientMessage::Start::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Start>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Start>::serialize(const plump_message::ClientMessage::Start &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.bots)>::serialize(obj.bots, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Start serde::Deserializable<plump_message::ClientMessage::Start>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Start obj;
+    obj.bots = serde::Deserializable<decltype(obj.bots)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const ClientMessage::Leave &lhs, const ClientMessage::Leave &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> ClientMessage::Leave::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<ClientMessage::Leave>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline ClientMessage::Leave ClientMessage::Leave::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<ClientMessage::Leave>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::ClientMessage::Leave>::serialize(const plump_message::ClientMessage::Leave &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::ClientMessage::Leave serde::Deserializable<plump_message::ClientMessage::Leave>::deserialize(Deserializer &deserializer) {
+    plump_message::ClientMessage::Leave obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Colour &lhs, const Colour &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Colour::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Colour>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Colour Colour::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Colour>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Colour>::serialize(const plump_message::Colour &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Colour serde::Deserializable<plump_message::Colour>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Colour obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Colour::Red &lhs, const Colour::Red &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Colour::Red::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Colour::Red>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Colour::Red Colour::Red::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Colour::Red>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Colour::Red>::serialize(const plump_message::Colour::Red &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Colour::Red serde::Deserializable<plump_message::Colour::Red>::deserialize(Deserializer &deserializer) {
+    plump_message::Colour::Red obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Colour::Black &lhs, const Colour::Black &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Colour::Black::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Colour::Black>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Colour::Black Colour::Black::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Colour::Black>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Colour::Black>::serialize(const plump_message::Colour::Black &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Colour::Black serde::Deserializable<plump_message::Colour::Black>::deserialize(Deserializer &deserializer) {
+    plump_message::Colour::Black obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect &lhs, const Disconnect &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect Disconnect::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect>::serialize(const plump_message::Disconnect &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect serde::Deserializable<plump_message::Disconnect>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Disconnect obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect::LineTooLong &lhs, const Disconnect::LineTooLong &rhs) {
+        if (!(lhs.max == rhs.max)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::LineTooLong::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect::LineTooLong>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect::LineTooLong Disconnect::LineTooLong::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect::LineTooLong>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect::LineTooLong>::serialize(const plump_message::Disconnect::LineTooLong &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.max)>::serialize(obj.max, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect::LineTooLong serde::Deserializable<plump_message::Disconnect::LineTooLong>::deserialize(Deserializer &deserializer) {
+    plump_message::Disconnect::LineTooLong obj;
+    obj.max = serde::Deserializable<decltype(obj.max)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect::InvalidUtf8 &lhs, const Disconnect::InvalidUtf8 &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::InvalidUtf8::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect::InvalidUtf8>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect::InvalidUtf8 Disconnect::InvalidUtf8::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect::InvalidUtf8>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect::InvalidUtf8>::serialize(const plump_message::Disconnect::InvalidUtf8 &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect::InvalidUtf8 serde::Deserializable<plump_message::Disconnect::InvalidUtf8>::deserialize(Deserializer &deserializer) {
+    plump_message::Disconnect::InvalidUtf8 obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect::Malformed &lhs, const Disconnect::Malformed &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::Malformed::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect::Malformed>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect::Malformed Disconnect::Malformed::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect::Malformed>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect::Malformed>::serialize(const plump_message::Disconnect::Malformed &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect::Malformed serde::Deserializable<plump_message::Disconnect::Malformed>::deserialize(Deserializer &deserializer) {
+    plump_message::Disconnect::Malformed obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect::Idle &lhs, const Disconnect::Idle &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::Idle::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect::Idle>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect::Idle Disconnect::Idle::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect::Idle>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect::Idle>::serialize(const plump_message::Disconnect::Idle &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect::Idle serde::Deserializable<plump_message::Disconnect::Idle>::deserialize(Deserializer &deserializer) {
+    plump_message::Disconnect::Idle obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Disconnect::NoSeat &lhs, const Disconnect::NoSeat &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Disconnect::NoSeat::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Disconnect::NoSeat>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Disconnect::NoSeat Disconnect::NoSeat::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Disconnect::NoSeat>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Disconnect::NoSeat>::serialize(const plump_message::Disconnect::NoSeat &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Disconnect::NoSeat serde::Deserializable<plump_message::Disconnect::NoSeat>::deserialize(Deserializer &deserializer) {
+    plump_message::Disconnect::NoSeat obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Encoding &lhs, const Encoding &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Encoding::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Encoding>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Encoding Encoding::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Encoding>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Encoding>::serialize(const plump_message::Encoding &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Encoding serde::Deserializable<plump_message::Encoding>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Encoding obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Encoding::Text &lhs, const Encoding::Text &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Encoding::Text::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Encoding::Text>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Encoding::Text Encoding::Text::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Encoding::Text>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Encoding::Text>::serialize(const plump_message::Encoding::Text &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Encoding::Text serde::Deserializable<plump_message::Encoding::Text>::deserialize(Deserializer &deserializer) {
+    plump_message::Encoding::Text obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Encoding::Json &lhs, const Encoding::Json &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Encoding::Json::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Encoding::Json>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Encoding::Json Encoding::Json::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Encoding::Json>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Encoding::Json>::serialize(const plump_message::Encoding::Json &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Encoding::Json serde::Deserializable<plump_message::Encoding::Json>::deserialize(Deserializer &deserializer) {
+    plump_message::Encoding::Json obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Encoding::Binary &lhs, const Encoding::Binary &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Encoding::Binary::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Encoding::Binary>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Encoding::Binary Encoding::Binary::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Encoding::Binary>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Encoding::Binary>::serialize(const plump_message::Encoding::Binary &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Encoding::Binary serde::Deserializable<plump_message::Encoding::Binary>::deserialize(Deserializer &deserializer) {
+    plump_message::Encoding::Binary obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const HandshakeRejection &lhs, const HandshakeRejection &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> HandshakeRejection::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<HandshakeRejection>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline HandshakeRejection HandshakeRejection::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<HandshakeRejection>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::HandshakeRejection>::serialize(const plump_message::HandshakeRejection &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::HandshakeRejection serde::Deserializable<plump_message::HandshakeRejection>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::HandshakeRejection obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const HandshakeRejection::UnsupportedVersion &lhs, const HandshakeRejection::UnsupportedVersion &rhs) {
+        if (!(lhs.requested == rhs.requested)) { return false; }
+        if (!(lhs.supported == rhs.supported)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> HandshakeRejection::UnsupportedVersion::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<HandshakeRejection::UnsupportedVersion>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline HandshakeRejection::UnsupportedVersion HandshakeRejection::UnsupportedVersion::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<HandshakeRejection::UnsupportedVersion>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::HandshakeRejection::UnsupportedVersion>::serialize(const plump_message::HandshakeRejection::UnsupportedVersion &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.requested)>::serialize(obj.requested, serializer);
+    serde::Serializable<decltype(obj.supported)>::serialize(obj.supported, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::HandshakeRejection::UnsupportedVersion serde::Deserializable<plump_message::HandshakeRejection::UnsupportedVersion>::deserialize(Deserializer &deserializer) {
+    plump_message::HandshakeRejection::UnsupportedVersion obj;
+    obj.requested = serde::Deserializable<decltype(obj.requested)>::deserialize(deserializer);
+    obj.supported = serde::Deserializable<decltype(obj.supported)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const HandshakeRejection::Malformed &lhs, const HandshakeRejection::Malformed &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> HandshakeRejection::Malformed::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<HandshakeRejection::Malformed>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline HandshakeRejection::Malformed HandshakeRejection::Malformed::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<HandshakeRejection::Malformed>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::HandshakeRejection::Malformed>::serialize(const plump_message::HandshakeRejection::Malformed &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::HandshakeRejection::Malformed serde::Deserializable<plump_message::HandshakeRejection::Malformed>::deserialize(Deserializer &deserializer) {
+    plump_message::HandshakeRejection::Malformed obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const LobbyPlayer &lhs, const LobbyPlayer &rhs) {
+        if (!(lhs.name == rhs.name)) { return false; }
+        if (!(lhs.ready == rhs.ready)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> LobbyPlayer::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<LobbyPlayer>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline LobbyPlayer LobbyPlayer::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<LobbyPlayer>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::LobbyPlayer>::serialize(const plump_message::LobbyPlayer &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.name)>::serialize(obj.name, serializer);
+    serde::Serializable<decltype(obj.ready)>::serialize(obj.ready, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::LobbyPlayer serde::Deserializable<plump_message::LobbyPlayer>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::LobbyPlayer obj;
+    obj.name = serde::Deserializable<decltype(obj.name)>::deserialize(deserializer);
+    obj.ready = serde::Deserializable<decltype(obj.ready)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message &lhs, const Message &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message Message::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message>::serialize(const plump_message::Message &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message serde::Deserializable<plump_message::Message>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Message obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RequestGuessContext &lhs, const Message::RequestGuessContext &rhs) {
+        if (!(lhs.player == rhs.player)) { return false; }
+        if (!(lhs.hand == rhs.hand)) { return false; }
+        if (!(lhs.guesses == rhs.guesses)) { return false; }
+        if (!(lhs.players == rhs.players)) { return false; }
+        if (!(lhs.legal_guesses == rhs.legal_guesses)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RequestGuessContext::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RequestGuessContext>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RequestGuessContext Message::RequestGuessContext::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RequestGuessContext>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RequestGuessContext>::serialize(const plump_message::Message::RequestGuessContext &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
+    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
+    serde::Serializable<decltype(obj.guesses)>::serialize(obj.guesses, serializer);
+    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
+    serde::Serializable<decltype(obj.legal_guesses)>::serialize(obj.legal_guesses, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RequestGuessContext serde::Deserializable<plump_message::Message::RequestGuessContext>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RequestGuessContext obj;
+    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
+    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
+    obj.guesses = serde::Deserializable<decltype(obj.guesses)>::deserialize(deserializer);
+    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
+    obj.legal_guesses = serde::Deserializable<decltype(obj.legal_guesses)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Guesses &lhs, const Message::Guesses &rhs) {
+        if (!(lhs.state == rhs.state)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Guesses::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Guesses>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Guesses Message::Guesses::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Guesses>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Guesses>::serialize(const plump_message::Message::Guesses &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.state)>::serialize(obj.state, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Guesses serde::Deserializable<plump_message::Message::Guesses>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Guesses obj;
+    obj.state = serde::Deserializable<decltype(obj.state)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Turn &lhs, const Message::Turn &rhs) {
+        if (!(lhs.whose == rhs.whose)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Turn::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Turn>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Turn Message::Turn::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Turn>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Turn>::serialize(const plump_message::Message::Turn &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.whose)>::serialize(obj.whose, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Turn serde::Deserializable<plump_message::Message::Turn>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Turn obj;
+    obj.whose = serde::Deserializable<decltype(obj.whose)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::PlayRequestContext &lhs, const Message::PlayRequestContext &rhs) {
+        if (!(lhs.player == rhs.player)) { return false; }
+        if (!(lhs.hand == rhs.hand)) { return false; }
+        if (!(lhs.trick == rhs.trick)) { return false; }
+        if (!(lhs.valid_cards == rhs.valid_cards)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::PlayRequestContext::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::PlayRequestContext>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::PlayRequestContext Message::PlayRequestContext::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::PlayRequestContext>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::PlayRequestContext>::serialize(const plump_message::Message::PlayRequestContext &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
+    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
+    serde::Serializable<decltype(obj.trick)>::serialize(obj.trick, serializer);
+    serde::Serializable<decltype(obj.valid_cards)>::serialize(obj.valid_cards, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::PlayRequestContext serde::Deserializable<plump_message::Message::PlayRequestContext>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::PlayRequestContext obj;
+    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
+    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
+    obj.trick = serde::Deserializable<decltype(obj.trick)>::deserialize(deserializer);
+    obj.valid_cards = serde::Deserializable<decltype(obj.valid_cards)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Trick &lhs, const Message::Trick &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Trick::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Trick>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Trick Message::Trick::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Trick>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Trick>::serialize(const plump_message::Message::Trick &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Trick serde::Deserializable<plump_message::Message::Trick>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Trick obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Scoreboard &lhs, const Message::Scoreboard &rhs) {
+        if (!(lhs.state == rhs.state)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Scoreboard::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Scoreboard>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Scoreboard Message::Scoreboard::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Scoreboard>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Scoreboard>::serialize(const plump_message::Message::Scoreboard &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.state)>::serialize(obj.state, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Scoreboard serde::Deserializable<plump_message::Message::Scoreboard>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Scoreboard obj;
+    obj.state = serde::Deserializable<decltype(obj.state)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Winner &lhs, const Message::Winner &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Winner::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Winner>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Winner Message::Winner::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Winner>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Winner>::serialize(const plump_message::Message::Winner &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Winner serde::Deserializable<plump_message::Message::Winner>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Winner obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Winners &lhs, const Message::Winners &rhs) {
+        if (!(lhs.players == rhs.players)) { return false; }
+        if (!(lhs.winner_indices == rhs.winner_indices)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Winners::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Winners>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Winners Message::Winners::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Winners>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Winners>::serialize(const plump_message::Message::Winners &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
+    serde::Serializable<decltype(obj.winner_indices)>::serialize(obj.winner_indices, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Winners serde::Deserializable<plump_message::Message::Winners>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Winners obj;
+    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
+    obj.winner_indices = serde::Deserializable<decltype(obj.winner_indices)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RequestPlayerName &lhs, const Message::RequestPlayerName &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RequestPlayerName::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RequestPlayerName>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RequestPlayerName Message::RequestPlayerName::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RequestPlayerName>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RequestPlayerName>::serialize(const plump_message::Message::RequestPlayerName &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RequestPlayerName serde::Deserializable<plump_message::Message::RequestPlayerName>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RequestPlayerName obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::PlayRequest &lhs, const Message::PlayRequest &rhs) {
+        if (!(lhs.player == rhs.player)) { return false; }
+        if (!(lhs.valid_cards == rhs.valid_cards)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::PlayRequest::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::PlayRequest>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::PlayRequest Message::PlayRequest::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::PlayRequest>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::PlayRequest>::serialize(const plump_message::Message::PlayRequest &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
+    serde::Serializable<decltype(obj.valid_cards)>::serialize(obj.valid_cards, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::PlayRequest serde::Deserializable<plump_message::Message::PlayRequest>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::PlayRequest obj;
+    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
+    obj.valid_cards = serde::Deserializable<decltype(obj.valid_cards)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RequestGuess &lhs, const Message::RequestGuess &rhs) {
+        if (!(lhs.legal_guesses == rhs.legal_guesses)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RequestGuess::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RequestGuess>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RequestGuess Message::RequestGuess::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RequestGuess>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RequestGuess>::serialize(const plump_message::Message::RequestGuess &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.legal_guesses)>::serialize(obj.legal_guesses, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RequestGuess serde::Deserializable<plump_message::Message::RequestGuess>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RequestGuess obj;
+    obj.legal_guesses = serde::Deserializable<decltype(obj.legal_guesses)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::GameOver &lhs, const Message::GameOver &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::GameOver::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::GameOver>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::GameOver Message::GameOver::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::GameOver>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::GameOver>::serialize(const plump_message::Message::GameOver &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::GameOver serde::Deserializable<plump_message::Message::GameOver>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::GameOver obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Rejected &lhs, const Message::Rejected &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Rejected::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Rejected>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Rejected Message::Rejected::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Rejected>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Rejected>::serialize(const plump_message::Message::Rejected &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Rejected serde::Deserializable<plump_message::Message::Rejected>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Rejected obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::HandshakeAccepted &lhs, const Message::HandshakeAccepted &rhs) {
+        if (!(lhs.version == rhs.version)) { return false; }
+        if (!(lhs.encoding == rhs.encoding)) { return false; }
+        if (!(lhs.capabilities == rhs.capabilities)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::HandshakeAccepted::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::HandshakeAccepted>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::HandshakeAccepted Message::HandshakeAccepted::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::HandshakeAccepted>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::HandshakeAccepted>::serialize(const plump_message::Message::HandshakeAccepted &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.version)>::serialize(obj.version, serializer);
+    serde::Serializable<decltype(obj.encoding)>::serialize(obj.encoding, serializer);
+    serde::Serializable<decltype(obj.capabilities)>::serialize(obj.capabilities, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::HandshakeAccepted serde::Deserializable<plump_message::Message::HandshakeAccepted>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::HandshakeAccepted obj;
+    obj.version = serde::Deserializable<decltype(obj.version)>::deserialize(deserializer);
+    obj.encoding = serde::Deserializable<decltype(obj.encoding)>::deserialize(deserializer);
+    obj.capabilities = serde::Deserializable<decltype(obj.capabilities)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::HandshakeRejected &lhs, const Message::HandshakeRejected &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::HandshakeRejected::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::HandshakeRejected>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::HandshakeRejected Message::HandshakeRejected::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::HandshakeRejected>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::HandshakeRejected>::serialize(const plump_message::Message::HandshakeRejected &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::HandshakeRejected serde::Deserializable<plump_message::Message::HandshakeRejected>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::HandshakeRejected obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::ScoreSheet &lhs, const Message::ScoreSheet &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::ScoreSheet::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::ScoreSheet>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::ScoreSheet Message::ScoreSheet::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::ScoreSheet>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::ScoreSheet>::serialize(const plump_message::Message::ScoreSheet &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::ScoreSheet serde::Deserializable<plump_message::Message::ScoreSheet>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::ScoreSheet obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RoundStart &lhs, const Message::RoundStart &rhs) {
+        if (!(lhs.round == rhs.round)) { return false; }
+        if (!(lhs.rounds == rhs.rounds)) { return false; }
+        if (!(lhs.hand_size == rhs.hand_size)) { return false; }
+        if (!(lhs.dealer == rhs.dealer)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RoundStart::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RoundStart>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RoundStart Message::RoundStart::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RoundStart>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RoundStart>::serialize(const plump_message::Message::RoundStart &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.round)>::serialize(obj.round, serializer);
+    serde::Serializable<decltype(obj.rounds)>::serialize(obj.rounds, serializer);
+    serde::Serializable<decltype(obj.hand_size)>::serialize(obj.hand_size, serializer);
+    serde::Serializable<decltype(obj.dealer)>::serialize(obj.dealer, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RoundStart serde::Deserializable<plump_message::Message::RoundStart>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RoundStart obj;
+    obj.round = serde::Deserializable<decltype(obj.round)>::deserialize(deserializer);
+    obj.rounds = serde::Deserializable<decltype(obj.rounds)>::deserialize(deserializer);
+    obj.hand_size = serde::Deserializable<decltype(obj.hand_size)>::deserialize(deserializer);
+    obj.dealer = serde::Deserializable<decltype(obj.dealer)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RoundEnd &lhs, const Message::RoundEnd &rhs) {
+        if (!(lhs.round == rhs.round)) { return false; }
+        if (!(lhs.made == rhs.made)) { return false; }
+        if (!(lhs.missed == rhs.missed)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RoundEnd::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RoundEnd>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RoundEnd Message::RoundEnd::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RoundEnd>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RoundEnd>::serialize(const plump_message::Message::RoundEnd &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.round)>::serialize(obj.round, serializer);
+    serde::Serializable<decltype(obj.made)>::serialize(obj.made, serializer);
+    serde::Serializable<decltype(obj.missed)>::serialize(obj.missed, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RoundEnd serde::Deserializable<plump_message::Message::RoundEnd>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RoundEnd obj;
+    obj.round = serde::Deserializable<decltype(obj.round)>::deserialize(deserializer);
+    obj.made = serde::Deserializable<decltype(obj.made)>::deserialize(deserializer);
+    obj.missed = serde::Deserializable<decltype(obj.missed)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::LastTrick &lhs, const Message::LastTrick &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::LastTrick::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::LastTrick>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::LastTrick Message::LastTrick::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::LastTrick>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::LastTrick>::serialize(const plump_message::Message::LastTrick &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::LastTrick serde::Deserializable<plump_message::Message::LastTrick>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::LastTrick obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::TrickHistory &lhs, const Message::TrickHistory &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::TrickHistory::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::TrickHistory>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::TrickHistory Message::TrickHistory::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::TrickHistory>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::TrickHistory>::serialize(const plump_message::Message::TrickHistory &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::TrickHistory serde::Deserializable<plump_message::Message::TrickHistory>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::TrickHistory obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Chat &lhs, const Message::Chat &rhs) {
+        if (!(lhs.sender == rhs.sender)) { return false; }
+        if (!(lhs.text == rhs.text)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Chat::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Chat>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Chat Message::Chat::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Chat>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Chat>::serialize(const plump_message::Message::Chat &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.sender)>::serialize(obj.sender, serializer);
+    serde::Serializable<decltype(obj.text)>::serialize(obj.text, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Chat serde::Deserializable<plump_message::Message::Chat>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Chat obj;
+    obj.sender = serde::Deserializable<decltype(obj.sender)>::deserialize(deserializer);
+    obj.text = serde::Deserializable<decltype(obj.text)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Whisper &lhs, const Message::Whisper &rhs) {
+        if (!(lhs.sender == rhs.sender)) { return false; }
+        if (!(lhs.to == rhs.to)) { return false; }
+        if (!(lhs.text == rhs.text)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Whisper::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Whisper>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Whisper Message::Whisper::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Whisper>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Whisper>::serialize(const plump_message::Message::Whisper &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.sender)>::serialize(obj.sender, serializer);
+    serde::Serializable<decltype(obj.to)>::serialize(obj.to, serializer);
+    serde::Serializable<decltype(obj.text)>::serialize(obj.text, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Whisper serde::Deserializable<plump_message::Message::Whisper>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Whisper obj;
+    obj.sender = serde::Deserializable<decltype(obj.sender)>::deserialize(deserializer);
+    obj.to = serde::Deserializable<decltype(obj.to)>::deserialize(deserializer);
+    obj.text = serde::Deserializable<decltype(obj.text)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Disconnected &lhs, const Message::Disconnected &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Disconnected::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Disconnected>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Disconnected Message::Disconnected::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Disconnected>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Disconnected>::serialize(const plump_message::Message::Disconnected &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Disconnected serde::Deserializable<plump_message::Message::Disconnected>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Disconnected obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::NameRejected &lhs, const Message::NameRejected &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::NameRejected::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::NameRejected>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::NameRejected Message::NameRejected::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::NameRejected>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::NameRejected>::serialize(const plump_message::Message::NameRejected &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::NameRejected serde::Deserializable<plump_message::Message::NameRejected>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::NameRejected obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Lobby &lhs, const Message::Lobby &rhs) {
+        if (!(lhs.players == rhs.players)) { return false; }
+        if (!(lhs.seats == rhs.seats)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Lobby::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Lobby>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Lobby Message::Lobby::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Lobby>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Lobby>::serialize(const plump_message::Message::Lobby &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
+    serde::Serializable<decltype(obj.seats)>::serialize(obj.seats, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Lobby serde::Deserializable<plump_message::Message::Lobby>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Lobby obj;
+    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
+    obj.seats = serde::Deserializable<decltype(obj.seats)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::Series &lhs, const Message::Series &rhs) {
+        if (!(lhs.games == rhs.games)) { return false; }
+        if (!(lhs.scores == rhs.scores)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::Series::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::Series>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::Series Message::Series::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::Series>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::Series>::serialize(const plump_message::Message::Series &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.games)>::serialize(obj.games, serializer);
+    serde::Serializable<decltype(obj.scores)>::serialize(obj.scores, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::Series serde::Deserializable<plump_message::Message::Series>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::Series obj;
+    obj.games = serde::Deserializable<decltype(obj.games)>::deserialize(deserializer);
+    obj.scores = serde::Deserializable<decltype(obj.scores)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Message::RejoinToken &lhs, const Message::RejoinToken &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Message::RejoinToken::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Message::RejoinToken>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Message::RejoinToken Message::RejoinToken::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Message::RejoinToken>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Message::RejoinToken>::serialize(const plump_message::Message::RejoinToken &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Message::RejoinToken serde::Deserializable<plump_message::Message::RejoinToken>::deserialize(Deserializer &deserializer) {
+    plump_message::Message::RejoinToken obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const NameRejection &lhs, const NameRejection &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> NameRejection::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<NameRejection>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline NameRejection NameRejection::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<NameRejection>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::NameRejection>::serialize(const plump_message::NameRejection &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::NameRejection serde::Deserializable<plump_message::NameRejection>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::NameRejection obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const NameRejection::Empty &lhs, const NameRejection::Empty &rhs) {
+        return true;
+    }
+
+    inline std::vector<uint8_t> NameRejection::Empty::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<NameRejection::Empty>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline NameRejection::Empty NameRejection::Empty::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<NameRejection::Empty>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::NameRejection::Empty>::serialize(const plump_message::NameRejection::Empty &obj, Serializer &serializer) {
+}
+
+template <>
+template <typename Deserializer>
+plump_message::NameRejection::Empty serde::Deserializable<plump_message::NameRejection::Empty>::deserialize(Deserializer &deserializer) {
+    plump_message::NameRejection::Empty obj;
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const NameRejection::TooLong &lhs, const NameRejection::TooLong &rhs) {
+        if (!(lhs.max == rhs.max)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> NameRejection::TooLong::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<NameRejection::TooLong>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline NameRejection::TooLong NameRejection::TooLong::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<NameRejection::TooLong>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::NameRejection::TooLong>::serialize(const plump_message::NameRejection::TooLong &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.max)>::serialize(obj.max, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::NameRejection::TooLong serde::Deserializable<plump_message::NameRejection::TooLong>::deserialize(Deserializer &deserializer) {
+    plump_message::NameRejection::TooLong obj;
+    obj.max = serde::Deserializable<decltype(obj.max)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const NameRejection::InvalidCharacter &lhs, const NameRejection::InvalidCharacter &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> NameRejection::InvalidCharacter::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<NameRejection::InvalidCharacter>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline NameRejection::InvalidCharacter NameRejection::InvalidCharacter::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<NameRejection::InvalidCharacter>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::NameRejection::InvalidCharacter>::serialize(const plump_message::NameRejection::InvalidCharacter &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::NameRejection::InvalidCharacter serde::Deserializable<plump_message::NameRejection::InvalidCharacter>::deserialize(Deserializer &deserializer) {
+    plump_message::NameRejection::InvalidCharacter obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const NameRejection::Taken &lhs, const NameRejection::Taken &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> NameRejection::Taken::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<NameRejection::Taken>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline NameRejection::Taken NameRejection::Taken::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<NameRejection::Taken>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::NameRejection::Taken>::serialize(const plump_message::NameRejection::Taken &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+}
+
+template <>
+template <typename Deserializer>
+plump_message::NameRejection::Taken serde::Deserializable<plump_message::NameRejection::Taken>::deserialize(Deserializer &deserializer) {
+    plump_message::NameRejection::Taken obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Play &lhs, const Play &rhs) {
+        if (!(lhs.player == rhs.player)) { return false; }
+        if (!(lhs.card == rhs.card)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Play::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Play>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Play Play::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Play>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Play>::serialize(const plump_message::Play &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
+    serde::Serializable<decltype(obj.card)>::serialize(obj.card, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Play serde::Deserializable<plump_message::Play>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Play obj;
+    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
+    obj.card = serde::Deserializable<decltype(obj.card)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const Player &lhs, const Player &rhs) {
+        if (!(lhs.name == rhs.name)) { return false; }
+        if (!(lhs.human == rhs.human)) { return false; }
+        if (!(lhs.hand == rhs.hand)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> Player::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<Player>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline Player Player::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<Player>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::Player>::serialize(const plump_message::Player &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.name)>::serialize(obj.name, serializer);
+    serde::Serializable<decltype(obj.human)>::serialize(obj.human, serializer);
+    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::Player serde::Deserializable<plump_message::Player>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::Player obj;
+    obj.name = serde::Deserializable<decltype(obj.name)>::deserialize(deserializer);
+    obj.human = serde::Deserializable<decltype(obj.human)>::deserialize(deserializer);
+    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const PlayerName &lhs, const PlayerName &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> PlayerName::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<PlayerName>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline PlayerName PlayerName::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<PlayerName>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::PlayerName>::serialize(const plump_message::PlayerName &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::PlayerName serde::Deserializable<plump_message::PlayerName>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::PlayerName obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const PublicPlayer &lhs, const PublicPlayer &rhs) {
+        if (!(lhs.name == rhs.name)) { return false; }
+        if (!(lhs.human == rhs.human)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> PublicPlayer::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<PublicPlayer>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline PublicPlayer PublicPlayer::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<PublicPlayer>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::PublicPlayer>::serialize(const plump_message::PublicPlayer &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.name)>::serialize(obj.name, serializer);
+    serde::Serializable<decltype(obj.human)>::serialize(obj.human, serializer);
+    serializer.decrease_container_depth();
+}
+
+template <>
+template <typename Deserializer>
+plump_message::PublicPlayer serde::Deserializable<plump_message::PublicPlayer>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::PublicPlayer obj;
+    obj.name = serde::Deserializable<decltype(obj.name)>::deserialize(deserializer);
+    obj.human = serde::Deserializable<decltype(obj.human)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
+
+namespace plump_message {
+
+    inline bool operator==(const PublicState &lhs, const PublicState &rhs) {
+        if (!(lhs.guess == rhs.guess)) { return false; }
+        if (!(lhs.wins == rhs.wins)) { return false; }
+        if (!(lhs.score == rhs.score)) { return false; }
+        return true;
+    }
+
+    inline std::vector<uint8_t> PublicState::bincodeSerialize() const {
+        auto serializer = serde::BincodeSerializer();
+        serde::Serializable<PublicState>::serialize(*this, serializer);
+        return std::move(serializer).bytes();
+    }
+
+    inline PublicState PublicState::bincodeDeserialize(std::vector<uint8_t> input) {
+        auto deserializer = serde::BincodeDeserializer(input);
+        auto value = serde::Deserializable<PublicState>::deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.size()) {
+            throw serde::deserialization_error("Some input bytes were not read");
+        }
+        return value;
+    }
+
+} // end of namespace plump_message
+
+template <>
+template <typename Serializer>
+void serde::Serializable<plump_message::PublicState>::serialize(const plump_message::PublicState &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.guess)>::serialize(obj.guess, serializer);
+    serde::Serializable<decltype(obj.wins)>::serialize(obj.wins, serializer);
+    serde::Serializable<decltype(obj.score)>::serialize(obj.score, serializer);
+    serializer.decrease_container_depth();
+}
 
+template <>
+template <typename Deserializer>
+plump_message::PublicState serde::Deserializable<plump_message::PublicState>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::PublicState obj;
+    obj.guess = serde::Deserializable<decltype(obj.guess)>::deserialize(deserializer);
+    obj.wins = serde::Deserializable<decltype(obj.wins)>::deserialize(deserializer);
+    obj.score = serde::Deserializable<decltype(obj.score)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
+    return obj;
+}
 
 namespace plump_message {
 
-    inline bool operator==(const Card &lhs, const Card &rhs) {
-        if (!(lhs.suit == rhs.suit)) { return false; }
+    inline bool operator==(const Rejection &lhs, const Rejection &rhs) {
         if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Card::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Card>::serialize(*this, serializer);
+        serde::Serializable<Rejection>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Card Card::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection Rejection::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Card>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -186,19 +3624,17 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Card>::serialize(const plump_message::Card &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::Rejection>::serialize(const plump_message::Rejection &obj, Serializer &serializer) {
     serializer.increase_container_depth();
-    serde::Serializable<decltype(obj.suit)>::serialize(obj.suit, serializer);
     serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
     serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Card serde::Deserializable<plump_message::Card>::deserialize(Deserializer &deserializer) {
+plump_message::Rejection serde::Deserializable<plump_message::Rejection>::deserialize(Deserializer &deserializer) {
     deserializer.increase_container_depth();
-    plump_message::Card obj;
-    obj.suit = serde::Deserializable<decltype(obj.suit)>::deserialize(deserializer);
+    plump_message::Rejection obj;
     obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
@@ -206,20 +3642,20 @@ plump_message::Card serde::Deserializable<plump_message::Card>::deserialize(Dese
 
 namespace plump_message {
 
-    inline bool operator==(const Message &lhs, const Message &rhs) {
+    inline bool operator==(const Rejection::NotANumber &lhs, const Rejection::NotANumber &rhs) {
         if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::NotANumber::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message>::serialize(*this, serializer);
+        serde::Serializable<Rejection::NotANumber>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message Message::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::NotANumber Rejection::NotANumber::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::NotANumber>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -230,41 +3666,35 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message>::serialize(const plump_message::Message &obj, Serializer &serializer) {
-    serializer.increase_container_depth();
+void serde::Serializable<plump_message::Rejection::NotANumber>::serialize(const plump_message::Rejection::NotANumber &obj, Serializer &serializer) {
     serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
-    serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message serde::Deserializable<plump_message::Message>::deserialize(Deserializer &deserializer) {
-    deserializer.increase_container_depth();
-    plump_message::Message obj;
+plump_message::Rejection::NotANumber serde::Deserializable<plump_message::Rejection::NotANumber>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::NotANumber obj;
     obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
-    deserializer.decrease_container_depth();
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::RequestGuessContext &lhs, const Message::RequestGuessContext &rhs) {
-        if (!(lhs.player == rhs.player)) { return false; }
-        if (!(lhs.hand == rhs.hand)) { return false; }
-        if (!(lhs.guesses == rhs.guesses)) { return false; }
-        if (!(lhs.players == rhs.players)) { return false; }
+    inline bool operator==(const Rejection::GuessTooHigh &lhs, const Rejection::GuessTooHigh &rhs) {
+        if (!(lhs.guess == rhs.guess)) { return false; }
+        if (!(lhs.hand_size == rhs.hand_size)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::RequestGuessContext::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::GuessTooHigh::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::RequestGuessContext>::serialize(*this, serializer);
+        serde::Serializable<Rejection::GuessTooHigh>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::RequestGuessContext Message::RequestGuessContext::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::GuessTooHigh Rejection::GuessTooHigh::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::RequestGuessContext>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::GuessTooHigh>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -275,40 +3705,37 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::RequestGuessContext>::serialize(const plump_message::Message::RequestGuessContext &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
-    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
-    serde::Serializable<decltype(obj.guesses)>::serialize(obj.guesses, serializer);
-    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
+void serde::Serializable<plump_message::Rejection::GuessTooHigh>::serialize(const plump_message::Rejection::GuessTooHigh &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.guess)>::serialize(obj.guess, serializer);
+    serde::Serializable<decltype(obj.hand_size)>::serialize(obj.hand_size, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::RequestGuessContext serde::Deserializable<plump_message::Message::RequestGuessContext>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::RequestGuessContext obj;
-    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
-    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
-    obj.guesses = serde::Deserializable<decltype(obj.guesses)>::deserialize(deserializer);
-    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
+plump_message::Rejection::GuessTooHigh serde::Deserializable<plump_message::Rejection::GuessTooHigh>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::GuessTooHigh obj;
+    obj.guess = serde::Deserializable<decltype(obj.guess)>::deserialize(deserializer);
+    obj.hand_size = serde::Deserializable<decltype(obj.hand_size)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Guesses &lhs, const Message::Guesses &rhs) {
-        if (!(lhs.state == rhs.state)) { return false; }
+    inline bool operator==(const Rejection::ForbiddenGuess &lhs, const Rejection::ForbiddenGuess &rhs) {
+        if (!(lhs.guess == rhs.guess)) { return false; }
+        if (!(lhs.hand_size == rhs.hand_size)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Guesses::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::ForbiddenGuess::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Guesses>::serialize(*this, serializer);
+        serde::Serializable<Rejection::ForbiddenGuess>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Guesses Message::Guesses::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::ForbiddenGuess Rejection::ForbiddenGuess::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Guesses>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::ForbiddenGuess>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -319,34 +3746,37 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Guesses>::serialize(const plump_message::Message::Guesses &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.state)>::serialize(obj.state, serializer);
+void serde::Serializable<plump_message::Rejection::ForbiddenGuess>::serialize(const plump_message::Rejection::ForbiddenGuess &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.guess)>::serialize(obj.guess, serializer);
+    serde::Serializable<decltype(obj.hand_size)>::serialize(obj.hand_size, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Guesses serde::Deserializable<plump_message::Message::Guesses>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Guesses obj;
-    obj.state = serde::Deserializable<decltype(obj.state)>::deserialize(deserializer);
+plump_message::Rejection::ForbiddenGuess serde::Deserializable<plump_message::Rejection::ForbiddenGuess>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::ForbiddenGuess obj;
+    obj.guess = serde::Deserializable<decltype(obj.guess)>::deserialize(deserializer);
+    obj.hand_size = serde::Deserializable<decltype(obj.hand_size)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Turn &lhs, const Message::Turn &rhs) {
-        if (!(lhs.whose == rhs.whose)) { return false; }
+    inline bool operator==(const Rejection::NoSuchCard &lhs, const Rejection::NoSuchCard &rhs) {
+        if (!(lhs.index == rhs.index)) { return false; }
+        if (!(lhs.hand_size == rhs.hand_size)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Turn::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::NoSuchCard::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Turn>::serialize(*this, serializer);
+        serde::Serializable<Rejection::NoSuchCard>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Turn Message::Turn::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::NoSuchCard Rejection::NoSuchCard::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Turn>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::NoSuchCard>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -357,37 +3787,36 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Turn>::serialize(const plump_message::Message::Turn &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.whose)>::serialize(obj.whose, serializer);
+void serde::Serializable<plump_message::Rejection::NoSuchCard>::serialize(const plump_message::Rejection::NoSuchCard &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.index)>::serialize(obj.index, serializer);
+    serde::Serializable<decltype(obj.hand_size)>::serialize(obj.hand_size, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Turn serde::Deserializable<plump_message::Message::Turn>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Turn obj;
-    obj.whose = serde::Deserializable<decltype(obj.whose)>::deserialize(deserializer);
+plump_message::Rejection::NoSuchCard serde::Deserializable<plump_message::Rejection::NoSuchCard>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::NoSuchCard obj;
+    obj.index = serde::Deserializable<decltype(obj.index)>::deserialize(deserializer);
+    obj.hand_size = serde::Deserializable<decltype(obj.hand_size)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::PlayRequestContext &lhs, const Message::PlayRequestContext &rhs) {
-        if (!(lhs.player == rhs.player)) { return false; }
-        if (!(lhs.hand == rhs.hand)) { return false; }
-        if (!(lhs.trick == rhs.trick)) { return false; }
-        if (!(lhs.valid_cards == rhs.valid_cards)) { return false; }
+    inline bool operator==(const Rejection::MustFollowSuit &lhs, const Rejection::MustFollowSuit &rhs) {
+        if (!(lhs.suit == rhs.suit)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::PlayRequestContext::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::MustFollowSuit::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::PlayRequestContext>::serialize(*this, serializer);
+        serde::Serializable<Rejection::MustFollowSuit>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::PlayRequestContext Message::PlayRequestContext::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::MustFollowSuit Rejection::MustFollowSuit::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::PlayRequestContext>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::MustFollowSuit>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -398,40 +3827,34 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::PlayRequestContext>::serialize(const plump_message::Message::PlayRequestContext &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.player)>::serialize(obj.player, serializer);
-    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
-    serde::Serializable<decltype(obj.trick)>::serialize(obj.trick, serializer);
-    serde::Serializable<decltype(obj.valid_cards)>::serialize(obj.valid_cards, serializer);
+void serde::Serializable<plump_message::Rejection::MustFollowSuit>::serialize(const plump_message::Rejection::MustFollowSuit &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.suit)>::serialize(obj.suit, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::PlayRequestContext serde::Deserializable<plump_message::Message::PlayRequestContext>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::PlayRequestContext obj;
-    obj.player = serde::Deserializable<decltype(obj.player)>::deserialize(deserializer);
-    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
-    obj.trick = serde::Deserializable<decltype(obj.trick)>::deserialize(deserializer);
-    obj.valid_cards = serde::Deserializable<decltype(obj.valid_cards)>::deserialize(deserializer);
+plump_message::Rejection::MustFollowSuit serde::Deserializable<plump_message::Rejection::MustFollowSuit>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::MustFollowSuit obj;
+    obj.suit = serde::Deserializable<decltype(obj.suit)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Trick &lhs, const Message::Trick &rhs) {
+    inline bool operator==(const Rejection::NotACard &lhs, const Rejection::NotACard &rhs) {
         if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Trick::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::NotACard::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Trick>::serialize(*this, serializer);
+        serde::Serializable<Rejection::NotACard>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Trick Message::Trick::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::NotACard Rejection::NotACard::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Trick>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::NotACard>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -442,34 +3865,34 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Trick>::serialize(const plump_message::Message::Trick &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::Rejection::NotACard>::serialize(const plump_message::Rejection::NotACard &obj, Serializer &serializer) {
     serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Trick serde::Deserializable<plump_message::Message::Trick>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Trick obj;
+plump_message::Rejection::NotACard serde::Deserializable<plump_message::Rejection::NotACard>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::NotACard obj;
     obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Scoreboard &lhs, const Message::Scoreboard &rhs) {
-        if (!(lhs.state == rhs.state)) { return false; }
+    inline bool operator==(const Rejection::CardNotInHand &lhs, const Rejection::CardNotInHand &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Scoreboard::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::CardNotInHand::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Scoreboard>::serialize(*this, serializer);
+        serde::Serializable<Rejection::CardNotInHand>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Scoreboard Message::Scoreboard::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::CardNotInHand Rejection::CardNotInHand::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Scoreboard>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::CardNotInHand>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -480,34 +3903,35 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Scoreboard>::serialize(const plump_message::Message::Scoreboard &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.state)>::serialize(obj.state, serializer);
+void serde::Serializable<plump_message::Rejection::CardNotInHand>::serialize(const plump_message::Rejection::CardNotInHand &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Scoreboard serde::Deserializable<plump_message::Message::Scoreboard>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Scoreboard obj;
-    obj.state = serde::Deserializable<decltype(obj.state)>::deserialize(deserializer);
+plump_message::Rejection::CardNotInHand serde::Deserializable<plump_message::Rejection::CardNotInHand>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::CardNotInHand obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Winner &lhs, const Message::Winner &rhs) {
-        if (!(lhs.value == rhs.value)) { return false; }
+    inline bool operator==(const Rejection::ChatTooLong &lhs, const Rejection::ChatTooLong &rhs) {
+        if (!(lhs.length == rhs.length)) { return false; }
+        if (!(lhs.max == rhs.max)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Winner::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::ChatTooLong::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Winner>::serialize(*this, serializer);
+        serde::Serializable<Rejection::ChatTooLong>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Winner Message::Winner::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::ChatTooLong Rejection::ChatTooLong::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Winner>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::ChatTooLong>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -518,35 +3942,36 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Winner>::serialize(const plump_message::Message::Winner &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+void serde::Serializable<plump_message::Rejection::ChatTooLong>::serialize(const plump_message::Rejection::ChatTooLong &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.length)>::serialize(obj.length, serializer);
+    serde::Serializable<decltype(obj.max)>::serialize(obj.max, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Winner serde::Deserializable<plump_message::Message::Winner>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Winner obj;
-    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+plump_message::Rejection::ChatTooLong serde::Deserializable<plump_message::Rejection::ChatTooLong>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::ChatTooLong obj;
+    obj.length = serde::Deserializable<decltype(obj.length)>::deserialize(deserializer);
+    obj.max = serde::Deserializable<decltype(obj.max)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::Winners &lhs, const Message::Winners &rhs) {
-        if (!(lhs.players == rhs.players)) { return false; }
-        if (!(lhs.winner_indices == rhs.winner_indices)) { return false; }
+    inline bool operator==(const Rejection::UnknownPlayer &lhs, const Rejection::UnknownPlayer &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::Winners::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::UnknownPlayer::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::Winners>::serialize(*this, serializer);
+        serde::Serializable<Rejection::UnknownPlayer>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::Winners Message::Winners::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::UnknownPlayer Rejection::UnknownPlayer::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::Winners>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::UnknownPlayer>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -557,35 +3982,34 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::Winners>::serialize(const plump_message::Message::Winners &obj, Serializer &serializer) {
-    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
-    serde::Serializable<decltype(obj.winner_indices)>::serialize(obj.winner_indices, serializer);
+void serde::Serializable<plump_message::Rejection::UnknownPlayer>::serialize(const plump_message::Rejection::UnknownPlayer &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::Winners serde::Deserializable<plump_message::Message::Winners>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::Winners obj;
-    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
-    obj.winner_indices = serde::Deserializable<decltype(obj.winner_indices)>::deserialize(deserializer);
+plump_message::Rejection::UnknownPlayer serde::Deserializable<plump_message::Rejection::UnknownPlayer>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::UnknownPlayer obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::RequestPlayerName &lhs, const Message::RequestPlayerName &rhs) {
+    inline bool operator==(const Rejection::NotHost &lhs, const Rejection::NotHost &rhs) {
+        if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::RequestPlayerName::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::NotHost::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::RequestPlayerName>::serialize(*this, serializer);
+        serde::Serializable<Rejection::NotHost>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::RequestPlayerName Message::RequestPlayerName::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::NotHost Rejection::NotHost::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::RequestPlayerName>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::NotHost>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -596,32 +4020,34 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::RequestPlayerName>::serialize(const plump_message::Message::RequestPlayerName &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::Rejection::NotHost>::serialize(const plump_message::Rejection::NotHost &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::RequestPlayerName serde::Deserializable<plump_message::Message::RequestPlayerName>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::RequestPlayerName obj;
+plump_message::Rejection::NotHost serde::Deserializable<plump_message::Rejection::NotHost>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::NotHost obj;
+    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::PlayRequest &lhs, const Message::PlayRequest &rhs) {
+    inline bool operator==(const Rejection::NotReady &lhs, const Rejection::NotReady &rhs) {
         if (!(lhs.value == rhs.value)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::PlayRequest::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::NotReady::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::PlayRequest>::serialize(*this, serializer);
+        serde::Serializable<Rejection::NotReady>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::PlayRequest Message::PlayRequest::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::NotReady Rejection::NotReady::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::PlayRequest>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::NotReady>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -632,33 +4058,34 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::PlayRequest>::serialize(const plump_message::Message::PlayRequest &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::Rejection::NotReady>::serialize(const plump_message::Rejection::NotReady &obj, Serializer &serializer) {
     serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::PlayRequest serde::Deserializable<plump_message::Message::PlayRequest>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::PlayRequest obj;
+plump_message::Rejection::NotReady serde::Deserializable<plump_message::Rejection::NotReady>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::NotReady obj;
     obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::RequestGuess &lhs, const Message::RequestGuess &rhs) {
+    inline bool operator==(const Rejection::TooFewPlayers &lhs, const Rejection::TooFewPlayers &rhs) {
+        if (!(lhs.min == rhs.min)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::RequestGuess::bincodeSerialize() const {
+    inline std::vector<uint8_t> Rejection::TooFewPlayers::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::RequestGuess>::serialize(*this, serializer);
+        serde::Serializable<Rejection::TooFewPlayers>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::RequestGuess Message::RequestGuess::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline Rejection::TooFewPlayers Rejection::TooFewPlayers::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::RequestGuess>::deserialize(deserializer);
+        auto value = serde::Deserializable<Rejection::TooFewPlayers>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -669,31 +4096,36 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::RequestGuess>::serialize(const plump_message::Message::RequestGuess &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::Rejection::TooFewPlayers>::serialize(const plump_message::Rejection::TooFewPlayers &obj, Serializer &serializer) {
+    serde::Serializable<decltype(obj.min)>::serialize(obj.min, serializer);
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::RequestGuess serde::Deserializable<plump_message::Message::RequestGuess>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::RequestGuess obj;
+plump_message::Rejection::TooFewPlayers serde::Deserializable<plump_message::Rejection::TooFewPlayers>::deserialize(Deserializer &deserializer) {
+    plump_message::Rejection::TooFewPlayers obj;
+    obj.min = serde::Deserializable<decltype(obj.min)>::deserialize(deserializer);
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Message::GameOver &lhs, const Message::GameOver &rhs) {
+    inline bool operator==(const RoundScore &lhs, const RoundScore &rhs) {
+        if (!(lhs.guess == rhs.guess)) { return false; }
+        if (!(lhs.wins == rhs.wins)) { return false; }
+        if (!(lhs.points == rhs.points)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Message::GameOver::bincodeSerialize() const {
+    inline std::vector<uint8_t> RoundScore::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Message::GameOver>::serialize(*this, serializer);
+        serde::Serializable<RoundScore>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Message::GameOver Message::GameOver::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline RoundScore RoundScore::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Message::GameOver>::deserialize(deserializer);
+        auto value = serde::Deserializable<RoundScore>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -704,34 +4136,43 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Message::GameOver>::serialize(const plump_message::Message::GameOver &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::RoundScore>::serialize(const plump_message::RoundScore &obj, Serializer &serializer) {
+    serializer.increase_container_depth();
+    serde::Serializable<decltype(obj.guess)>::serialize(obj.guess, serializer);
+    serde::Serializable<decltype(obj.wins)>::serialize(obj.wins, serializer);
+    serde::Serializable<decltype(obj.points)>::serialize(obj.points, serializer);
+    serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Message::GameOver serde::Deserializable<plump_message::Message::GameOver>::deserialize(Deserializer &deserializer) {
-    plump_message::Message::GameOver obj;
+plump_message::RoundScore serde::Deserializable<plump_message::RoundScore>::deserialize(Deserializer &deserializer) {
+    deserializer.increase_container_depth();
+    plump_message::RoundScore obj;
+    obj.guess = serde::Deserializable<decltype(obj.guess)>::deserialize(deserializer);
+    obj.wins = serde::Deserializable<decltype(obj.wins)>::deserialize(deserializer);
+    obj.points = serde::Deserializable<decltype(obj.points)>::deserialize(deserializer);
+    deserializer.decrease_container_depth();
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const Player &lhs, const Player &rhs) {
-        if (!(lhs.name == rhs.name)) { return false; }
-        if (!(lhs.human == rhs.human)) { return false; }
-        if (!(lhs.hand == rhs.hand)) { return false; }
+    inline bool operator==(const ScoreSheet &lhs, const ScoreSheet &rhs) {
+        if (!(lhs.players == rhs.players)) { return false; }
+        if (!(lhs.rounds == rhs.rounds)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> Player::bincodeSerialize() const {
+    inline std::vector<uint8_t> ScoreSheet::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<Player>::serialize(*this, serializer);
+        serde::Serializable<ScoreSheet>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline Player Player::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline ScoreSheet ScoreSheet::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<Player>::deserialize(deserializer);
+        auto value = serde::Deserializable<ScoreSheet>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -742,42 +4183,41 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::Player>::serialize(const plump_message::Player &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::ScoreSheet>::serialize(const plump_message::ScoreSheet &obj, Serializer &serializer) {
     serializer.increase_container_depth();
-    serde::Serializable<decltype(obj.name)>::serialize(obj.name, serializer);
-    serde::Serializable<decltype(obj.human)>::serialize(obj.human, serializer);
-    serde::Serializable<decltype(obj.hand)>::serialize(obj.hand, serializer);
+    serde::Serializable<decltype(obj.players)>::serialize(obj.players, serializer);
+    serde::Serializable<decltype(obj.rounds)>::serialize(obj.rounds, serializer);
     serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::Player serde::Deserializable<plump_message::Player>::deserialize(Deserializer &deserializer) {
+plump_message::ScoreSheet serde::Deserializable<plump_message::ScoreSheet>::deserialize(Deserializer &deserializer) {
     deserializer.increase_container_depth();
-    plump_message::Player obj;
-    obj.name = serde::Deserializable<decltype(obj.name)>::deserialize(deserializer);
-    obj.human = serde::Deserializable<decltype(obj.human)>::deserialize(deserializer);
-    obj.hand = serde::Deserializable<decltype(obj.hand)>::deserialize(deserializer);
+    plump_message::ScoreSheet obj;
+    obj.players = serde::Deserializable<decltype(obj.players)>::deserialize(deserializer);
+    obj.rounds = serde::Deserializable<decltype(obj.rounds)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const PlayerName &lhs, const PlayerName &rhs) {
-        if (!(lhs.value == rhs.value)) { return false; }
+    inline bool operator==(const ScoreSheetRound &lhs, const ScoreSheetRound &rhs) {
+        if (!(lhs.hand_size == rhs.hand_size)) { return false; }
+        if (!(lhs.scores == rhs.scores)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> PlayerName::bincodeSerialize() const {
+    inline std::vector<uint8_t> ScoreSheetRound::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<PlayerName>::serialize(*this, serializer);
+        serde::Serializable<ScoreSheetRound>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline PlayerName PlayerName::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline ScoreSheetRound ScoreSheetRound::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<PlayerName>::deserialize(deserializer);
+        auto value = serde::Deserializable<ScoreSheetRound>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -788,40 +4228,42 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::PlayerName>::serialize(const plump_message::PlayerName &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::ScoreSheetRound>::serialize(const plump_message::ScoreSheetRound &obj, Serializer &serializer) {
     serializer.increase_container_depth();
-    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serde::Serializable<decltype(obj.hand_size)>::serialize(obj.hand_size, serializer);
+    serde::Serializable<decltype(obj.scores)>::serialize(obj.scores, serializer);
     serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::PlayerName serde::Deserializable<plump_message::PlayerName>::deserialize(Deserializer &deserializer) {
+plump_message::ScoreSheetRound serde::Deserializable<plump_message::ScoreSheetRound>::deserialize(Deserializer &deserializer) {
     deserializer.increase_container_depth();
-    plump_message::PlayerName obj;
-    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    plump_message::ScoreSheetRound obj;
+    obj.hand_size = serde::Deserializable<decltype(obj.hand_size)>::deserialize(deserializer);
+    obj.scores = serde::Deserializable<decltype(obj.scores)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
 }
 
 namespace plump_message {
 
-    inline bool operator==(const PublicState &lhs, const PublicState &rhs) {
-        if (!(lhs.guess == rhs.guess)) { return false; }
-        if (!(lhs.wins == rhs.wins)) { return false; }
-        if (!(lhs.score == rhs.score)) { return false; }
+    inline bool operator==(const SeriesScore &lhs, const SeriesScore &rhs) {
+        if (!(lhs.name == rhs.name)) { return false; }
+        if (!(lhs.played == rhs.played)) { return false; }
+        if (!(lhs.won == rhs.won)) { return false; }
         return true;
     }
 
-    inline std::vector<uint8_t> PublicState::bincodeSerialize() const {
+    inline std::vector<uint8_t> SeriesScore::bincodeSerialize() const {
         auto serializer = serde::BincodeSerializer();
-        serde::Serializable<PublicState>::serialize(*this, serializer);
+        serde::Serializable<SeriesScore>::serialize(*this, serializer);
         return std::move(serializer).bytes();
     }
 
-    inline PublicState PublicState::bincodeDeserialize(std::vector<uint8_t> input) {
+    inline SeriesScore SeriesScore::bincodeDeserialize(std::vector<uint8_t> input) {
         auto deserializer = serde::BincodeDeserializer(input);
-        auto value = serde::Deserializable<PublicState>::deserialize(deserializer);
+        auto value = serde::Deserializable<SeriesScore>::deserialize(deserializer);
         if (deserializer.get_buffer_offset() < input.size()) {
             throw serde::deserialization_error("Some input bytes were not read");
         }
@@ -832,22 +4274,22 @@ namespace plump_message {
 
 template <>
 template <typename Serializer>
-void serde::Serializable<plump_message::PublicState>::serialize(const plump_message::PublicState &obj, Serializer &serializer) {
+void serde::Serializable<plump_message::SeriesScore>::serialize(const plump_message::SeriesScore &obj, Serializer &serializer) {
     serializer.increase_container_depth();
-    serde::Serializable<decltype(obj.guess)>::serialize(obj.guess, serializer);
-    serde::Serializable<decltype(obj.wins)>::serialize(obj.wins, serializer);
-    serde::Serializable<decltype(obj.score)>::serialize(obj.score, serializer);
+    serde::Serializable<decltype(obj.name)>::serialize(obj.name, serializer);
+    serde::Serializable<decltype(obj.played)>::serialize(obj.played, serializer);
+    serde::Serializable<decltype(obj.won)>::serialize(obj.won, serializer);
     serializer.decrease_container_depth();
 }
 
 template <>
 template <typename Deserializer>
-plump_message::PublicState serde::Deserializable<plump_message::PublicState>::deserialize(Deserializer &deserializer) {
+plump_message::SeriesScore serde::Deserializable<plump_message::SeriesScore>::deserialize(Deserializer &deserializer) {
     deserializer.increase_container_depth();
-    plump_message::PublicState obj;
-    obj.guess = serde::Deserializable<decltype(obj.guess)>::deserialize(deserializer);
-    obj.wins = serde::Deserializable<decltype(obj.wins)>::deserialize(deserializer);
-    obj.score = serde::Deserializable<decltype(obj.score)>::deserialize(deserializer);
+    plump_message::SeriesScore obj;
+    obj.name = serde::Deserializable<decltype(obj.name)>::deserialize(deserializer);
+    obj.played = serde::Deserializable<decltype(obj.played)>::deserialize(deserializer);
+    obj.won = serde::Deserializable<decltype(obj.won)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
 }
@@ -855,7 +4297,9 @@ plump_message::PublicState serde::Deserializable<plump_message::PublicState>::de
 namespace plump_message {
 
     inline bool operator==(const Trick &lhs, const Trick &rhs) {
-        if (!(lhs.value == rhs.value)) { return false; }
+        if (!(lhs.plays == rhs.plays)) { return false; }
+        if (!(lhs.led_suit == rhs.led_suit)) { return false; }
+        if (!(lhs.winning == rhs.winning)) { return false; }
         return true;
     }
 
@@ -880,7 +4324,9 @@ template <>
 template <typename Serializer>
 void serde::Serializable<plump_message::Trick>::serialize(const plump_message::Trick &obj, Serializer &serializer) {
     serializer.increase_container_depth();
-    serde::Serializable<decltype(obj.value)>::serialize(obj.value, serializer);
+    serde::Serializable<decltype(obj.plays)>::serialize(obj.plays, serializer);
+    serde::Serializable<decltype(obj.led_suit)>::serialize(obj.led_suit, serializer);
+    serde::Serializable<decltype(obj.winning)>::serialize(obj.winning, serializer);
     serializer.decrease_container_depth();
 }
 
@@ -889,7 +4335,9 @@ template <typename Deserializer>
 plump_message::Trick serde::Deserializable<plump_message::Trick>::deserialize(Deserializer &deserializer) {
     deserializer.increase_container_depth();
     plump_message::Trick obj;
-    obj.value = serde::Deserializable<decltype(obj.value)>::deserialize(deserializer);
+    obj.plays = serde::Deserializable<decltype(obj.plays)>::deserialize(deserializer);
+    obj.led_suit = serde::Deserializable<decltype(obj.led_suit)>::deserialize(deserializer);
+    obj.winning = serde::Deserializable<decltype(obj.winning)>::deserialize(deserializer);
     deserializer.decrease_container_depth();
     return obj;
 }
@@ -0,0 +1,154 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class Capability: IEquatable<Capability>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static Capability Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Rejections.Load(deserializer);
+                case 1: return LegalActions.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Capability: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Capability BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Capability BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Capability value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case Rejections x: return x.GetHashCode();
+            case LegalActions x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is Capability other && Equals(other);
+
+        public bool Equals(Capability other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Rejections x: return x.Equals((Rejections)other);
+            case LegalActions x: return x.Equals((LegalActions)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Capability Clone() => (Capability)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class Rejections: Capability, IEquatable<Rejections>, ICloneable {
+            public Rejections() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Rejections Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Rejections obj = new Rejections(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Rejections other && Equals(other);
+
+            public static bool operator ==(Rejections left, Rejections right) => Equals(left, right);
+
+            public static bool operator !=(Rejections left, Rejections right) => !Equals(left, right);
+
+            public bool Equals(Rejections other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class LegalActions: Capability, IEquatable<LegalActions>, ICloneable {
+            public LegalActions() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+
+            internal static LegalActions Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                LegalActions obj = new LegalActions(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is LegalActions other && Equals(other);
+
+            public static bool operator ==(LegalActions left, LegalActions right) => Equals(left, right);
+
+            public static bool operator !=(LegalActions left, LegalActions right) => !Equals(left, right);
+
+            public bool Equals(LegalActions other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
@@ -7,20 +7,17 @@ using System.Numerics;
 
 namespace plump_message {
 
-    public sealed class Card: IEquatable<Card>, ICloneable {
-        public ulong suit;
-        public ulong value;
+    public abstract class Card: IEquatable<Card>, ICloneable {
 
-        public Card(ulong _suit, ulong _value) {
-            suit = _suit;
-            value = _value;
-        }
+        public abstract void Serialize(Serde.ISerializer serializer);
 
-        public void Serialize(Serde.ISerializer serializer) {
-            serializer.increase_container_depth();
-            serializer.serialize_u64(suit);
-            serializer.serialize_u64(value);
-            serializer.decrease_container_depth();
+        public static Card Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Suited.Load(deserializer);
+                case 1: return Joker.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Card: " + index);
+            }
         }
 
         public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
@@ -37,15 +34,6 @@ namespace plump_message {
             return serializer.get_bytes();
         }
 
-        public static Card Deserialize(Serde.IDeserializer deserializer) {
-            deserializer.increase_container_depth();
-            Card obj = new Card(
-            	deserializer.deserialize_u64(),
-            	deserializer.deserialize_u64());
-            deserializer.decrease_container_depth();
-            return obj;
-        }
-
         public static Card BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
 
         public static Card BincodeDeserialize(ArraySegment<byte> input) {
@@ -59,26 +47,23 @@ namespace plump_message {
             }
             return value;
         }
+        public override int GetHashCode() {
+            switch (this) {
+            case Suited x: return x.GetHashCode();
+            case Joker x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
         public override bool Equals(object obj) => obj is Card other && Equals(other);
 
-        public static bool operator ==(Card left, Card right) => Equals(left, right);
-
-        public static bool operator !=(Card left, Card right) => !Equals(left, right);
-
         public bool Equals(Card other) {
             if (other == null) return false;
             if (ReferenceEquals(this, other)) return true;
-            if (!suit.Equals(other.suit)) return false;
-            if (!value.Equals(other.value)) return false;
-            return true;
-        }
-
-        public override int GetHashCode() {
-            unchecked {
-                int value = 7;
-                value = 31 * value + suit.GetHashCode();
-                value = 31 * value + value.GetHashCode();
-                return value;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Suited x: return x.Equals((Suited)other);
+            case Joker x: return x.Equals((Joker)other);
+            default: throw new InvalidOperationException("Unknown variant type");
             }
         }
 
@@ -87,6 +72,102 @@ namespace plump_message {
 
         object ICloneable.Clone() => Clone();
 
+
+        public sealed class Suited: Card, IEquatable<Suited>, ICloneable {
+            public ulong suit;
+            public ulong value;
+
+            public Suited(ulong _suit, ulong _value) {
+                suit = _suit;
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_u64(suit);
+                serializer.serialize_u64(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Suited Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Suited obj = new Suited(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Suited other && Equals(other);
+
+            public static bool operator ==(Suited left, Suited right) => Equals(left, right);
+
+            public static bool operator !=(Suited left, Suited right) => !Equals(left, right);
+
+            public bool Equals(Suited other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!suit.Equals(other.suit)) return false;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + suit.GetHashCode();
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Joker: Card, IEquatable<Joker>, ICloneable {
+            public Colour value;
+
+            public Joker(Colour _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Joker Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Joker obj = new Joker(
+                	Colour.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Joker other && Equals(other);
+
+            public static bool operator ==(Joker left, Joker right) => Equals(left, right);
+
+            public static bool operator !=(Joker left, Joker right) => !Equals(left, right);
+
+            public bool Equals(Joker other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
     }
 
+
 } // end of namespace plump_message
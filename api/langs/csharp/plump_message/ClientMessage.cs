@@ -0,0 +1,548 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class ClientMessage: IEquatable<ClientMessage>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static ClientMessage Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Reply.Load(deserializer);
+                case 1: return Hello.Load(deserializer);
+                case 2: return LastTrick.Load(deserializer);
+                case 3: return TrickHistory.Load(deserializer);
+                case 4: return Chat.Load(deserializer);
+                case 5: return Mute.Load(deserializer);
+                case 6: return Unmute.Load(deserializer);
+                case 7: return Ready.Load(deserializer);
+                case 8: return Start.Load(deserializer);
+                case 9: return Leave.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for ClientMessage: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static ClientMessage BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static ClientMessage BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            ClientMessage value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case Reply x: return x.GetHashCode();
+            case Hello x: return x.GetHashCode();
+            case LastTrick x: return x.GetHashCode();
+            case TrickHistory x: return x.GetHashCode();
+            case Chat x: return x.GetHashCode();
+            case Mute x: return x.GetHashCode();
+            case Unmute x: return x.GetHashCode();
+            case Ready x: return x.GetHashCode();
+            case Start x: return x.GetHashCode();
+            case Leave x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is ClientMessage other && Equals(other);
+
+        public bool Equals(ClientMessage other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Reply x: return x.Equals((Reply)other);
+            case Hello x: return x.Equals((Hello)other);
+            case LastTrick x: return x.Equals((LastTrick)other);
+            case TrickHistory x: return x.Equals((TrickHistory)other);
+            case Chat x: return x.Equals((Chat)other);
+            case Mute x: return x.Equals((Mute)other);
+            case Unmute x: return x.Equals((Unmute)other);
+            case Ready x: return x.Equals((Ready)other);
+            case Start x: return x.Equals((Start)other);
+            case Leave x: return x.Equals((Leave)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public ClientMessage Clone() => (ClientMessage)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class Reply: ClientMessage, IEquatable<Reply>, ICloneable {
+            public string value;
+
+            public Reply(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Reply Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Reply obj = new Reply(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Reply other && Equals(other);
+
+            public static bool operator ==(Reply left, Reply right) => Equals(left, right);
+
+            public static bool operator !=(Reply left, Reply right) => !Equals(left, right);
+
+            public bool Equals(Reply other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Hello: ClientMessage, IEquatable<Hello>, ICloneable {
+            public uint version;
+            public Encoding encoding;
+            public Serde.ValueArray<Capability> capabilities;
+
+            public Hello(uint _version, Encoding _encoding, Serde.ValueArray<Capability> _capabilities) {
+                version = _version;
+                if (_encoding == null) throw new ArgumentNullException(nameof(_encoding));
+                encoding = _encoding;
+                if (_capabilities == null) throw new ArgumentNullException(nameof(_capabilities));
+                capabilities = _capabilities;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.serialize_u32(version);
+                encoding.Serialize(serializer);
+                TraitHelpers.serialize_vector_Capability(capabilities, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Hello Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Hello obj = new Hello(
+                	deserializer.deserialize_u32(),
+                	Encoding.Deserialize(deserializer),
+                	TraitHelpers.deserialize_vector_Capability(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Hello other && Equals(other);
+
+            public static bool operator ==(Hello left, Hello right) => Equals(left, right);
+
+            public static bool operator !=(Hello left, Hello right) => !Equals(left, right);
+
+            public bool Equals(Hello other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!version.Equals(other.version)) return false;
+                if (!encoding.Equals(other.encoding)) return false;
+                if (!capabilities.Equals(other.capabilities)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + version.GetHashCode();
+                    value = 31 * value + encoding.GetHashCode();
+                    value = 31 * value + capabilities.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class LastTrick: ClientMessage, IEquatable<LastTrick>, ICloneable {
+            public LastTrick() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.decrease_container_depth();
+            }
+
+            internal static LastTrick Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                LastTrick obj = new LastTrick(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is LastTrick other && Equals(other);
+
+            public static bool operator ==(LastTrick left, LastTrick right) => Equals(left, right);
+
+            public static bool operator !=(LastTrick left, LastTrick right) => !Equals(left, right);
+
+            public bool Equals(LastTrick other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class TrickHistory: ClientMessage, IEquatable<TrickHistory>, ICloneable {
+            public TrickHistory() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(3);
+                serializer.decrease_container_depth();
+            }
+
+            internal static TrickHistory Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                TrickHistory obj = new TrickHistory(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is TrickHistory other && Equals(other);
+
+            public static bool operator ==(TrickHistory left, TrickHistory right) => Equals(left, right);
+
+            public static bool operator !=(TrickHistory left, TrickHistory right) => !Equals(left, right);
+
+            public bool Equals(TrickHistory other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Chat: ClientMessage, IEquatable<Chat>, ICloneable {
+            public Serde.Option<PlayerName> to;
+            public string text;
+
+            public Chat(Serde.Option<PlayerName> _to, string _text) {
+                to = _to;
+                if (_text == null) throw new ArgumentNullException(nameof(_text));
+                text = _text;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(4);
+                TraitHelpers.serialize_option_PlayerName(to, serializer);
+                serializer.serialize_str(text);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Chat Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Chat obj = new Chat(
+                	TraitHelpers.deserialize_option_PlayerName(deserializer),
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Chat other && Equals(other);
+
+            public static bool operator ==(Chat left, Chat right) => Equals(left, right);
+
+            public static bool operator !=(Chat left, Chat right) => !Equals(left, right);
+
+            public bool Equals(Chat other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!to.Equals(other.to)) return false;
+                if (!text.Equals(other.text)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + to.GetHashCode();
+                    value = 31 * value + text.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Mute: ClientMessage, IEquatable<Mute>, ICloneable {
+            public PlayerName value;
+
+            public Mute(PlayerName _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(5);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Mute Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Mute obj = new Mute(
+                	PlayerName.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Mute other && Equals(other);
+
+            public static bool operator ==(Mute left, Mute right) => Equals(left, right);
+
+            public static bool operator !=(Mute left, Mute right) => !Equals(left, right);
+
+            public bool Equals(Mute other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Unmute: ClientMessage, IEquatable<Unmute>, ICloneable {
+            public PlayerName value;
+
+            public Unmute(PlayerName _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(6);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Unmute Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Unmute obj = new Unmute(
+                	PlayerName.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Unmute other && Equals(other);
+
+            public static bool operator ==(Unmute left, Unmute right) => Equals(left, right);
+
+            public static bool operator !=(Unmute left, Unmute right) => !Equals(left, right);
+
+            public bool Equals(Unmute other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Ready: ClientMessage, IEquatable<Ready>, ICloneable {
+            public bool value;
+
+            public Ready(bool _value) {
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(7);
+                serializer.serialize_bool(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Ready Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Ready obj = new Ready(
+                	deserializer.deserialize_bool());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Ready other && Equals(other);
+
+            public static bool operator ==(Ready left, Ready right) => Equals(left, right);
+
+            public static bool operator !=(Ready left, Ready right) => !Equals(left, right);
+
+            public bool Equals(Ready other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Start: ClientMessage, IEquatable<Start>, ICloneable {
+            public bool bots;
+
+            public Start(bool _bots) {
+                bots = _bots;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(8);
+                serializer.serialize_bool(bots);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Start Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Start obj = new Start(
+                	deserializer.deserialize_bool());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Start other && Equals(other);
+
+            public static bool operator ==(Start left, Start right) => Equals(left, right);
+
+            public static bool operator !=(Start left, Start right) => !Equals(left, right);
+
+            public bool Equals(Start other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!bots.Equals(other.bots)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + bots.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Leave: ClientMessage, IEquatable<Leave>, ICloneable {
+            public Leave() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(9);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Leave Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Leave obj = new Leave(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Leave other && Equals(other);
+
+            public static bool operator ==(Leave left, Leave right) => Equals(left, right);
+
+            public static bool operator !=(Leave left, Leave right) => !Equals(left, right);
+
+            public bool Equals(Leave other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
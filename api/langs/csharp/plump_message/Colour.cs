@@ -0,0 +1,154 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class Colour: IEquatable<Colour>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static Colour Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Red.Load(deserializer);
+                case 1: return Black.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Colour: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Colour BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Colour BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Colour value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case Red x: return x.GetHashCode();
+            case Black x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is Colour other && Equals(other);
+
+        public bool Equals(Colour other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Red x: return x.Equals((Red)other);
+            case Black x: return x.Equals((Black)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Colour Clone() => (Colour)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class Red: Colour, IEquatable<Red>, ICloneable {
+            public Red() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Red Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Red obj = new Red(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Red other && Equals(other);
+
+            public static bool operator ==(Red left, Red right) => Equals(left, right);
+
+            public static bool operator !=(Red left, Red right) => !Equals(left, right);
+
+            public bool Equals(Red other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Black: Colour, IEquatable<Black>, ICloneable {
+            public Black() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Black Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Black obj = new Black(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Black other && Equals(other);
+
+            public static bool operator ==(Black left, Black right) => Equals(left, right);
+
+            public static bool operator !=(Black left, Black right) => !Equals(left, right);
+
+            public bool Equals(Black other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
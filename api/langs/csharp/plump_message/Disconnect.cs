@@ -0,0 +1,290 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class Disconnect: IEquatable<Disconnect>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static Disconnect Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return LineTooLong.Load(deserializer);
+                case 1: return InvalidUtf8.Load(deserializer);
+                case 2: return Malformed.Load(deserializer);
+                case 3: return Idle.Load(deserializer);
+                case 4: return NoSeat.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Disconnect: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Disconnect BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Disconnect BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Disconnect value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case LineTooLong x: return x.GetHashCode();
+            case InvalidUtf8 x: return x.GetHashCode();
+            case Malformed x: return x.GetHashCode();
+            case Idle x: return x.GetHashCode();
+            case NoSeat x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is Disconnect other && Equals(other);
+
+        public bool Equals(Disconnect other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case LineTooLong x: return x.Equals((LineTooLong)other);
+            case InvalidUtf8 x: return x.Equals((InvalidUtf8)other);
+            case Malformed x: return x.Equals((Malformed)other);
+            case Idle x: return x.Equals((Idle)other);
+            case NoSeat x: return x.Equals((NoSeat)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Disconnect Clone() => (Disconnect)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class LineTooLong: Disconnect, IEquatable<LineTooLong>, ICloneable {
+            public ulong max;
+
+            public LineTooLong(ulong _max) {
+                max = _max;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_u64(max);
+                serializer.decrease_container_depth();
+            }
+
+            internal static LineTooLong Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                LineTooLong obj = new LineTooLong(
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is LineTooLong other && Equals(other);
+
+            public static bool operator ==(LineTooLong left, LineTooLong right) => Equals(left, right);
+
+            public static bool operator !=(LineTooLong left, LineTooLong right) => !Equals(left, right);
+
+            public bool Equals(LineTooLong other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!max.Equals(other.max)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + max.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class InvalidUtf8: Disconnect, IEquatable<InvalidUtf8>, ICloneable {
+            public InvalidUtf8() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+
+            internal static InvalidUtf8 Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                InvalidUtf8 obj = new InvalidUtf8(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is InvalidUtf8 other && Equals(other);
+
+            public static bool operator ==(InvalidUtf8 left, InvalidUtf8 right) => Equals(left, right);
+
+            public static bool operator !=(InvalidUtf8 left, InvalidUtf8 right) => !Equals(left, right);
+
+            public bool Equals(InvalidUtf8 other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Malformed: Disconnect, IEquatable<Malformed>, ICloneable {
+            public string value;
+
+            public Malformed(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Malformed Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Malformed obj = new Malformed(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Malformed other && Equals(other);
+
+            public static bool operator ==(Malformed left, Malformed right) => Equals(left, right);
+
+            public static bool operator !=(Malformed left, Malformed right) => !Equals(left, right);
+
+            public bool Equals(Malformed other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Idle: Disconnect, IEquatable<Idle>, ICloneable {
+            public Idle() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(3);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Idle Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Idle obj = new Idle(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Idle other && Equals(other);
+
+            public static bool operator ==(Idle left, Idle right) => Equals(left, right);
+
+            public static bool operator !=(Idle left, Idle right) => !Equals(left, right);
+
+            public bool Equals(Idle other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NoSeat: Disconnect, IEquatable<NoSeat>, ICloneable {
+            public NoSeat() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(4);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NoSeat Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NoSeat obj = new NoSeat(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NoSeat other && Equals(other);
+
+            public static bool operator ==(NoSeat left, NoSeat right) => Equals(left, right);
+
+            public static bool operator !=(NoSeat left, NoSeat right) => !Equals(left, right);
+
+            public bool Equals(NoSeat other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
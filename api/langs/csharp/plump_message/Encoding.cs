@@ -0,0 +1,195 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class Encoding: IEquatable<Encoding>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static Encoding Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Text.Load(deserializer);
+                case 1: return Json.Load(deserializer);
+                case 2: return Binary.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Encoding: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Encoding BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Encoding BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Encoding value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case Text x: return x.GetHashCode();
+            case Json x: return x.GetHashCode();
+            case Binary x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is Encoding other && Equals(other);
+
+        public bool Equals(Encoding other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Text x: return x.Equals((Text)other);
+            case Json x: return x.Equals((Json)other);
+            case Binary x: return x.Equals((Binary)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Encoding Clone() => (Encoding)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class Text: Encoding, IEquatable<Text>, ICloneable {
+            public Text() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Text Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Text obj = new Text(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Text other && Equals(other);
+
+            public static bool operator ==(Text left, Text right) => Equals(left, right);
+
+            public static bool operator !=(Text left, Text right) => !Equals(left, right);
+
+            public bool Equals(Text other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Json: Encoding, IEquatable<Json>, ICloneable {
+            public Json() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Json Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Json obj = new Json(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Json other && Equals(other);
+
+            public static bool operator ==(Json left, Json right) => Equals(left, right);
+
+            public static bool operator !=(Json left, Json right) => !Equals(left, right);
+
+            public bool Equals(Json other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Binary: Encoding, IEquatable<Binary>, ICloneable {
+            public Binary() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Binary Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Binary obj = new Binary(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Binary other && Equals(other);
+
+            public static bool operator ==(Binary left, Binary right) => Equals(left, right);
+
+            public static bool operator !=(Binary left, Binary right) => !Equals(left, right);
+
+            public bool Equals(Binary other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
@@ -0,0 +1,173 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class HandshakeRejection: IEquatable<HandshakeRejection>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static HandshakeRejection Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return UnsupportedVersion.Load(deserializer);
+                case 1: return Malformed.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for HandshakeRejection: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static HandshakeRejection BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static HandshakeRejection BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            HandshakeRejection value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case UnsupportedVersion x: return x.GetHashCode();
+            case Malformed x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is HandshakeRejection other && Equals(other);
+
+        public bool Equals(HandshakeRejection other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case UnsupportedVersion x: return x.Equals((UnsupportedVersion)other);
+            case Malformed x: return x.Equals((Malformed)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public HandshakeRejection Clone() => (HandshakeRejection)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class UnsupportedVersion: HandshakeRejection, IEquatable<UnsupportedVersion>, ICloneable {
+            public uint requested;
+            public uint supported;
+
+            public UnsupportedVersion(uint _requested, uint _supported) {
+                requested = _requested;
+                supported = _supported;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_u32(requested);
+                serializer.serialize_u32(supported);
+                serializer.decrease_container_depth();
+            }
+
+            internal static UnsupportedVersion Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                UnsupportedVersion obj = new UnsupportedVersion(
+                	deserializer.deserialize_u32(),
+                	deserializer.deserialize_u32());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is UnsupportedVersion other && Equals(other);
+
+            public static bool operator ==(UnsupportedVersion left, UnsupportedVersion right) => Equals(left, right);
+
+            public static bool operator !=(UnsupportedVersion left, UnsupportedVersion right) => !Equals(left, right);
+
+            public bool Equals(UnsupportedVersion other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!requested.Equals(other.requested)) return false;
+                if (!supported.Equals(other.supported)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + requested.GetHashCode();
+                    value = 31 * value + supported.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Malformed: HandshakeRejection, IEquatable<Malformed>, ICloneable {
+            public string value;
+
+            public Malformed(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Malformed Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Malformed obj = new Malformed(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Malformed other && Equals(other);
+
+            public static bool operator ==(Malformed left, Malformed right) => Equals(left, right);
+
+            public static bool operator !=(Malformed left, Malformed right) => !Equals(left, right);
+
+            public bool Equals(Malformed other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
@@ -0,0 +1,93 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class LobbyPlayer: IEquatable<LobbyPlayer>, ICloneable {
+        public PlayerName name;
+        public bool ready;
+
+        public LobbyPlayer(PlayerName _name, bool _ready) {
+            if (_name == null) throw new ArgumentNullException(nameof(_name));
+            name = _name;
+            ready = _ready;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            name.Serialize(serializer);
+            serializer.serialize_bool(ready);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static LobbyPlayer Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            LobbyPlayer obj = new LobbyPlayer(
+            	PlayerName.Deserialize(deserializer),
+            	deserializer.deserialize_bool());
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static LobbyPlayer BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static LobbyPlayer BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            LobbyPlayer value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is LobbyPlayer other && Equals(other);
+
+        public static bool operator ==(LobbyPlayer left, LobbyPlayer right) => Equals(left, right);
+
+        public static bool operator !=(LobbyPlayer left, LobbyPlayer right) => !Equals(left, right);
+
+        public bool Equals(LobbyPlayer other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!name.Equals(other.name)) return false;
+            if (!ready.Equals(other.ready)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + name.GetHashCode();
+                value = 31 * value + ready.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public LobbyPlayer Clone() => (LobbyPlayer)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
@@ -26,6 +26,21 @@ namespace plump_message {
                 case 9: return PlayRequest.Load(deserializer);
                 case 10: return RequestGuess.Load(deserializer);
                 case 11: return GameOver.Load(deserializer);
+                case 12: return Rejected.Load(deserializer);
+                case 13: return HandshakeAccepted.Load(deserializer);
+                case 14: return HandshakeRejected.Load(deserializer);
+                case 15: return ScoreSheet.Load(deserializer);
+                case 16: return RoundStart.Load(deserializer);
+                case 17: return RoundEnd.Load(deserializer);
+                case 18: return LastTrick.Load(deserializer);
+                case 19: return TrickHistory.Load(deserializer);
+                case 20: return Chat.Load(deserializer);
+                case 21: return Whisper.Load(deserializer);
+                case 22: return Disconnected.Load(deserializer);
+                case 23: return NameRejected.Load(deserializer);
+                case 24: return Lobby.Load(deserializer);
+                case 25: return Series.Load(deserializer);
+                case 26: return RejoinToken.Load(deserializer);
                 default: throw new Serde.DeserializationException("Unknown variant index for Message: " + index);
             }
         }
@@ -71,6 +86,21 @@ namespace plump_message {
             case PlayRequest x: return x.GetHashCode();
             case RequestGuess x: return x.GetHashCode();
             case GameOver x: return x.GetHashCode();
+            case Rejected x: return x.GetHashCode();
+            case HandshakeAccepted x: return x.GetHashCode();
+            case HandshakeRejected x: return x.GetHashCode();
+            case ScoreSheet x: return x.GetHashCode();
+            case RoundStart x: return x.GetHashCode();
+            case RoundEnd x: return x.GetHashCode();
+            case LastTrick x: return x.GetHashCode();
+            case TrickHistory x: return x.GetHashCode();
+            case Chat x: return x.GetHashCode();
+            case Whisper x: return x.GetHashCode();
+            case Disconnected x: return x.GetHashCode();
+            case NameRejected x: return x.GetHashCode();
+            case Lobby x: return x.GetHashCode();
+            case Series x: return x.GetHashCode();
+            case RejoinToken x: return x.GetHashCode();
             default: throw new InvalidOperationException("Unknown variant type");
             }
         }
@@ -93,6 +123,21 @@ namespace plump_message {
             case PlayRequest x: return x.Equals((PlayRequest)other);
             case RequestGuess x: return x.Equals((RequestGuess)other);
             case GameOver x: return x.Equals((GameOver)other);
+            case Rejected x: return x.Equals((Rejected)other);
+            case HandshakeAccepted x: return x.Equals((HandshakeAccepted)other);
+            case HandshakeRejected x: return x.Equals((HandshakeRejected)other);
+            case ScoreSheet x: return x.Equals((ScoreSheet)other);
+            case RoundStart x: return x.Equals((RoundStart)other);
+            case RoundEnd x: return x.Equals((RoundEnd)other);
+            case LastTrick x: return x.Equals((LastTrick)other);
+            case TrickHistory x: return x.Equals((TrickHistory)other);
+            case Chat x: return x.Equals((Chat)other);
+            case Whisper x: return x.Equals((Whisper)other);
+            case Disconnected x: return x.Equals((Disconnected)other);
+            case NameRejected x: return x.Equals((NameRejected)other);
+            case Lobby x: return x.Equals((Lobby)other);
+            case Series x: return x.Equals((Series)other);
+            case RejoinToken x: return x.Equals((RejoinToken)other);
             default: throw new InvalidOperationException("Unknown variant type");
             }
         }
@@ -108,8 +153,9 @@ namespace plump_message {
             public Serde.ValueArray<Card> hand;
             public Serde.ValueArray<ulong> guesses;
             public ulong players;
+            public Serde.Option<Serde.ValueArray<ulong>> legal_guesses;
 
-            public RequestGuessContext(Player _player, Serde.ValueArray<Card> _hand, Serde.ValueArray<ulong> _guesses, ulong _players) {
+            public RequestGuessContext(Player _player, Serde.ValueArray<Card> _hand, Serde.ValueArray<ulong> _guesses, ulong _players, Serde.Option<Serde.ValueArray<ulong>> _legal_guesses) {
                 if (_player == null) throw new ArgumentNullException(nameof(_player));
                 player = _player;
                 if (_hand == null) throw new ArgumentNullException(nameof(_hand));
@@ -117,6 +163,7 @@ namespace plump_message {
                 if (_guesses == null) throw new ArgumentNullException(nameof(_guesses));
                 guesses = _guesses;
                 players = _players;
+                legal_guesses = _legal_guesses;
             }
 
             public override void Serialize(Serde.ISerializer serializer) {
@@ -126,6 +173,7 @@ namespace plump_message {
                 TraitHelpers.serialize_vector_Card(hand, serializer);
                 TraitHelpers.serialize_vector_u64(guesses, serializer);
                 serializer.serialize_u64(players);
+                TraitHelpers.serialize_option_vector_u64(legal_guesses, serializer);
                 serializer.decrease_container_depth();
             }
 
@@ -135,7 +183,8 @@ namespace plump_message {
                 	Player.Deserialize(deserializer),
                 	TraitHelpers.deserialize_vector_Card(deserializer),
                 	TraitHelpers.deserialize_vector_u64(deserializer),
-                	deserializer.deserialize_u64());
+                	deserializer.deserialize_u64(),
+                	TraitHelpers.deserialize_option_vector_u64(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
             }
@@ -152,6 +201,7 @@ namespace plump_message {
                 if (!hand.Equals(other.hand)) return false;
                 if (!guesses.Equals(other.guesses)) return false;
                 if (!players.Equals(other.players)) return false;
+                if (!legal_guesses.Equals(other.legal_guesses)) return false;
                 return true;
             }
 
@@ -162,6 +212,7 @@ namespace plump_message {
                     value = 31 * value + hand.GetHashCode();
                     value = 31 * value + guesses.GetHashCode();
                     value = 31 * value + players.GetHashCode();
+                    value = 31 * value + legal_guesses.GetHashCode();
                     return value;
                 }
             }
@@ -214,9 +265,9 @@ namespace plump_message {
         }
 
         public sealed class Turn: Message, IEquatable<Turn>, ICloneable {
-            public Player whose;
+            public PublicPlayer whose;
 
-            public Turn(Player _whose) {
+            public Turn(PublicPlayer _whose) {
                 if (_whose == null) throw new ArgumentNullException(nameof(_whose));
                 whose = _whose;
             }
@@ -231,7 +282,7 @@ namespace plump_message {
             internal static Turn Load(Serde.IDeserializer deserializer) {
                 deserializer.increase_container_depth();
                 Turn obj = new Turn(
-                	Player.Deserialize(deserializer));
+                	PublicPlayer.Deserialize(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
             }
@@ -414,9 +465,9 @@ namespace plump_message {
         }
 
         public sealed class Winner: Message, IEquatable<Winner>, ICloneable {
-            public Player value;
+            public PublicPlayer value;
 
-            public Winner(Player _value) {
+            public Winner(PublicPlayer _value) {
                 if (_value == null) throw new ArgumentNullException(nameof(_value));
                 value = _value;
             }
@@ -431,7 +482,7 @@ namespace plump_message {
             internal static Winner Load(Serde.IDeserializer deserializer) {
                 deserializer.increase_container_depth();
                 Winner obj = new Winner(
-                	Player.Deserialize(deserializer));
+                	PublicPlayer.Deserialize(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
             }
@@ -459,10 +510,10 @@ namespace plump_message {
         }
 
         public sealed class Winners: Message, IEquatable<Winners>, ICloneable {
-            public Serde.ValueArray<Player> players;
+            public Serde.ValueArray<PublicPlayer> players;
             public Serde.ValueArray<ulong> winner_indices;
 
-            public Winners(Serde.ValueArray<Player> _players, Serde.ValueArray<ulong> _winner_indices) {
+            public Winners(Serde.ValueArray<PublicPlayer> _players, Serde.ValueArray<ulong> _winner_indices) {
                 if (_players == null) throw new ArgumentNullException(nameof(_players));
                 players = _players;
                 if (_winner_indices == null) throw new ArgumentNullException(nameof(_winner_indices));
@@ -472,7 +523,7 @@ namespace plump_message {
             public override void Serialize(Serde.ISerializer serializer) {
                 serializer.increase_container_depth();
                 serializer.serialize_variant_index(7);
-                TraitHelpers.serialize_vector_Player(players, serializer);
+                TraitHelpers.serialize_vector_PublicPlayer(players, serializer);
                 TraitHelpers.serialize_vector_u64(winner_indices, serializer);
                 serializer.decrease_container_depth();
             }
@@ -480,7 +531,7 @@ namespace plump_message {
             internal static Winners Load(Serde.IDeserializer deserializer) {
                 deserializer.increase_container_depth();
                 Winners obj = new Winners(
-                	TraitHelpers.deserialize_vector_Player(deserializer),
+                	TraitHelpers.deserialize_vector_PublicPlayer(deserializer),
                 	TraitHelpers.deserialize_vector_u64(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
@@ -549,24 +600,28 @@ namespace plump_message {
         }
 
         public sealed class PlayRequest: Message, IEquatable<PlayRequest>, ICloneable {
-            public Player value;
+            public Player player;
+            public Serde.Option<Serde.ValueArray<ulong>> valid_cards;
 
-            public PlayRequest(Player _value) {
-                if (_value == null) throw new ArgumentNullException(nameof(_value));
-                value = _value;
+            public PlayRequest(Player _player, Serde.Option<Serde.ValueArray<ulong>> _valid_cards) {
+                if (_player == null) throw new ArgumentNullException(nameof(_player));
+                player = _player;
+                valid_cards = _valid_cards;
             }
 
             public override void Serialize(Serde.ISerializer serializer) {
                 serializer.increase_container_depth();
                 serializer.serialize_variant_index(9);
-                value.Serialize(serializer);
+                player.Serialize(serializer);
+                TraitHelpers.serialize_option_vector_u64(valid_cards, serializer);
                 serializer.decrease_container_depth();
             }
 
             internal static PlayRequest Load(Serde.IDeserializer deserializer) {
                 deserializer.increase_container_depth();
                 PlayRequest obj = new PlayRequest(
-                	Player.Deserialize(deserializer));
+                	Player.Deserialize(deserializer),
+                	TraitHelpers.deserialize_option_vector_u64(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
             }
@@ -579,14 +634,16 @@ namespace plump_message {
             public bool Equals(PlayRequest other) {
                 if (other == null) return false;
                 if (ReferenceEquals(this, other)) return true;
-                if (!value.Equals(other.value)) return false;
+                if (!player.Equals(other.player)) return false;
+                if (!valid_cards.Equals(other.valid_cards)) return false;
                 return true;
             }
 
             public override int GetHashCode() {
                 unchecked {
                     int value = 7;
-                    value = 31 * value + value.GetHashCode();
+                    value = 31 * value + player.GetHashCode();
+                    value = 31 * value + valid_cards.GetHashCode();
                     return value;
                 }
             }
@@ -594,19 +651,23 @@ namespace plump_message {
         }
 
         public sealed class RequestGuess: Message, IEquatable<RequestGuess>, ICloneable {
-            public RequestGuess() {
+            public Serde.Option<Serde.ValueArray<ulong>> legal_guesses;
+
+            public RequestGuess(Serde.Option<Serde.ValueArray<ulong>> _legal_guesses) {
+                legal_guesses = _legal_guesses;
             }
 
             public override void Serialize(Serde.ISerializer serializer) {
                 serializer.increase_container_depth();
                 serializer.serialize_variant_index(10);
+                TraitHelpers.serialize_option_vector_u64(legal_guesses, serializer);
                 serializer.decrease_container_depth();
             }
 
             internal static RequestGuess Load(Serde.IDeserializer deserializer) {
                 deserializer.increase_container_depth();
                 RequestGuess obj = new RequestGuess(
-                	);
+                	TraitHelpers.deserialize_option_vector_u64(deserializer));
                 deserializer.decrease_container_depth();
                 return obj;
             }
@@ -619,12 +680,14 @@ namespace plump_message {
             public bool Equals(RequestGuess other) {
                 if (other == null) return false;
                 if (ReferenceEquals(this, other)) return true;
+                if (!legal_guesses.Equals(other.legal_guesses)) return false;
                 return true;
             }
 
             public override int GetHashCode() {
                 unchecked {
                     int value = 7;
+                    value = 31 * value + legal_guesses.GetHashCode();
                     return value;
                 }
             }
@@ -668,6 +731,757 @@ namespace plump_message {
             }
 
         }
+
+        public sealed class Rejected: Message, IEquatable<Rejected>, ICloneable {
+            public Rejection value;
+
+            public Rejected(Rejection _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(12);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Rejected Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Rejected obj = new Rejected(
+                	Rejection.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Rejected other && Equals(other);
+
+            public static bool operator ==(Rejected left, Rejected right) => Equals(left, right);
+
+            public static bool operator !=(Rejected left, Rejected right) => !Equals(left, right);
+
+            public bool Equals(Rejected other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class HandshakeAccepted: Message, IEquatable<HandshakeAccepted>, ICloneable {
+            public uint version;
+            public Encoding encoding;
+            public Serde.ValueArray<Capability> capabilities;
+
+            public HandshakeAccepted(uint _version, Encoding _encoding, Serde.ValueArray<Capability> _capabilities) {
+                version = _version;
+                if (_encoding == null) throw new ArgumentNullException(nameof(_encoding));
+                encoding = _encoding;
+                if (_capabilities == null) throw new ArgumentNullException(nameof(_capabilities));
+                capabilities = _capabilities;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(13);
+                serializer.serialize_u32(version);
+                encoding.Serialize(serializer);
+                TraitHelpers.serialize_vector_Capability(capabilities, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static HandshakeAccepted Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                HandshakeAccepted obj = new HandshakeAccepted(
+                	deserializer.deserialize_u32(),
+                	Encoding.Deserialize(deserializer),
+                	TraitHelpers.deserialize_vector_Capability(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is HandshakeAccepted other && Equals(other);
+
+            public static bool operator ==(HandshakeAccepted left, HandshakeAccepted right) => Equals(left, right);
+
+            public static bool operator !=(HandshakeAccepted left, HandshakeAccepted right) => !Equals(left, right);
+
+            public bool Equals(HandshakeAccepted other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!version.Equals(other.version)) return false;
+                if (!encoding.Equals(other.encoding)) return false;
+                if (!capabilities.Equals(other.capabilities)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + version.GetHashCode();
+                    value = 31 * value + encoding.GetHashCode();
+                    value = 31 * value + capabilities.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class HandshakeRejected: Message, IEquatable<HandshakeRejected>, ICloneable {
+            public HandshakeRejection value;
+
+            public HandshakeRejected(HandshakeRejection _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(14);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static HandshakeRejected Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                HandshakeRejected obj = new HandshakeRejected(
+                	HandshakeRejection.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is HandshakeRejected other && Equals(other);
+
+            public static bool operator ==(HandshakeRejected left, HandshakeRejected right) => Equals(left, right);
+
+            public static bool operator !=(HandshakeRejected left, HandshakeRejected right) => !Equals(left, right);
+
+            public bool Equals(HandshakeRejected other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class ScoreSheet: Message, IEquatable<ScoreSheet>, ICloneable {
+            public plump_message.ScoreSheet value;
+
+            public ScoreSheet(plump_message.ScoreSheet _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(15);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static ScoreSheet Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                ScoreSheet obj = new ScoreSheet(
+                	plump_message.ScoreSheet.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is ScoreSheet other && Equals(other);
+
+            public static bool operator ==(ScoreSheet left, ScoreSheet right) => Equals(left, right);
+
+            public static bool operator !=(ScoreSheet left, ScoreSheet right) => !Equals(left, right);
+
+            public bool Equals(ScoreSheet other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class RoundStart: Message, IEquatable<RoundStart>, ICloneable {
+            public ulong round;
+            public ulong rounds;
+            public ulong hand_size;
+            public PublicPlayer dealer;
+
+            public RoundStart(ulong _round, ulong _rounds, ulong _hand_size, PublicPlayer _dealer) {
+                round = _round;
+                rounds = _rounds;
+                hand_size = _hand_size;
+                if (_dealer == null) throw new ArgumentNullException(nameof(_dealer));
+                dealer = _dealer;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(16);
+                serializer.serialize_u64(round);
+                serializer.serialize_u64(rounds);
+                serializer.serialize_u64(hand_size);
+                dealer.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static RoundStart Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                RoundStart obj = new RoundStart(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64(),
+                	PublicPlayer.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is RoundStart other && Equals(other);
+
+            public static bool operator ==(RoundStart left, RoundStart right) => Equals(left, right);
+
+            public static bool operator !=(RoundStart left, RoundStart right) => !Equals(left, right);
+
+            public bool Equals(RoundStart other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!round.Equals(other.round)) return false;
+                if (!rounds.Equals(other.rounds)) return false;
+                if (!hand_size.Equals(other.hand_size)) return false;
+                if (!dealer.Equals(other.dealer)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + round.GetHashCode();
+                    value = 31 * value + rounds.GetHashCode();
+                    value = 31 * value + hand_size.GetHashCode();
+                    value = 31 * value + dealer.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class RoundEnd: Message, IEquatable<RoundEnd>, ICloneable {
+            public ulong round;
+            public Serde.ValueArray<PublicPlayer> made;
+            public Serde.ValueArray<PublicPlayer> missed;
+
+            public RoundEnd(ulong _round, Serde.ValueArray<PublicPlayer> _made, Serde.ValueArray<PublicPlayer> _missed) {
+                round = _round;
+                if (_made == null) throw new ArgumentNullException(nameof(_made));
+                made = _made;
+                if (_missed == null) throw new ArgumentNullException(nameof(_missed));
+                missed = _missed;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(17);
+                serializer.serialize_u64(round);
+                TraitHelpers.serialize_vector_PublicPlayer(made, serializer);
+                TraitHelpers.serialize_vector_PublicPlayer(missed, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static RoundEnd Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                RoundEnd obj = new RoundEnd(
+                	deserializer.deserialize_u64(),
+                	TraitHelpers.deserialize_vector_PublicPlayer(deserializer),
+                	TraitHelpers.deserialize_vector_PublicPlayer(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is RoundEnd other && Equals(other);
+
+            public static bool operator ==(RoundEnd left, RoundEnd right) => Equals(left, right);
+
+            public static bool operator !=(RoundEnd left, RoundEnd right) => !Equals(left, right);
+
+            public bool Equals(RoundEnd other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!round.Equals(other.round)) return false;
+                if (!made.Equals(other.made)) return false;
+                if (!missed.Equals(other.missed)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + round.GetHashCode();
+                    value = 31 * value + made.GetHashCode();
+                    value = 31 * value + missed.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class LastTrick: Message, IEquatable<LastTrick>, ICloneable {
+            public Serde.Option<plump_message.Trick> value;
+
+            public LastTrick(Serde.Option<plump_message.Trick> _value) {
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(18);
+                TraitHelpers.serialize_option_Trick(value, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static LastTrick Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                LastTrick obj = new LastTrick(
+                	TraitHelpers.deserialize_option_Trick(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is LastTrick other && Equals(other);
+
+            public static bool operator ==(LastTrick left, LastTrick right) => Equals(left, right);
+
+            public static bool operator !=(LastTrick left, LastTrick right) => !Equals(left, right);
+
+            public bool Equals(LastTrick other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class TrickHistory: Message, IEquatable<TrickHistory>, ICloneable {
+            public Serde.ValueArray<plump_message.Trick> value;
+
+            public TrickHistory(Serde.ValueArray<plump_message.Trick> _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(19);
+                TraitHelpers.serialize_vector_Trick(value, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static TrickHistory Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                TrickHistory obj = new TrickHistory(
+                	TraitHelpers.deserialize_vector_Trick(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is TrickHistory other && Equals(other);
+
+            public static bool operator ==(TrickHistory left, TrickHistory right) => Equals(left, right);
+
+            public static bool operator !=(TrickHistory left, TrickHistory right) => !Equals(left, right);
+
+            public bool Equals(TrickHistory other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Chat: Message, IEquatable<Chat>, ICloneable {
+            public PlayerName sender;
+            public string text;
+
+            public Chat(PlayerName _sender, string _text) {
+                if (_sender == null) throw new ArgumentNullException(nameof(_sender));
+                sender = _sender;
+                if (_text == null) throw new ArgumentNullException(nameof(_text));
+                text = _text;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(20);
+                sender.Serialize(serializer);
+                serializer.serialize_str(text);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Chat Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Chat obj = new Chat(
+                	PlayerName.Deserialize(deserializer),
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Chat other && Equals(other);
+
+            public static bool operator ==(Chat left, Chat right) => Equals(left, right);
+
+            public static bool operator !=(Chat left, Chat right) => !Equals(left, right);
+
+            public bool Equals(Chat other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!sender.Equals(other.sender)) return false;
+                if (!text.Equals(other.text)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + sender.GetHashCode();
+                    value = 31 * value + text.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Whisper: Message, IEquatable<Whisper>, ICloneable {
+            public PlayerName sender;
+            public PlayerName to;
+            public string text;
+
+            public Whisper(PlayerName _sender, PlayerName _to, string _text) {
+                if (_sender == null) throw new ArgumentNullException(nameof(_sender));
+                sender = _sender;
+                if (_to == null) throw new ArgumentNullException(nameof(_to));
+                to = _to;
+                if (_text == null) throw new ArgumentNullException(nameof(_text));
+                text = _text;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(21);
+                sender.Serialize(serializer);
+                to.Serialize(serializer);
+                serializer.serialize_str(text);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Whisper Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Whisper obj = new Whisper(
+                	PlayerName.Deserialize(deserializer),
+                	PlayerName.Deserialize(deserializer),
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Whisper other && Equals(other);
+
+            public static bool operator ==(Whisper left, Whisper right) => Equals(left, right);
+
+            public static bool operator !=(Whisper left, Whisper right) => !Equals(left, right);
+
+            public bool Equals(Whisper other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!sender.Equals(other.sender)) return false;
+                if (!to.Equals(other.to)) return false;
+                if (!text.Equals(other.text)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + sender.GetHashCode();
+                    value = 31 * value + to.GetHashCode();
+                    value = 31 * value + text.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Disconnected: Message, IEquatable<Disconnected>, ICloneable {
+            public Disconnect value;
+
+            public Disconnected(Disconnect _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(22);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Disconnected Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Disconnected obj = new Disconnected(
+                	Disconnect.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Disconnected other && Equals(other);
+
+            public static bool operator ==(Disconnected left, Disconnected right) => Equals(left, right);
+
+            public static bool operator !=(Disconnected left, Disconnected right) => !Equals(left, right);
+
+            public bool Equals(Disconnected other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NameRejected: Message, IEquatable<NameRejected>, ICloneable {
+            public NameRejection value;
+
+            public NameRejected(NameRejection _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(23);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NameRejected Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NameRejected obj = new NameRejected(
+                	NameRejection.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NameRejected other && Equals(other);
+
+            public static bool operator ==(NameRejected left, NameRejected right) => Equals(left, right);
+
+            public static bool operator !=(NameRejected left, NameRejected right) => !Equals(left, right);
+
+            public bool Equals(NameRejected other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Lobby: Message, IEquatable<Lobby>, ICloneable {
+            public Serde.ValueArray<LobbyPlayer> players;
+            public ulong seats;
+
+            public Lobby(Serde.ValueArray<LobbyPlayer> _players, ulong _seats) {
+                if (_players == null) throw new ArgumentNullException(nameof(_players));
+                players = _players;
+                seats = _seats;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(24);
+                TraitHelpers.serialize_vector_LobbyPlayer(players, serializer);
+                serializer.serialize_u64(seats);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Lobby Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Lobby obj = new Lobby(
+                	TraitHelpers.deserialize_vector_LobbyPlayer(deserializer),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Lobby other && Equals(other);
+
+            public static bool operator ==(Lobby left, Lobby right) => Equals(left, right);
+
+            public static bool operator !=(Lobby left, Lobby right) => !Equals(left, right);
+
+            public bool Equals(Lobby other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!players.Equals(other.players)) return false;
+                if (!seats.Equals(other.seats)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + players.GetHashCode();
+                    value = 31 * value + seats.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Series: Message, IEquatable<Series>, ICloneable {
+            public uint games;
+            public Serde.ValueArray<SeriesScore> scores;
+
+            public Series(uint _games, Serde.ValueArray<SeriesScore> _scores) {
+                games = _games;
+                if (_scores == null) throw new ArgumentNullException(nameof(_scores));
+                scores = _scores;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(25);
+                serializer.serialize_u32(games);
+                TraitHelpers.serialize_vector_SeriesScore(scores, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Series Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Series obj = new Series(
+                	deserializer.deserialize_u32(),
+                	TraitHelpers.deserialize_vector_SeriesScore(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Series other && Equals(other);
+
+            public static bool operator ==(Series left, Series right) => Equals(left, right);
+
+            public static bool operator !=(Series left, Series right) => !Equals(left, right);
+
+            public bool Equals(Series other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!games.Equals(other.games)) return false;
+                if (!scores.Equals(other.scores)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + games.GetHashCode();
+                    value = 31 * value + scores.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class RejoinToken: Message, IEquatable<RejoinToken>, ICloneable {
+            public string value;
+
+            public RejoinToken(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(26);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static RejoinToken Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                RejoinToken obj = new RejoinToken(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is RejoinToken other && Equals(other);
+
+            public static bool operator ==(RejoinToken left, RejoinToken right) => Equals(left, right);
+
+            public static bool operator !=(RejoinToken left, RejoinToken right) => !Equals(left, right);
+
+            public bool Equals(RejoinToken other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
     }
 
 
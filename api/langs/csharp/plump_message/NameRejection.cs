@@ -0,0 +1,255 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class NameRejection: IEquatable<NameRejection>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static NameRejection Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return Empty.Load(deserializer);
+                case 1: return TooLong.Load(deserializer);
+                case 2: return InvalidCharacter.Load(deserializer);
+                case 3: return Taken.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for NameRejection: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static NameRejection BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static NameRejection BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            NameRejection value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case Empty x: return x.GetHashCode();
+            case TooLong x: return x.GetHashCode();
+            case InvalidCharacter x: return x.GetHashCode();
+            case Taken x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is NameRejection other && Equals(other);
+
+        public bool Equals(NameRejection other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case Empty x: return x.Equals((Empty)other);
+            case TooLong x: return x.Equals((TooLong)other);
+            case InvalidCharacter x: return x.Equals((InvalidCharacter)other);
+            case Taken x: return x.Equals((Taken)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public NameRejection Clone() => (NameRejection)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class Empty: NameRejection, IEquatable<Empty>, ICloneable {
+            public Empty() {
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Empty Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Empty obj = new Empty(
+                	);
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Empty other && Equals(other);
+
+            public static bool operator ==(Empty left, Empty right) => Equals(left, right);
+
+            public static bool operator !=(Empty left, Empty right) => !Equals(left, right);
+
+            public bool Equals(Empty other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class TooLong: NameRejection, IEquatable<TooLong>, ICloneable {
+            public ulong max;
+
+            public TooLong(ulong _max) {
+                max = _max;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.serialize_u64(max);
+                serializer.decrease_container_depth();
+            }
+
+            internal static TooLong Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                TooLong obj = new TooLong(
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is TooLong other && Equals(other);
+
+            public static bool operator ==(TooLong left, TooLong right) => Equals(left, right);
+
+            public static bool operator !=(TooLong left, TooLong right) => !Equals(left, right);
+
+            public bool Equals(TooLong other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!max.Equals(other.max)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + max.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class InvalidCharacter: NameRejection, IEquatable<InvalidCharacter>, ICloneable {
+            public char value;
+
+            public InvalidCharacter(char _value) {
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.serialize_char(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static InvalidCharacter Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                InvalidCharacter obj = new InvalidCharacter(
+                	deserializer.deserialize_char());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is InvalidCharacter other && Equals(other);
+
+            public static bool operator ==(InvalidCharacter left, InvalidCharacter right) => Equals(left, right);
+
+            public static bool operator !=(InvalidCharacter left, InvalidCharacter right) => !Equals(left, right);
+
+            public bool Equals(InvalidCharacter other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class Taken: NameRejection, IEquatable<Taken>, ICloneable {
+            public PlayerName value;
+
+            public Taken(PlayerName _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(3);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static Taken Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                Taken obj = new Taken(
+                	PlayerName.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is Taken other && Equals(other);
+
+            public static bool operator ==(Taken left, Taken right) => Equals(left, right);
+
+            public static bool operator !=(Taken left, Taken right) => !Equals(left, right);
+
+            public bool Equals(Taken other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
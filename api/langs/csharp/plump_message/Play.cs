@@ -0,0 +1,94 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class Play: IEquatable<Play>, ICloneable {
+        public PlayerName player;
+        public Card card;
+
+        public Play(PlayerName _player, Card _card) {
+            if (_player == null) throw new ArgumentNullException(nameof(_player));
+            player = _player;
+            if (_card == null) throw new ArgumentNullException(nameof(_card));
+            card = _card;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            player.Serialize(serializer);
+            card.Serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Play Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            Play obj = new Play(
+            	PlayerName.Deserialize(deserializer),
+            	Card.Deserialize(deserializer));
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static Play BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Play BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Play value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is Play other && Equals(other);
+
+        public static bool operator ==(Play left, Play right) => Equals(left, right);
+
+        public static bool operator !=(Play left, Play right) => !Equals(left, right);
+
+        public bool Equals(Play other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!player.Equals(other.player)) return false;
+            if (!card.Equals(other.card)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + player.GetHashCode();
+                value = 31 * value + card.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Play Clone() => (Play)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
@@ -0,0 +1,666 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public abstract class Rejection: IEquatable<Rejection>, ICloneable {
+
+        public abstract void Serialize(Serde.ISerializer serializer);
+
+        public static Rejection Deserialize(Serde.IDeserializer deserializer) {
+            int index = deserializer.deserialize_variant_index();
+            switch (index) {
+                case 0: return NotANumber.Load(deserializer);
+                case 1: return GuessTooHigh.Load(deserializer);
+                case 2: return ForbiddenGuess.Load(deserializer);
+                case 3: return NoSuchCard.Load(deserializer);
+                case 4: return MustFollowSuit.Load(deserializer);
+                case 5: return NotACard.Load(deserializer);
+                case 6: return CardNotInHand.Load(deserializer);
+                case 7: return ChatTooLong.Load(deserializer);
+                case 8: return UnknownPlayer.Load(deserializer);
+                case 9: return NotHost.Load(deserializer);
+                case 10: return NotReady.Load(deserializer);
+                case 11: return TooFewPlayers.Load(deserializer);
+                default: throw new Serde.DeserializationException("Unknown variant index for Rejection: " + index);
+            }
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static Rejection BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static Rejection BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            Rejection value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override int GetHashCode() {
+            switch (this) {
+            case NotANumber x: return x.GetHashCode();
+            case GuessTooHigh x: return x.GetHashCode();
+            case ForbiddenGuess x: return x.GetHashCode();
+            case NoSuchCard x: return x.GetHashCode();
+            case MustFollowSuit x: return x.GetHashCode();
+            case NotACard x: return x.GetHashCode();
+            case CardNotInHand x: return x.GetHashCode();
+            case ChatTooLong x: return x.GetHashCode();
+            case UnknownPlayer x: return x.GetHashCode();
+            case NotHost x: return x.GetHashCode();
+            case NotReady x: return x.GetHashCode();
+            case TooFewPlayers x: return x.GetHashCode();
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+        public override bool Equals(object obj) => obj is Rejection other && Equals(other);
+
+        public bool Equals(Rejection other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (GetType() != other.GetType()) return false;
+            switch (this) {
+            case NotANumber x: return x.Equals((NotANumber)other);
+            case GuessTooHigh x: return x.Equals((GuessTooHigh)other);
+            case ForbiddenGuess x: return x.Equals((ForbiddenGuess)other);
+            case NoSuchCard x: return x.Equals((NoSuchCard)other);
+            case MustFollowSuit x: return x.Equals((MustFollowSuit)other);
+            case NotACard x: return x.Equals((NotACard)other);
+            case CardNotInHand x: return x.Equals((CardNotInHand)other);
+            case ChatTooLong x: return x.Equals((ChatTooLong)other);
+            case UnknownPlayer x: return x.Equals((UnknownPlayer)other);
+            case NotHost x: return x.Equals((NotHost)other);
+            case NotReady x: return x.Equals((NotReady)other);
+            case TooFewPlayers x: return x.Equals((TooFewPlayers)other);
+            default: throw new InvalidOperationException("Unknown variant type");
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public Rejection Clone() => (Rejection)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+
+        public sealed class NotANumber: Rejection, IEquatable<NotANumber>, ICloneable {
+            public string value;
+
+            public NotANumber(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(0);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NotANumber Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NotANumber obj = new NotANumber(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NotANumber other && Equals(other);
+
+            public static bool operator ==(NotANumber left, NotANumber right) => Equals(left, right);
+
+            public static bool operator !=(NotANumber left, NotANumber right) => !Equals(left, right);
+
+            public bool Equals(NotANumber other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class GuessTooHigh: Rejection, IEquatable<GuessTooHigh>, ICloneable {
+            public ulong guess;
+            public ulong hand_size;
+
+            public GuessTooHigh(ulong _guess, ulong _hand_size) {
+                guess = _guess;
+                hand_size = _hand_size;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(1);
+                serializer.serialize_u64(guess);
+                serializer.serialize_u64(hand_size);
+                serializer.decrease_container_depth();
+            }
+
+            internal static GuessTooHigh Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                GuessTooHigh obj = new GuessTooHigh(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is GuessTooHigh other && Equals(other);
+
+            public static bool operator ==(GuessTooHigh left, GuessTooHigh right) => Equals(left, right);
+
+            public static bool operator !=(GuessTooHigh left, GuessTooHigh right) => !Equals(left, right);
+
+            public bool Equals(GuessTooHigh other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!guess.Equals(other.guess)) return false;
+                if (!hand_size.Equals(other.hand_size)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + guess.GetHashCode();
+                    value = 31 * value + hand_size.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class ForbiddenGuess: Rejection, IEquatable<ForbiddenGuess>, ICloneable {
+            public ulong guess;
+            public ulong hand_size;
+
+            public ForbiddenGuess(ulong _guess, ulong _hand_size) {
+                guess = _guess;
+                hand_size = _hand_size;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(2);
+                serializer.serialize_u64(guess);
+                serializer.serialize_u64(hand_size);
+                serializer.decrease_container_depth();
+            }
+
+            internal static ForbiddenGuess Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                ForbiddenGuess obj = new ForbiddenGuess(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is ForbiddenGuess other && Equals(other);
+
+            public static bool operator ==(ForbiddenGuess left, ForbiddenGuess right) => Equals(left, right);
+
+            public static bool operator !=(ForbiddenGuess left, ForbiddenGuess right) => !Equals(left, right);
+
+            public bool Equals(ForbiddenGuess other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!guess.Equals(other.guess)) return false;
+                if (!hand_size.Equals(other.hand_size)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + guess.GetHashCode();
+                    value = 31 * value + hand_size.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NoSuchCard: Rejection, IEquatable<NoSuchCard>, ICloneable {
+            public ulong index;
+            public ulong hand_size;
+
+            public NoSuchCard(ulong _index, ulong _hand_size) {
+                index = _index;
+                hand_size = _hand_size;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(3);
+                serializer.serialize_u64(index);
+                serializer.serialize_u64(hand_size);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NoSuchCard Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NoSuchCard obj = new NoSuchCard(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NoSuchCard other && Equals(other);
+
+            public static bool operator ==(NoSuchCard left, NoSuchCard right) => Equals(left, right);
+
+            public static bool operator !=(NoSuchCard left, NoSuchCard right) => !Equals(left, right);
+
+            public bool Equals(NoSuchCard other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!index.Equals(other.index)) return false;
+                if (!hand_size.Equals(other.hand_size)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + index.GetHashCode();
+                    value = 31 * value + hand_size.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class MustFollowSuit: Rejection, IEquatable<MustFollowSuit>, ICloneable {
+            public ulong suit;
+
+            public MustFollowSuit(ulong _suit) {
+                suit = _suit;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(4);
+                serializer.serialize_u64(suit);
+                serializer.decrease_container_depth();
+            }
+
+            internal static MustFollowSuit Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                MustFollowSuit obj = new MustFollowSuit(
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is MustFollowSuit other && Equals(other);
+
+            public static bool operator ==(MustFollowSuit left, MustFollowSuit right) => Equals(left, right);
+
+            public static bool operator !=(MustFollowSuit left, MustFollowSuit right) => !Equals(left, right);
+
+            public bool Equals(MustFollowSuit other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!suit.Equals(other.suit)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + suit.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NotACard: Rejection, IEquatable<NotACard>, ICloneable {
+            public string value;
+
+            public NotACard(string _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(5);
+                serializer.serialize_str(value);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NotACard Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NotACard obj = new NotACard(
+                	deserializer.deserialize_str());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NotACard other && Equals(other);
+
+            public static bool operator ==(NotACard left, NotACard right) => Equals(left, right);
+
+            public static bool operator !=(NotACard left, NotACard right) => !Equals(left, right);
+
+            public bool Equals(NotACard other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class CardNotInHand: Rejection, IEquatable<CardNotInHand>, ICloneable {
+            public Card value;
+
+            public CardNotInHand(Card _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(6);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static CardNotInHand Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                CardNotInHand obj = new CardNotInHand(
+                	Card.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is CardNotInHand other && Equals(other);
+
+            public static bool operator ==(CardNotInHand left, CardNotInHand right) => Equals(left, right);
+
+            public static bool operator !=(CardNotInHand left, CardNotInHand right) => !Equals(left, right);
+
+            public bool Equals(CardNotInHand other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class ChatTooLong: Rejection, IEquatable<ChatTooLong>, ICloneable {
+            public ulong length;
+            public ulong max;
+
+            public ChatTooLong(ulong _length, ulong _max) {
+                length = _length;
+                max = _max;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(7);
+                serializer.serialize_u64(length);
+                serializer.serialize_u64(max);
+                serializer.decrease_container_depth();
+            }
+
+            internal static ChatTooLong Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                ChatTooLong obj = new ChatTooLong(
+                	deserializer.deserialize_u64(),
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is ChatTooLong other && Equals(other);
+
+            public static bool operator ==(ChatTooLong left, ChatTooLong right) => Equals(left, right);
+
+            public static bool operator !=(ChatTooLong left, ChatTooLong right) => !Equals(left, right);
+
+            public bool Equals(ChatTooLong other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!length.Equals(other.length)) return false;
+                if (!max.Equals(other.max)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + length.GetHashCode();
+                    value = 31 * value + max.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class UnknownPlayer: Rejection, IEquatable<UnknownPlayer>, ICloneable {
+            public PlayerName value;
+
+            public UnknownPlayer(PlayerName _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(8);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static UnknownPlayer Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                UnknownPlayer obj = new UnknownPlayer(
+                	PlayerName.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is UnknownPlayer other && Equals(other);
+
+            public static bool operator ==(UnknownPlayer left, UnknownPlayer right) => Equals(left, right);
+
+            public static bool operator !=(UnknownPlayer left, UnknownPlayer right) => !Equals(left, right);
+
+            public bool Equals(UnknownPlayer other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NotHost: Rejection, IEquatable<NotHost>, ICloneable {
+            public PlayerName value;
+
+            public NotHost(PlayerName _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(9);
+                value.Serialize(serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NotHost Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NotHost obj = new NotHost(
+                	PlayerName.Deserialize(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NotHost other && Equals(other);
+
+            public static bool operator ==(NotHost left, NotHost right) => Equals(left, right);
+
+            public static bool operator !=(NotHost left, NotHost right) => !Equals(left, right);
+
+            public bool Equals(NotHost other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class NotReady: Rejection, IEquatable<NotReady>, ICloneable {
+            public Serde.ValueArray<PlayerName> value;
+
+            public NotReady(Serde.ValueArray<PlayerName> _value) {
+                if (_value == null) throw new ArgumentNullException(nameof(_value));
+                value = _value;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(10);
+                TraitHelpers.serialize_vector_PlayerName(value, serializer);
+                serializer.decrease_container_depth();
+            }
+
+            internal static NotReady Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                NotReady obj = new NotReady(
+                	TraitHelpers.deserialize_vector_PlayerName(deserializer));
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is NotReady other && Equals(other);
+
+            public static bool operator ==(NotReady left, NotReady right) => Equals(left, right);
+
+            public static bool operator !=(NotReady left, NotReady right) => !Equals(left, right);
+
+            public bool Equals(NotReady other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!value.Equals(other.value)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + value.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+
+        public sealed class TooFewPlayers: Rejection, IEquatable<TooFewPlayers>, ICloneable {
+            public ulong min;
+
+            public TooFewPlayers(ulong _min) {
+                min = _min;
+            }
+
+            public override void Serialize(Serde.ISerializer serializer) {
+                serializer.increase_container_depth();
+                serializer.serialize_variant_index(11);
+                serializer.serialize_u64(min);
+                serializer.decrease_container_depth();
+            }
+
+            internal static TooFewPlayers Load(Serde.IDeserializer deserializer) {
+                deserializer.increase_container_depth();
+                TooFewPlayers obj = new TooFewPlayers(
+                	deserializer.deserialize_u64());
+                deserializer.decrease_container_depth();
+                return obj;
+            }
+            public override bool Equals(object obj) => obj is TooFewPlayers other && Equals(other);
+
+            public static bool operator ==(TooFewPlayers left, TooFewPlayers right) => Equals(left, right);
+
+            public static bool operator !=(TooFewPlayers left, TooFewPlayers right) => !Equals(left, right);
+
+            public bool Equals(TooFewPlayers other) {
+                if (other == null) return false;
+                if (ReferenceEquals(this, other)) return true;
+                if (!min.Equals(other.min)) return false;
+                return true;
+            }
+
+            public override int GetHashCode() {
+                unchecked {
+                    int value = 7;
+                    value = 31 * value + min.GetHashCode();
+                    return value;
+                }
+            }
+
+        }
+    }
+
+
+} // end of namespace plump_message
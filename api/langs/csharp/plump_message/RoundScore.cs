@@ -0,0 +1,98 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class RoundScore: IEquatable<RoundScore>, ICloneable {
+        public ulong guess;
+        public ulong wins;
+        public ulong points;
+
+        public RoundScore(ulong _guess, ulong _wins, ulong _points) {
+            guess = _guess;
+            wins = _wins;
+            points = _points;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            serializer.serialize_u64(guess);
+            serializer.serialize_u64(wins);
+            serializer.serialize_u64(points);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static RoundScore Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            RoundScore obj = new RoundScore(
+            	deserializer.deserialize_u64(),
+            	deserializer.deserialize_u64(),
+            	deserializer.deserialize_u64());
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static RoundScore BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static RoundScore BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            RoundScore value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is RoundScore other && Equals(other);
+
+        public static bool operator ==(RoundScore left, RoundScore right) => Equals(left, right);
+
+        public static bool operator !=(RoundScore left, RoundScore right) => !Equals(left, right);
+
+        public bool Equals(RoundScore other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!guess.Equals(other.guess)) return false;
+            if (!wins.Equals(other.wins)) return false;
+            if (!points.Equals(other.points)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + guess.GetHashCode();
+                value = 31 * value + wins.GetHashCode();
+                value = 31 * value + points.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public RoundScore Clone() => (RoundScore)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
@@ -0,0 +1,94 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class ScoreSheet: IEquatable<ScoreSheet>, ICloneable {
+        public Serde.ValueArray<PlayerName> players;
+        public Serde.ValueArray<ScoreSheetRound> rounds;
+
+        public ScoreSheet(Serde.ValueArray<PlayerName> _players, Serde.ValueArray<ScoreSheetRound> _rounds) {
+            if (_players == null) throw new ArgumentNullException(nameof(_players));
+            players = _players;
+            if (_rounds == null) throw new ArgumentNullException(nameof(_rounds));
+            rounds = _rounds;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            TraitHelpers.serialize_vector_PlayerName(players, serializer);
+            TraitHelpers.serialize_vector_ScoreSheetRound(rounds, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static ScoreSheet Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            ScoreSheet obj = new ScoreSheet(
+            	TraitHelpers.deserialize_vector_PlayerName(deserializer),
+            	TraitHelpers.deserialize_vector_ScoreSheetRound(deserializer));
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static ScoreSheet BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static ScoreSheet BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            ScoreSheet value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is ScoreSheet other && Equals(other);
+
+        public static bool operator ==(ScoreSheet left, ScoreSheet right) => Equals(left, right);
+
+        public static bool operator !=(ScoreSheet left, ScoreSheet right) => !Equals(left, right);
+
+        public bool Equals(ScoreSheet other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!players.Equals(other.players)) return false;
+            if (!rounds.Equals(other.rounds)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + players.GetHashCode();
+                value = 31 * value + rounds.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public ScoreSheet Clone() => (ScoreSheet)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
@@ -0,0 +1,93 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class ScoreSheetRound: IEquatable<ScoreSheetRound>, ICloneable {
+        public ulong hand_size;
+        public Serde.ValueArray<RoundScore> scores;
+
+        public ScoreSheetRound(ulong _hand_size, Serde.ValueArray<RoundScore> _scores) {
+            hand_size = _hand_size;
+            if (_scores == null) throw new ArgumentNullException(nameof(_scores));
+            scores = _scores;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            serializer.serialize_u64(hand_size);
+            TraitHelpers.serialize_vector_RoundScore(scores, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static ScoreSheetRound Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            ScoreSheetRound obj = new ScoreSheetRound(
+            	deserializer.deserialize_u64(),
+            	TraitHelpers.deserialize_vector_RoundScore(deserializer));
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static ScoreSheetRound BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static ScoreSheetRound BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            ScoreSheetRound value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is ScoreSheetRound other && Equals(other);
+
+        public static bool operator ==(ScoreSheetRound left, ScoreSheetRound right) => Equals(left, right);
+
+        public static bool operator !=(ScoreSheetRound left, ScoreSheetRound right) => !Equals(left, right);
+
+        public bool Equals(ScoreSheetRound other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!hand_size.Equals(other.hand_size)) return false;
+            if (!scores.Equals(other.scores)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + hand_size.GetHashCode();
+                value = 31 * value + scores.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public ScoreSheetRound Clone() => (ScoreSheetRound)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
@@ -0,0 +1,99 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Numerics;
+
+namespace plump_message {
+
+    public sealed class SeriesScore: IEquatable<SeriesScore>, ICloneable {
+        public PlayerName name;
+        public uint played;
+        public uint won;
+
+        public SeriesScore(PlayerName _name, uint _played, uint _won) {
+            if (_name == null) throw new ArgumentNullException(nameof(_name));
+            name = _name;
+            played = _played;
+            won = _won;
+        }
+
+        public void Serialize(Serde.ISerializer serializer) {
+            serializer.increase_container_depth();
+            name.Serialize(serializer);
+            serializer.serialize_u32(played);
+            serializer.serialize_u32(won);
+            serializer.decrease_container_depth();
+        }
+
+        public int BincodeSerialize(byte[] outputBuffer) => BincodeSerialize(new ArraySegment<byte>(outputBuffer));
+
+        public int BincodeSerialize(ArraySegment<byte> outputBuffer) {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer(outputBuffer);
+            Serialize(serializer);
+            return serializer.get_buffer_offset();
+        }
+
+        public byte[] BincodeSerialize()  {
+            Serde.ISerializer serializer = new Bincode.BincodeSerializer();
+            Serialize(serializer);
+            return serializer.get_bytes();
+        }
+
+        public static SeriesScore Deserialize(Serde.IDeserializer deserializer) {
+            deserializer.increase_container_depth();
+            SeriesScore obj = new SeriesScore(
+            	PlayerName.Deserialize(deserializer),
+            	deserializer.deserialize_u32(),
+            	deserializer.deserialize_u32());
+            deserializer.decrease_container_depth();
+            return obj;
+        }
+
+        public static SeriesScore BincodeDeserialize(byte[] input) => BincodeDeserialize(new ArraySegment<byte>(input));
+
+        public static SeriesScore BincodeDeserialize(ArraySegment<byte> input) {
+            if (input == null) {
+                 throw new Serde.DeserializationException("Cannot deserialize null array");
+            }
+            Serde.IDeserializer deserializer = new Bincode.BincodeDeserializer(input);
+            SeriesScore value = Deserialize(deserializer);
+            if (deserializer.get_buffer_offset() < input.Count) {
+                 throw new Serde.DeserializationException("Some input bytes were not read");
+            }
+            return value;
+        }
+        public override bool Equals(object obj) => obj is SeriesScore other && Equals(other);
+
+        public static bool operator ==(SeriesScore left, SeriesScore right) => Equals(left, right);
+
+        public static bool operator !=(SeriesScore left, SeriesScore right) => !Equals(left, right);
+
+        public bool Equals(SeriesScore other) {
+            if (other == null) return false;
+            if (ReferenceEquals(this, other)) return true;
+            if (!name.Equals(other.name)) return false;
+            if (!played.Equals(other.played)) return false;
+            if (!won.Equals(other.won)) return false;
+            return true;
+        }
+
+        public override int GetHashCode() {
+            unchecked {
+                int value = 7;
+                value = 31 * value + name.GetHashCode();
+                value = 31 * value + played.GetHashCode();
+                value = 31 * value + won.GetHashCode();
+                return value;
+            }
+        }
+
+        /// <summary>Creates a shallow clone of the object.</summary>
+        public SeriesScore Clone() => (SeriesScore)MemberwiseClone();
+
+        object ICloneable.Clone() => Clone();
+
+    }
+
+} // end of namespace plump_message
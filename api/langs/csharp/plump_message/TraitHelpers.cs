@@ -41,6 +41,42 @@ namespace plump_message {
             return new Serde.ValueDictionary<PlayerName, PublicState>(obj);
         }
 
+        public static void serialize_option_PlayerName(Serde.Option<PlayerName> value, Serde.ISerializer serializer) {
+            if (value.IsSome(out var val)) {
+                serializer.serialize_option_tag(true);
+                val.Serialize(serializer);
+            } else {
+                serializer.serialize_option_tag(false);
+            }
+        }
+
+        public static Serde.Option<PlayerName> deserialize_option_PlayerName(Serde.IDeserializer deserializer) {
+            bool tag = deserializer.deserialize_option_tag();
+            if (!tag) {
+                return Serde.Option<PlayerName>.None;
+            } else {
+                return Serde.Option<PlayerName>.Some(PlayerName.Deserialize(deserializer));
+            }
+        }
+
+        public static void serialize_option_Trick(Serde.Option<Trick> value, Serde.ISerializer serializer) {
+            if (value.IsSome(out var val)) {
+                serializer.serialize_option_tag(true);
+                val.Serialize(serializer);
+            } else {
+                serializer.serialize_option_tag(false);
+            }
+        }
+
+        public static Serde.Option<Trick> deserialize_option_Trick(Serde.IDeserializer deserializer) {
+            bool tag = deserializer.deserialize_option_tag();
+            if (!tag) {
+                return Serde.Option<Trick>.None;
+            } else {
+                return Serde.Option<Trick>.Some(Trick.Deserialize(deserializer));
+            }
+        }
+
         public static void serialize_option_u64(Serde.Option<ulong> value, Serde.ISerializer serializer) {
             if (value.IsSome(out var val)) {
                 serializer.serialize_option_tag(true);
@@ -77,6 +113,22 @@ namespace plump_message {
             }
         }
 
+        public static void serialize_vector_Capability(Serde.ValueArray<Capability> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<Capability> deserialize_vector_Capability(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            Capability[] obj = new Capability[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = Capability.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<Capability>(obj);
+        }
+
         public static void serialize_vector_Card(Serde.ValueArray<Card> value, Serde.ISerializer serializer) {
             serializer.serialize_len(value.Count);
             foreach (var item in value) {
@@ -93,20 +145,132 @@ namespace plump_message {
             return new Serde.ValueArray<Card>(obj);
         }
 
-        public static void serialize_vector_Player(Serde.ValueArray<Player> value, Serde.ISerializer serializer) {
+        public static void serialize_vector_LobbyPlayer(Serde.ValueArray<LobbyPlayer> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<LobbyPlayer> deserialize_vector_LobbyPlayer(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            LobbyPlayer[] obj = new LobbyPlayer[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = LobbyPlayer.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<LobbyPlayer>(obj);
+        }
+
+        public static void serialize_vector_Play(Serde.ValueArray<Play> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<Play> deserialize_vector_Play(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            Play[] obj = new Play[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = Play.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<Play>(obj);
+        }
+
+        public static void serialize_vector_PlayerName(Serde.ValueArray<PlayerName> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<PlayerName> deserialize_vector_PlayerName(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            PlayerName[] obj = new PlayerName[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = PlayerName.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<PlayerName>(obj);
+        }
+
+        public static void serialize_vector_PublicPlayer(Serde.ValueArray<PublicPlayer> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<PublicPlayer> deserialize_vector_PublicPlayer(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            PublicPlayer[] obj = new PublicPlayer[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = PublicPlayer.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<PublicPlayer>(obj);
+        }
+
+        public static void serialize_vector_RoundScore(Serde.ValueArray<RoundScore> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<RoundScore> deserialize_vector_RoundScore(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            RoundScore[] obj = new RoundScore[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = RoundScore.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<RoundScore>(obj);
+        }
+
+        public static void serialize_vector_ScoreSheetRound(Serde.ValueArray<ScoreSheetRound> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<ScoreSheetRound> deserialize_vector_ScoreSheetRound(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            ScoreSheetRound[] obj = new ScoreSheetRound[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = ScoreSheetRound.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<ScoreSheetRound>(obj);
+        }
+
+        public static void serialize_vector_SeriesScore(Serde.ValueArray<SeriesScore> value, Serde.ISerializer serializer) {
+            serializer.serialize_len(value.Count);
+            foreach (var item in value) {
+                item.Serialize(serializer);
+            }
+        }
+
+        public static Serde.ValueArray<SeriesScore> deserialize_vector_SeriesScore(Serde.IDeserializer deserializer) {
+            long length = deserializer.deserialize_len();
+            SeriesScore[] obj = new SeriesScore[length];
+            for (int i = 0; i < length; i++) {
+                obj[i] = SeriesScore.Deserialize(deserializer);
+            }
+            return new Serde.ValueArray<SeriesScore>(obj);
+        }
+
+        public static void serialize_vector_Trick(Serde.ValueArray<Trick> value, Serde.ISerializer serializer) {
             serializer.serialize_len(value.Count);
             foreach (var item in value) {
                 item.Serialize(serializer);
             }
         }
 
-        public static Serde.ValueArray<Player> deserialize_vector_Player(Serde.IDeserializer deserializer) {
+        public static Serde.ValueArray<Trick> deserialize_vector_Trick(Serde.IDeserializer deserializer) {
             long length = deserializer.deserialize_len();
-            Player[] obj = new Player[length];
+            Trick[] obj = new Trick[length];
             for (int i = 0; i < length; i++) {
-                obj[i] = Player.Deserialize(deserializer);
+                obj[i] = Trick.Deserialize(deserializer);
             }
-            return new Serde.ValueArray<Player>(obj);
+            return new Serde.ValueArray<Trick>(obj);
         }
 
         public static void serialize_vector_u64(Serde.ValueArray<ulong> value, Serde.ISerializer serializer) {
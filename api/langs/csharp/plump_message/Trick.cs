@@ -8,16 +8,22 @@ using System.Numerics;
 namespace plump_message {
 
     public sealed class Trick: IEquatable<Trick>, ICloneable {
-        public Serde.ValueArray<Card> value;
-
-        public Trick(Serde.ValueArray<Card> _value) {
-            if (_value == null) throw new ArgumentNullException(nameof(_value));
-            value = _value;
+        public Serde.ValueArray<Play> plays;
+        public Serde.Option<ulong> led_suit;
+        public Serde.Option<ulong> winning;
+
+        public Trick(Serde.ValueArray<Play> _plays, Serde.Option<ulong> _led_suit, Serde.Option<ulong> _winning) {
+            if (_plays == null) throw new ArgumentNullException(nameof(_plays));
+            plays = _plays;
+            led_suit = _led_suit;
+            winning = _winning;
         }
 
         public void Serialize(Serde.ISerializer serializer) {
             serializer.increase_container_depth();
-            TraitHelpers.serialize_vector_Card(value, serializer);
+            TraitHelpers.serialize_vector_Play(plays, serializer);
+            TraitHelpers.serialize_option_u64(led_suit, serializer);
+            TraitHelpers.serialize_option_u64(winning, serializer);
             serializer.decrease_container_depth();
         }
 
@@ -38,7 +44,9 @@ namespace plump_message {
         public static Trick Deserialize(Serde.IDeserializer deserializer) {
             deserializer.increase_container_depth();
             Trick obj = new Trick(
-            	TraitHelpers.deserialize_vector_Card(deserializer));
+            	TraitHelpers.deserialize_vector_Play(deserializer),
+            	TraitHelpers.deserialize_option_u64(deserializer),
+            	TraitHelpers.deserialize_option_u64(deserializer));
             deserializer.decrease_container_depth();
             return obj;
         }
@@ -65,14 +73,18 @@ namespace plump_message {
         public bool Equals(Trick other) {
             if (other == null) return false;
             if (ReferenceEquals(this, other)) return true;
-            if (!value.Equals(other.value)) return false;
+            if (!plays.Equals(other.plays)) return false;
+            if (!led_suit.Equals(other.led_suit)) return false;
+            if (!winning.Equals(other.winning)) return false;
             return true;
         }
 
         public override int GetHashCode() {
             unchecked {
                 int value = 7;
-                value = 31 * value + value.GetHashCode();
+                value = 31 * value + plays.GetHashCode();
+                value = 31 * value + led_suit.GetHashCode();
+                value = 31 * value + winning.GetHashCode();
                 return value;
             }
         }
@@ -1,22 +1,17 @@
 package plump_message;
 
 
-public final class Card {
-    public final @com.novi.serde.Unsigned Long suit;
-    public final @com.novi.serde.Unsigned Long value;
-
-    public Card(@com.novi.serde.Unsigned Long suit, @com.novi.serde.Unsigned Long value) {
-        java.util.Objects.requireNonNull(suit, "suit must not be null");
-        java.util.Objects.requireNonNull(value, "value must not be null");
-        this.suit = suit;
-        this.value = value;
-    }
+public abstract class Card {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
 
-    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
-        serializer.increase_container_depth();
-        serializer.serialize_u64(suit);
-        serializer.serialize_u64(value);
-        serializer.decrease_container_depth();
+    public static Card deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return Suited.load(deserializer);
+            case 1: return Joker.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for Card: " + index);
+        }
     }
 
     public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
@@ -25,15 +20,6 @@ public final class Card {
         return serializer.get_bytes();
     }
 
-    public static Card deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
-        deserializer.increase_container_depth();
-        Builder builder = new Builder();
-        builder.suit = deserializer.deserialize_u64();
-        builder.value = deserializer.deserialize_u64();
-        deserializer.decrease_container_depth();
-        return builder.build();
-    }
-
     public static Card bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
         if (input == null) {
              throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
@@ -46,32 +32,111 @@ public final class Card {
         return value;
     }
 
-    public boolean equals(Object obj) {
-        if (this == obj) return true;
-        if (obj == null) return false;
-        if (getClass() != obj.getClass()) return false;
-        Card other = (Card) obj;
-        if (!java.util.Objects.equals(this.suit, other.suit)) { return false; }
-        if (!java.util.Objects.equals(this.value, other.value)) { return false; }
-        return true;
-    }
+    public static final class Suited extends Card {
+        public final @com.novi.serde.Unsigned Long suit;
+        public final @com.novi.serde.Unsigned Long value;
 
-    public int hashCode() {
-        int value = 7;
-        value = 31 * value + (this.suit != null ? this.suit.hashCode() : 0);
-        value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
-        return value;
+        public Suited(@com.novi.serde.Unsigned Long suit, @com.novi.serde.Unsigned Long value) {
+            java.util.Objects.requireNonNull(suit, "suit must not be null");
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.suit = suit;
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.serialize_u64(suit);
+            serializer.serialize_u64(value);
+            serializer.decrease_container_depth();
+        }
+
+        static Suited load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.suit = deserializer.deserialize_u64();
+            builder.value = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Suited other = (Suited) obj;
+            if (!java.util.Objects.equals(this.suit, other.suit)) { return false; }
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.suit != null ? this.suit.hashCode() : 0);
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long suit;
+            public @com.novi.serde.Unsigned Long value;
+
+            public Suited build() {
+                return new Suited(
+                    suit,
+                    value
+                );
+            }
+        }
     }
 
-    public static final class Builder {
-        public @com.novi.serde.Unsigned Long suit;
-        public @com.novi.serde.Unsigned Long value;
+    public static final class Joker extends Card {
+        public final Colour value;
+
+        public Joker(Colour value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Joker load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = Colour.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Joker other = (Joker) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Colour value;
 
-        public Card build() {
-            return new Card(
-                suit,
-                value
-            );
+            public Joker build() {
+                return new Joker(
+                    value
+                );
+            }
         }
     }
 }
+
@@ -0,0 +1,527 @@
+package plump_message;
+
+
+public abstract class ClientMessage {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+    public static ClientMessage deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return Reply.load(deserializer);
+            case 1: return Hello.load(deserializer);
+            case 2: return LastTrick.load(deserializer);
+            case 3: return TrickHistory.load(deserializer);
+            case 4: return Chat.load(deserializer);
+            case 5: return Mute.load(deserializer);
+            case 6: return Unmute.load(deserializer);
+            case 7: return Ready.load(deserializer);
+            case 8: return Start.load(deserializer);
+            case 9: return Leave.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for ClientMessage: " + index);
+        }
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static ClientMessage bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        ClientMessage value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public static final class Reply extends ClientMessage {
+        public final String value;
+
+        public Reply(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static Reply load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Reply other = (Reply) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public Reply build() {
+                return new Reply(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Hello extends ClientMessage {
+        public final @com.novi.serde.Unsigned Integer version;
+        public final Encoding encoding;
+        public final java.util.List<Capability> capabilities;
+
+        public Hello(@com.novi.serde.Unsigned Integer version, Encoding encoding, java.util.List<Capability> capabilities) {
+            java.util.Objects.requireNonNull(version, "version must not be null");
+            java.util.Objects.requireNonNull(encoding, "encoding must not be null");
+            java.util.Objects.requireNonNull(capabilities, "capabilities must not be null");
+            this.version = version;
+            this.encoding = encoding;
+            this.capabilities = capabilities;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            serializer.serialize_u32(version);
+            encoding.serialize(serializer);
+            TraitHelpers.serialize_vector_Capability(capabilities, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Hello load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.version = deserializer.deserialize_u32();
+            builder.encoding = Encoding.deserialize(deserializer);
+            builder.capabilities = TraitHelpers.deserialize_vector_Capability(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Hello other = (Hello) obj;
+            if (!java.util.Objects.equals(this.version, other.version)) { return false; }
+            if (!java.util.Objects.equals(this.encoding, other.encoding)) { return false; }
+            if (!java.util.Objects.equals(this.capabilities, other.capabilities)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.version != null ? this.version.hashCode() : 0);
+            value = 31 * value + (this.encoding != null ? this.encoding.hashCode() : 0);
+            value = 31 * value + (this.capabilities != null ? this.capabilities.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Integer version;
+            public Encoding encoding;
+            public java.util.List<Capability> capabilities;
+
+            public Hello build() {
+                return new Hello(
+                    version,
+                    encoding,
+                    capabilities
+                );
+            }
+        }
+    }
+
+    public static final class LastTrick extends ClientMessage {
+        public LastTrick() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(2);
+            serializer.decrease_container_depth();
+        }
+
+        static LastTrick load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            LastTrick other = (LastTrick) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public LastTrick build() {
+                return new LastTrick(
+                );
+            }
+        }
+    }
+
+    public static final class TrickHistory extends ClientMessage {
+        public TrickHistory() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(3);
+            serializer.decrease_container_depth();
+        }
+
+        static TrickHistory load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            TrickHistory other = (TrickHistory) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public TrickHistory build() {
+                return new TrickHistory(
+                );
+            }
+        }
+    }
+
+    public static final class Chat extends ClientMessage {
+        public final java.util.Optional<PlayerName> to;
+        public final String text;
+
+        public Chat(java.util.Optional<PlayerName> to, String text) {
+            java.util.Objects.requireNonNull(to, "to must not be null");
+            java.util.Objects.requireNonNull(text, "text must not be null");
+            this.to = to;
+            this.text = text;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(4);
+            TraitHelpers.serialize_option_PlayerName(to, serializer);
+            serializer.serialize_str(text);
+            serializer.decrease_container_depth();
+        }
+
+        static Chat load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.to = TraitHelpers.deserialize_option_PlayerName(deserializer);
+            builder.text = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Chat other = (Chat) obj;
+            if (!java.util.Objects.equals(this.to, other.to)) { return false; }
+            if (!java.util.Objects.equals(this.text, other.text)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.to != null ? this.to.hashCode() : 0);
+            value = 31 * value + (this.text != null ? this.text.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public java.util.Optional<PlayerName> to;
+            public String text;
+
+            public Chat build() {
+                return new Chat(
+                    to,
+                    text
+                );
+            }
+        }
+    }
+
+    public static final class Mute extends ClientMessage {
+        public final PlayerName value;
+
+        public Mute(PlayerName value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(5);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Mute load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = PlayerName.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Mute other = (Mute) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName value;
+
+            public Mute build() {
+                return new Mute(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Unmute extends ClientMessage {
+        public final PlayerName value;
+
+        public Unmute(PlayerName value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(6);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Unmute load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = PlayerName.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Unmute other = (Unmute) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName value;
+
+            public Unmute build() {
+                return new Unmute(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Ready extends ClientMessage {
+        public final Boolean value;
+
+        public Ready(Boolean value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(7);
+            serializer.serialize_bool(value);
+            serializer.decrease_container_depth();
+        }
+
+        static Ready load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_bool();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Ready other = (Ready) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Boolean value;
+
+            public Ready build() {
+                return new Ready(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Start extends ClientMessage {
+        public final Boolean bots;
+
+        public Start(Boolean bots) {
+            java.util.Objects.requireNonNull(bots, "bots must not be null");
+            this.bots = bots;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(8);
+            serializer.serialize_bool(bots);
+            serializer.decrease_container_depth();
+        }
+
+        static Start load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.bots = deserializer.deserialize_bool();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Start other = (Start) obj;
+            if (!java.util.Objects.equals(this.bots, other.bots)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.bots != null ? this.bots.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Boolean bots;
+
+            public Start build() {
+                return new Start(
+                    bots
+                );
+            }
+        }
+    }
+
+    public static final class Leave extends ClientMessage {
+        public Leave() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(9);
+            serializer.decrease_container_depth();
+        }
+
+        static Leave load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Leave other = (Leave) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public Leave build() {
+                return new Leave(
+                );
+            }
+        }
+    }
+}
+
@@ -0,0 +1,111 @@
+package plump_message;
+
+
+public abstract class Colour {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+    public static Colour deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return Red.load(deserializer);
+            case 1: return Black.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for Colour: " + index);
+        }
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static Colour bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        Colour value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public static final class Red extends Colour {
+        public Red() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.decrease_container_depth();
+        }
+
+        static Red load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Red other = (Red) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public Red build() {
+                return new Red(
+                );
+            }
+        }
+    }
+
+    public static final class Black extends Colour {
+        public Black() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            serializer.decrease_container_depth();
+        }
+
+        static Black load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Black other = (Black) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public Black build() {
+                return new Black(
+                );
+            }
+        }
+    }
+}
+
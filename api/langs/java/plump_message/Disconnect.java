@@ -0,0 +1,250 @@
+package plump_message;
+
+
+public abstract class Disconnect {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+    public static Disconnect deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return LineTooLong.load(deserializer);
+            case 1: return InvalidUtf8.load(deserializer);
+            case 2: return Malformed.load(deserializer);
+            case 3: return Idle.load(deserializer);
+            case 4: return NoSeat.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for Disconnect: " + index);
+        }
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static Disconnect bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        Disconnect value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public static final class LineTooLong extends Disconnect {
+        public final @com.novi.serde.Unsigned Long max;
+
+        public LineTooLong(@com.novi.serde.Unsigned Long max) {
+            java.util.Objects.requireNonNull(max, "max must not be null");
+            this.max = max;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.serialize_u64(max);
+            serializer.decrease_container_depth();
+        }
+
+        static LineTooLong load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.max = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            LineTooLong other = (LineTooLong) obj;
+            if (!java.util.Objects.equals(this.max, other.max)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.max != null ? this.max.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long max;
+
+            public LineTooLong build() {
+                return new LineTooLong(
+                    max
+                );
+            }
+        }
+    }
+
+    public static final class InvalidUtf8 extends Disconnect {
+        public InvalidUtf8() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            serializer.decrease_container_depth();
+        }
+
+        static InvalidUtf8 load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            InvalidUtf8 other = (InvalidUtf8) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public InvalidUtf8 build() {
+                return new InvalidUtf8(
+                );
+            }
+        }
+    }
+
+    public static final class Malformed extends Disconnect {
+        public final String value;
+
+        public Malformed(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(2);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static Malformed load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Malformed other = (Malformed) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public Malformed build() {
+                return new Malformed(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Idle extends Disconnect {
+        public Idle() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(3);
+            serializer.decrease_container_depth();
+        }
+
+        static Idle load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Idle other = (Idle) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public Idle build() {
+                return new Idle(
+                );
+            }
+        }
+    }
+
+    public static final class NoSeat extends Disconnect {
+        public NoSeat() {
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(4);
+            serializer.decrease_container_depth();
+        }
+
+        static NoSeat load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NoSeat other = (NoSeat) obj;
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            return value;
+        }
+
+        public static final class Builder {
+            public NoSeat build() {
+                return new NoSeat(
+                );
+            }
+        }
+    }
+}
+
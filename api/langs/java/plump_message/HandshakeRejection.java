@@ -0,0 +1,142 @@
+package plump_message;
+
+
+public abstract class HandshakeRejection {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+    public static HandshakeRejection deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return UnsupportedVersion.load(deserializer);
+            case 1: return Malformed.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for HandshakeRejection: " + index);
+        }
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static HandshakeRejection bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        HandshakeRejection value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public static final class UnsupportedVersion extends HandshakeRejection {
+        public final @com.novi.serde.Unsigned Integer requested;
+        public final @com.novi.serde.Unsigned Integer supported;
+
+        public UnsupportedVersion(@com.novi.serde.Unsigned Integer requested, @com.novi.serde.Unsigned Integer supported) {
+            java.util.Objects.requireNonNull(requested, "requested must not be null");
+            java.util.Objects.requireNonNull(supported, "supported must not be null");
+            this.requested = requested;
+            this.supported = supported;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.serialize_u32(requested);
+            serializer.serialize_u32(supported);
+            serializer.decrease_container_depth();
+        }
+
+        static UnsupportedVersion load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.requested = deserializer.deserialize_u32();
+            builder.supported = deserializer.deserialize_u32();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            UnsupportedVersion other = (UnsupportedVersion) obj;
+            if (!java.util.Objects.equals(this.requested, other.requested)) { return false; }
+            if (!java.util.Objects.equals(this.supported, other.supported)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.requested != null ? this.requested.hashCode() : 0);
+            value = 31 * value + (this.supported != null ? this.supported.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Integer requested;
+            public @com.novi.serde.Unsigned Integer supported;
+
+            public UnsupportedVersion build() {
+                return new UnsupportedVersion(
+                    requested,
+                    supported
+                );
+            }
+        }
+    }
+
+    public static final class Malformed extends HandshakeRejection {
+        public final String value;
+
+        public Malformed(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static Malformed load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Malformed other = (Malformed) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public Malformed build() {
+                return new Malformed(
+                    value
+                );
+            }
+        }
+    }
+}
+
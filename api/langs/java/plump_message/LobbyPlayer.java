@@ -0,0 +1,77 @@
+package plump_message;
+
+
+public final class LobbyPlayer {
+    public final PlayerName name;
+    public final Boolean ready;
+
+    public LobbyPlayer(PlayerName name, Boolean ready) {
+        java.util.Objects.requireNonNull(name, "name must not be null");
+        java.util.Objects.requireNonNull(ready, "ready must not be null");
+        this.name = name;
+        this.ready = ready;
+    }
+
+    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.increase_container_depth();
+        name.serialize(serializer);
+        serializer.serialize_bool(ready);
+        serializer.decrease_container_depth();
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static LobbyPlayer deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        deserializer.increase_container_depth();
+        Builder builder = new Builder();
+        builder.name = PlayerName.deserialize(deserializer);
+        builder.ready = deserializer.deserialize_bool();
+        deserializer.decrease_container_depth();
+        return builder.build();
+    }
+
+    public static LobbyPlayer bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        LobbyPlayer value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public boolean equals(Object obj) {
+        if (this == obj) return true;
+        if (obj == null) return false;
+        if (getClass() != obj.getClass()) return false;
+        LobbyPlayer other = (LobbyPlayer) obj;
+        if (!java.util.Objects.equals(this.name, other.name)) { return false; }
+        if (!java.util.Objects.equals(this.ready, other.ready)) { return false; }
+        return true;
+    }
+
+    public int hashCode() {
+        int value = 7;
+        value = 31 * value + (this.name != null ? this.name.hashCode() : 0);
+        value = 31 * value + (this.ready != null ? this.ready.hashCode() : 0);
+        return value;
+    }
+
+    public static final class Builder {
+        public PlayerName name;
+        public Boolean ready;
+
+        public LobbyPlayer build() {
+            return new LobbyPlayer(
+                name,
+                ready
+            );
+        }
+    }
+}
@@ -20,6 +20,21 @@ public abstract class Message {
             case 9: return PlayRequest.load(deserializer);
             case 10: return RequestGuess.load(deserializer);
             case 11: return GameOver.load(deserializer);
+            case 12: return Rejected.load(deserializer);
+            case 13: return HandshakeAccepted.load(deserializer);
+            case 14: return HandshakeRejected.load(deserializer);
+            case 15: return ScoreSheet.load(deserializer);
+            case 16: return RoundStart.load(deserializer);
+            case 17: return RoundEnd.load(deserializer);
+            case 18: return LastTrick.load(deserializer);
+            case 19: return TrickHistory.load(deserializer);
+            case 20: return Chat.load(deserializer);
+            case 21: return Whisper.load(deserializer);
+            case 22: return Disconnected.load(deserializer);
+            case 23: return NameRejected.load(deserializer);
+            case 24: return Lobby.load(deserializer);
+            case 25: return Series.load(deserializer);
+            case 26: return RejoinToken.load(deserializer);
             default: throw new com.novi.serde.DeserializationError("Unknown variant index for Message: " + index);
         }
     }
@@ -47,16 +62,19 @@ public abstract class Message {
         public final java.util.List<Card> hand;
         public final java.util.List<@com.novi.serde.Unsigned Long> guesses;
         public final @com.novi.serde.Unsigned Long players;
+        public final java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses;
 
-        public RequestGuessContext(Player player, java.util.List<Card> hand, java.util.List<@com.novi.serde.Unsigned Long> guesses, @com.novi.serde.Unsigned Long players) {
+        public RequestGuessContext(Player player, java.util.List<Card> hand, java.util.List<@com.novi.serde.Unsigned Long> guesses, @com.novi.serde.Unsigned Long players, java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses) {
             java.util.Objects.requireNonNull(player, "player must not be null");
             java.util.Objects.requireNonNull(hand, "hand must not be null");
             java.util.Objects.requireNonNull(guesses, "guesses must not be null");
             java.util.Objects.requireNonNull(players, "players must not be null");
+            java.util.Objects.requireNonNull(legal_guesses, "legal_guesses must not be null");
             this.player = player;
             this.hand = hand;
             this.guesses = guesses;
             this.players = players;
+            this.legal_guesses = legal_guesses;
         }
 
         public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
@@ -66,6 +84,7 @@ public abstract class Message {
             TraitHelpers.serialize_vector_Card(hand, serializer);
             TraitHelpers.serialize_vector_u64(guesses, serializer);
             serializer.serialize_u64(players);
+            TraitHelpers.serialize_option_vector_u64(legal_guesses, serializer);
             serializer.decrease_container_depth();
         }
 
@@ -76,6 +95,7 @@ public abstract class Message {
             builder.hand = TraitHelpers.deserialize_vector_Card(deserializer);
             builder.guesses = TraitHelpers.deserialize_vector_u64(deserializer);
             builder.players = deserializer.deserialize_u64();
+            builder.legal_guesses = TraitHelpers.deserialize_option_vector_u64(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
         }
@@ -89,6 +109,7 @@ public abstract class Message {
             if (!java.util.Objects.equals(this.hand, other.hand)) { return false; }
             if (!java.util.Objects.equals(this.guesses, other.guesses)) { return false; }
             if (!java.util.Objects.equals(this.players, other.players)) { return false; }
+            if (!java.util.Objects.equals(this.legal_guesses, other.legal_guesses)) { return false; }
             return true;
         }
 
@@ -98,6 +119,7 @@ public abstract class Message {
             value = 31 * value + (this.hand != null ? this.hand.hashCode() : 0);
             value = 31 * value + (this.guesses != null ? this.guesses.hashCode() : 0);
             value = 31 * value + (this.players != null ? this.players.hashCode() : 0);
+            value = 31 * value + (this.legal_guesses != null ? this.legal_guesses.hashCode() : 0);
             return value;
         }
 
@@ -106,13 +128,15 @@ public abstract class Message {
             public java.util.List<Card> hand;
             public java.util.List<@com.novi.serde.Unsigned Long> guesses;
             public @com.novi.serde.Unsigned Long players;
+            public java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses;
 
             public RequestGuessContext build() {
                 return new RequestGuessContext(
                     player,
                     hand,
                     guesses,
-                    players
+                    players,
+                    legal_guesses
                 );
             }
         }
@@ -168,9 +192,9 @@ public abstract class Message {
     }
 
     public static final class Turn extends Message {
-        public final Player whose;
+        public final PublicPlayer whose;
 
-        public Turn(Player whose) {
+        public Turn(PublicPlayer whose) {
             java.util.Objects.requireNonNull(whose, "whose must not be null");
             this.whose = whose;
         }
@@ -185,7 +209,7 @@ public abstract class Message {
         static Turn load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
             deserializer.increase_container_depth();
             Builder builder = new Builder();
-            builder.whose = Player.deserialize(deserializer);
+            builder.whose = PublicPlayer.deserialize(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
         }
@@ -206,7 +230,7 @@ public abstract class Message {
         }
 
         public static final class Builder {
-            public Player whose;
+            public PublicPlayer whose;
 
             public Turn build() {
                 return new Turn(
@@ -391,9 +415,9 @@ public abstract class Message {
     }
 
     public static final class Winner extends Message {
-        public final Player value;
+        public final PublicPlayer value;
 
-        public Winner(Player value) {
+        public Winner(PublicPlayer value) {
             java.util.Objects.requireNonNull(value, "value must not be null");
             this.value = value;
         }
@@ -408,7 +432,7 @@ public abstract class Message {
         static Winner load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
             deserializer.increase_container_depth();
             Builder builder = new Builder();
-            builder.value = Player.deserialize(deserializer);
+            builder.value = PublicPlayer.deserialize(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
         }
@@ -429,7 +453,7 @@ public abstract class Message {
         }
 
         public static final class Builder {
-            public Player value;
+            public PublicPlayer value;
 
             public Winner build() {
                 return new Winner(
@@ -440,10 +464,10 @@ public abstract class Message {
     }
 
     public static final class Winners extends Message {
-        public final java.util.List<Player> players;
+        public final java.util.List<PublicPlayer> players;
         public final java.util.List<@com.novi.serde.Unsigned Long> winner_indices;
 
-        public Winners(java.util.List<Player> players, java.util.List<@com.novi.serde.Unsigned Long> winner_indices) {
+        public Winners(java.util.List<PublicPlayer> players, java.util.List<@com.novi.serde.Unsigned Long> winner_indices) {
             java.util.Objects.requireNonNull(players, "players must not be null");
             java.util.Objects.requireNonNull(winner_indices, "winner_indices must not be null");
             this.players = players;
@@ -453,7 +477,7 @@ public abstract class Message {
         public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
             serializer.increase_container_depth();
             serializer.serialize_variant_index(7);
-            TraitHelpers.serialize_vector_Player(players, serializer);
+            TraitHelpers.serialize_vector_PublicPlayer(players, serializer);
             TraitHelpers.serialize_vector_u64(winner_indices, serializer);
             serializer.decrease_container_depth();
         }
@@ -461,7 +485,7 @@ public abstract class Message {
         static Winners load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
             deserializer.increase_container_depth();
             Builder builder = new Builder();
-            builder.players = TraitHelpers.deserialize_vector_Player(deserializer);
+            builder.players = TraitHelpers.deserialize_vector_PublicPlayer(deserializer);
             builder.winner_indices = TraitHelpers.deserialize_vector_u64(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
@@ -485,7 +509,7 @@ public abstract class Message {
         }
 
         public static final class Builder {
-            public java.util.List<Player> players;
+            public java.util.List<PublicPlayer> players;
             public java.util.List<@com.novi.serde.Unsigned Long> winner_indices;
 
             public Winners build() {
@@ -536,24 +560,29 @@ public abstract class Message {
     }
 
     public static final class PlayRequest extends Message {
-        public final Player value;
+        public final Player player;
+        public final java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> valid_cards;
 
-        public PlayRequest(Player value) {
-            java.util.Objects.requireNonNull(value, "value must not be null");
-            this.value = value;
+        public PlayRequest(Player player, java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> valid_cards) {
+            java.util.Objects.requireNonNull(player, "player must not be null");
+            java.util.Objects.requireNonNull(valid_cards, "valid_cards must not be null");
+            this.player = player;
+            this.valid_cards = valid_cards;
         }
 
         public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
             serializer.increase_container_depth();
             serializer.serialize_variant_index(9);
-            value.serialize(serializer);
+            player.serialize(serializer);
+            TraitHelpers.serialize_option_vector_u64(valid_cards, serializer);
             serializer.decrease_container_depth();
         }
 
         static PlayRequest load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
             deserializer.increase_container_depth();
             Builder builder = new Builder();
-            builder.value = Player.deserialize(deserializer);
+            builder.player = Player.deserialize(deserializer);
+            builder.valid_cards = TraitHelpers.deserialize_option_vector_u64(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
         }
@@ -563,40 +592,50 @@ public abstract class Message {
             if (obj == null) return false;
             if (getClass() != obj.getClass()) return false;
             PlayRequest other = (PlayRequest) obj;
-            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            if (!java.util.Objects.equals(this.player, other.player)) { return false; }
+            if (!java.util.Objects.equals(this.valid_cards, other.valid_cards)) { return false; }
             return true;
         }
 
         public int hashCode() {
             int value = 7;
-            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            value = 31 * value + (this.player != null ? this.player.hashCode() : 0);
+            value = 31 * value + (this.valid_cards != null ? this.valid_cards.hashCode() : 0);
             return value;
         }
 
         public static final class Builder {
-            public Player value;
+            public Player player;
+            public java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> valid_cards;
 
             public PlayRequest build() {
                 return new PlayRequest(
-                    value
+                    player,
+                    valid_cards
                 );
             }
         }
     }
 
     public static final class RequestGuess extends Message {
-        public RequestGuess() {
+        public final java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses;
+
+        public RequestGuess(java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses) {
+            java.util.Objects.requireNonNull(legal_guesses, "legal_guesses must not be null");
+            this.legal_guesses = legal_guesses;
         }
 
         public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
             serializer.increase_container_depth();
             serializer.serialize_variant_index(10);
+            TraitHelpers.serialize_option_vector_u64(legal_guesses, serializer);
             serializer.decrease_container_depth();
         }
 
         static RequestGuess load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
             deserializer.increase_container_depth();
             Builder builder = new Builder();
+            builder.legal_guesses = TraitHelpers.deserialize_option_vector_u64(deserializer);
             deserializer.decrease_container_depth();
             return builder.build();
         }
@@ -606,17 +645,22 @@ public abstract class Message {
             if (obj == null) return false;
             if (getClass() != obj.getClass()) return false;
             RequestGuess other = (RequestGuess) obj;
+            if (!java.util.Objects.equals(this.legal_guesses, other.legal_guesses)) { return false; }
             return true;
         }
 
         public int hashCode() {
             int value = 7;
+            value = 31 * value + (this.legal_guesses != null ? this.legal_guesses.hashCode() : 0);
             return value;
         }
 
         public static final class Builder {
+            public java.util.Optional<java.util.List<@com.novi.serde.Unsigned Long>> legal_guesses;
+
             public RequestGuess build() {
                 return new RequestGuess(
+                    legal_guesses
                 );
             }
         }
@@ -659,5 +703,848 @@ public abstract class Message {
             }
         }
     }
+
+    public static final class Rejected extends Message {
+        public final Rejection value;
+
+        public Rejected(Rejection value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(12);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Rejected load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = Rejection.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Rejected other = (Rejected) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Rejection value;
+
+            public Rejected build() {
+                return new Rejected(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class HandshakeAccepted extends Message {
+        public final @com.novi.serde.Unsigned Integer version;
+        public final Encoding encoding;
+        public final java.util.List<Capability> capabilities;
+
+        public HandshakeAccepted(@com.novi.serde.Unsigned Integer version, Encoding encoding, java.util.List<Capability> capabilities) {
+            java.util.Objects.requireNonNull(version, "version must not be null");
+            java.util.Objects.requireNonNull(encoding, "encoding must not be null");
+            java.util.Objects.requireNonNull(capabilities, "capabilities must not be null");
+            this.version = version;
+            this.encoding = encoding;
+            this.capabilities = capabilities;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(13);
+            serializer.serialize_u32(version);
+            encoding.serialize(serializer);
+            TraitHelpers.serialize_vector_Capability(capabilities, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static HandshakeAccepted load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.version = deserializer.deserialize_u32();
+            builder.encoding = Encoding.deserialize(deserializer);
+            builder.capabilities = TraitHelpers.deserialize_vector_Capability(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            HandshakeAccepted other = (HandshakeAccepted) obj;
+            if (!java.util.Objects.equals(this.version, other.version)) { return false; }
+            if (!java.util.Objects.equals(this.encoding, other.encoding)) { return false; }
+            if (!java.util.Objects.equals(this.capabilities, other.capabilities)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.version != null ? this.version.hashCode() : 0);
+            value = 31 * value + (this.encoding != null ? this.encoding.hashCode() : 0);
+            value = 31 * value + (this.capabilities != null ? this.capabilities.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Integer version;
+            public Encoding encoding;
+            public java.util.List<Capability> capabilities;
+
+            public HandshakeAccepted build() {
+                return new HandshakeAccepted(
+                    version,
+                    encoding,
+                    capabilities
+                );
+            }
+        }
+    }
+
+    public static final class HandshakeRejected extends Message {
+        public final HandshakeRejection value;
+
+        public HandshakeRejected(HandshakeRejection value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(14);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static HandshakeRejected load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = HandshakeRejection.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            HandshakeRejected other = (HandshakeRejected) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public HandshakeRejection value;
+
+            public HandshakeRejected build() {
+                return new HandshakeRejected(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class ScoreSheet extends Message {
+        public final plump_message.ScoreSheet value;
+
+        public ScoreSheet(plump_message.ScoreSheet value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(15);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static ScoreSheet load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = plump_message.ScoreSheet.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            ScoreSheet other = (ScoreSheet) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public plump_message.ScoreSheet value;
+
+            public ScoreSheet build() {
+                return new ScoreSheet(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class RoundStart extends Message {
+        public final @com.novi.serde.Unsigned Long round;
+        public final @com.novi.serde.Unsigned Long rounds;
+        public final @com.novi.serde.Unsigned Long hand_size;
+        public final PublicPlayer dealer;
+
+        public RoundStart(@com.novi.serde.Unsigned Long round, @com.novi.serde.Unsigned Long rounds, @com.novi.serde.Unsigned Long hand_size, PublicPlayer dealer) {
+            java.util.Objects.requireNonNull(round, "round must not be null");
+            java.util.Objects.requireNonNull(rounds, "rounds must not be null");
+            java.util.Objects.requireNonNull(hand_size, "hand_size must not be null");
+            java.util.Objects.requireNonNull(dealer, "dealer must not be null");
+            this.round = round;
+            this.rounds = rounds;
+            this.hand_size = hand_size;
+            this.dealer = dealer;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(16);
+            serializer.serialize_u64(round);
+            serializer.serialize_u64(rounds);
+            serializer.serialize_u64(hand_size);
+            dealer.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static RoundStart load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.round = deserializer.deserialize_u64();
+            builder.rounds = deserializer.deserialize_u64();
+            builder.hand_size = deserializer.deserialize_u64();
+            builder.dealer = PublicPlayer.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            RoundStart other = (RoundStart) obj;
+            if (!java.util.Objects.equals(this.round, other.round)) { return false; }
+            if (!java.util.Objects.equals(this.rounds, other.rounds)) { return false; }
+            if (!java.util.Objects.equals(this.hand_size, other.hand_size)) { return false; }
+            if (!java.util.Objects.equals(this.dealer, other.dealer)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.round != null ? this.round.hashCode() : 0);
+            value = 31 * value + (this.rounds != null ? this.rounds.hashCode() : 0);
+            value = 31 * value + (this.hand_size != null ? this.hand_size.hashCode() : 0);
+            value = 31 * value + (this.dealer != null ? this.dealer.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long round;
+            public @com.novi.serde.Unsigned Long rounds;
+            public @com.novi.serde.Unsigned Long hand_size;
+            public PublicPlayer dealer;
+
+            public RoundStart build() {
+                return new RoundStart(
+                    round,
+                    rounds,
+                    hand_size,
+                    dealer
+                );
+            }
+        }
+    }
+
+    public static final class RoundEnd extends Message {
+        public final @com.novi.serde.Unsigned Long round;
+        public final java.util.List<PublicPlayer> made;
+        public final java.util.List<PublicPlayer> missed;
+
+        public RoundEnd(@com.novi.serde.Unsigned Long round, java.util.List<PublicPlayer> made, java.util.List<PublicPlayer> missed) {
+            java.util.Objects.requireNonNull(round, "round must not be null");
+            java.util.Objects.requireNonNull(made, "made must not be null");
+            java.util.Objects.requireNonNull(missed, "missed must not be null");
+            this.round = round;
+            this.made = made;
+            this.missed = missed;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(17);
+            serializer.serialize_u64(round);
+            TraitHelpers.serialize_vector_PublicPlayer(made, serializer);
+            TraitHelpers.serialize_vector_PublicPlayer(missed, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static RoundEnd load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.round = deserializer.deserialize_u64();
+            builder.made = TraitHelpers.deserialize_vector_PublicPlayer(deserializer);
+            builder.missed = TraitHelpers.deserialize_vector_PublicPlayer(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            RoundEnd other = (RoundEnd) obj;
+            if (!java.util.Objects.equals(this.round, other.round)) { return false; }
+            if (!java.util.Objects.equals(this.made, other.made)) { return false; }
+            if (!java.util.Objects.equals(this.missed, other.missed)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.round != null ? this.round.hashCode() : 0);
+            value = 31 * value + (this.made != null ? this.made.hashCode() : 0);
+            value = 31 * value + (this.missed != null ? this.missed.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long round;
+            public java.util.List<PublicPlayer> made;
+            public java.util.List<PublicPlayer> missed;
+
+            public RoundEnd build() {
+                return new RoundEnd(
+                    round,
+                    made,
+                    missed
+                );
+            }
+        }
+    }
+
+    public static final class LastTrick extends Message {
+        public final java.util.Optional<plump_message.Trick> value;
+
+        public LastTrick(java.util.Optional<plump_message.Trick> value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(18);
+            TraitHelpers.serialize_option_Trick(value, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static LastTrick load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = TraitHelpers.deserialize_option_Trick(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            LastTrick other = (LastTrick) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public java.util.Optional<plump_message.Trick> value;
+
+            public LastTrick build() {
+                return new LastTrick(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class TrickHistory extends Message {
+        public final java.util.List<plump_message.Trick> value;
+
+        public TrickHistory(java.util.List<plump_message.Trick> value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(19);
+            TraitHelpers.serialize_vector_Trick(value, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static TrickHistory load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = TraitHelpers.deserialize_vector_Trick(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            TrickHistory other = (TrickHistory) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public java.util.List<plump_message.Trick> value;
+
+            public TrickHistory build() {
+                return new TrickHistory(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Chat extends Message {
+        public final PlayerName sender;
+        public final String text;
+
+        public Chat(PlayerName sender, String text) {
+            java.util.Objects.requireNonNull(sender, "sender must not be null");
+            java.util.Objects.requireNonNull(text, "text must not be null");
+            this.sender = sender;
+            this.text = text;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(20);
+            sender.serialize(serializer);
+            serializer.serialize_str(text);
+            serializer.decrease_container_depth();
+        }
+
+        static Chat load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.sender = PlayerName.deserialize(deserializer);
+            builder.text = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Chat other = (Chat) obj;
+            if (!java.util.Objects.equals(this.sender, other.sender)) { return false; }
+            if (!java.util.Objects.equals(this.text, other.text)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.sender != null ? this.sender.hashCode() : 0);
+            value = 31 * value + (this.text != null ? this.text.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName sender;
+            public String text;
+
+            public Chat build() {
+                return new Chat(
+                    sender,
+                    text
+                );
+            }
+        }
+    }
+
+    public static final class Whisper extends Message {
+        public final PlayerName sender;
+        public final PlayerName to;
+        public final String text;
+
+        public Whisper(PlayerName sender, PlayerName to, String text) {
+            java.util.Objects.requireNonNull(sender, "sender must not be null");
+            java.util.Objects.requireNonNull(to, "to must not be null");
+            java.util.Objects.requireNonNull(text, "text must not be null");
+            this.sender = sender;
+            this.to = to;
+            this.text = text;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(21);
+            sender.serialize(serializer);
+            to.serialize(serializer);
+            serializer.serialize_str(text);
+            serializer.decrease_container_depth();
+        }
+
+        static Whisper load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.sender = PlayerName.deserialize(deserializer);
+            builder.to = PlayerName.deserialize(deserializer);
+            builder.text = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Whisper other = (Whisper) obj;
+            if (!java.util.Objects.equals(this.sender, other.sender)) { return false; }
+            if (!java.util.Objects.equals(this.to, other.to)) { return false; }
+            if (!java.util.Objects.equals(this.text, other.text)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.sender != null ? this.sender.hashCode() : 0);
+            value = 31 * value + (this.to != null ? this.to.hashCode() : 0);
+            value = 31 * value + (this.text != null ? this.text.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName sender;
+            public PlayerName to;
+            public String text;
+
+            public Whisper build() {
+                return new Whisper(
+                    sender,
+                    to,
+                    text
+                );
+            }
+        }
+    }
+
+    public static final class Disconnected extends Message {
+        public final Disconnect value;
+
+        public Disconnected(Disconnect value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(22);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Disconnected load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = Disconnect.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Disconnected other = (Disconnected) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Disconnect value;
+
+            public Disconnected build() {
+                return new Disconnected(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class NameRejected extends Message {
+        public final NameRejection value;
+
+        public NameRejected(NameRejection value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(23);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static NameRejected load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = NameRejection.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NameRejected other = (NameRejected) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public NameRejection value;
+
+            public NameRejected build() {
+                return new NameRejected(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class Lobby extends Message {
+        public final java.util.List<LobbyPlayer> players;
+        public final @com.novi.serde.Unsigned Long seats;
+
+        public Lobby(java.util.List<LobbyPlayer> players, @com.novi.serde.Unsigned Long seats) {
+            java.util.Objects.requireNonNull(players, "players must not be null");
+            java.util.Objects.requireNonNull(seats, "seats must not be null");
+            this.players = players;
+            this.seats = seats;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(24);
+            TraitHelpers.serialize_vector_LobbyPlayer(players, serializer);
+            serializer.serialize_u64(seats);
+            serializer.decrease_container_depth();
+        }
+
+        static Lobby load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.players = TraitHelpers.deserialize_vector_LobbyPlayer(deserializer);
+            builder.seats = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Lobby other = (Lobby) obj;
+            if (!java.util.Objects.equals(this.players, other.players)) { return false; }
+            if (!java.util.Objects.equals(this.seats, other.seats)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.players != null ? this.players.hashCode() : 0);
+            value = 31 * value + (this.seats != null ? this.seats.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public java.util.List<LobbyPlayer> players;
+            public @com.novi.serde.Unsigned Long seats;
+
+            public Lobby build() {
+                return new Lobby(
+                    players,
+                    seats
+                );
+            }
+        }
+    }
+
+    public static final class Series extends Message {
+        public final @com.novi.serde.Unsigned Integer games;
+        public final java.util.List<SeriesScore> scores;
+
+        public Series(@com.novi.serde.Unsigned Integer games, java.util.List<SeriesScore> scores) {
+            java.util.Objects.requireNonNull(games, "games must not be null");
+            java.util.Objects.requireNonNull(scores, "scores must not be null");
+            this.games = games;
+            this.scores = scores;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(25);
+            serializer.serialize_u32(games);
+            TraitHelpers.serialize_vector_SeriesScore(scores, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static Series load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.games = deserializer.deserialize_u32();
+            builder.scores = TraitHelpers.deserialize_vector_SeriesScore(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            Series other = (Series) obj;
+            if (!java.util.Objects.equals(this.games, other.games)) { return false; }
+            if (!java.util.Objects.equals(this.scores, other.scores)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.games != null ? this.games.hashCode() : 0);
+            value = 31 * value + (this.scores != null ? this.scores.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Integer games;
+            public java.util.List<SeriesScore> scores;
+
+            public Series build() {
+                return new Series(
+                    games,
+                    scores
+                );
+            }
+        }
+    }
+
+    public static final class RejoinToken extends Message {
+        public final String value;
+
+        public RejoinToken(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(26);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static RejoinToken load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            RejoinToken other = (RejoinToken) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public RejoinToken build() {
+                return new RejoinToken(
+                    value
+                );
+            }
+        }
+    }
 }
 
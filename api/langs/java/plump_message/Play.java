@@ -0,0 +1,77 @@
+package plump_message;
+
+
+public final class Play {
+    public final PlayerName player;
+    public final Card card;
+
+    public Play(PlayerName player, Card card) {
+        java.util.Objects.requireNonNull(player, "player must not be null");
+        java.util.Objects.requireNonNull(card, "card must not be null");
+        this.player = player;
+        this.card = card;
+    }
+
+    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.increase_container_depth();
+        player.serialize(serializer);
+        card.serialize(serializer);
+        serializer.decrease_container_depth();
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static Play deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        deserializer.increase_container_depth();
+        Builder builder = new Builder();
+        builder.player = PlayerName.deserialize(deserializer);
+        builder.card = Card.deserialize(deserializer);
+        deserializer.decrease_container_depth();
+        return builder.build();
+    }
+
+    public static Play bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        Play value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public boolean equals(Object obj) {
+        if (this == obj) return true;
+        if (obj == null) return false;
+        if (getClass() != obj.getClass()) return false;
+        Play other = (Play) obj;
+        if (!java.util.Objects.equals(this.player, other.player)) { return false; }
+        if (!java.util.Objects.equals(this.card, other.card)) { return false; }
+        return true;
+    }
+
+    public int hashCode() {
+        int value = 7;
+        value = 31 * value + (this.player != null ? this.player.hashCode() : 0);
+        value = 31 * value + (this.card != null ? this.card.hashCode() : 0);
+        return value;
+    }
+
+    public static final class Builder {
+        public PlayerName player;
+        public Card card;
+
+        public Play build() {
+            return new Play(
+                player,
+                card
+            );
+        }
+    }
+}
@@ -0,0 +1,669 @@
+package plump_message;
+
+
+public abstract class Rejection {
+
+    abstract public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError;
+
+    public static Rejection deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        int index = deserializer.deserialize_variant_index();
+        switch (index) {
+            case 0: return NotANumber.load(deserializer);
+            case 1: return GuessTooHigh.load(deserializer);
+            case 2: return ForbiddenGuess.load(deserializer);
+            case 3: return NoSuchCard.load(deserializer);
+            case 4: return MustFollowSuit.load(deserializer);
+            case 5: return NotACard.load(deserializer);
+            case 6: return CardNotInHand.load(deserializer);
+            case 7: return ChatTooLong.load(deserializer);
+            case 8: return UnknownPlayer.load(deserializer);
+            case 9: return NotHost.load(deserializer);
+            case 10: return NotReady.load(deserializer);
+            case 11: return TooFewPlayers.load(deserializer);
+            default: throw new com.novi.serde.DeserializationError("Unknown variant index for Rejection: " + index);
+        }
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static Rejection bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        Rejection value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public static final class NotANumber extends Rejection {
+        public final String value;
+
+        public NotANumber(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(0);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static NotANumber load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NotANumber other = (NotANumber) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public NotANumber build() {
+                return new NotANumber(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class GuessTooHigh extends Rejection {
+        public final @com.novi.serde.Unsigned Long guess;
+        public final @com.novi.serde.Unsigned Long hand_size;
+
+        public GuessTooHigh(@com.novi.serde.Unsigned Long guess, @com.novi.serde.Unsigned Long hand_size) {
+            java.util.Objects.requireNonNull(guess, "guess must not be null");
+            java.util.Objects.requireNonNull(hand_size, "hand_size must not be null");
+            this.guess = guess;
+            this.hand_size = hand_size;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(1);
+            serializer.serialize_u64(guess);
+            serializer.serialize_u64(hand_size);
+            serializer.decrease_container_depth();
+        }
+
+        static GuessTooHigh load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.guess = deserializer.deserialize_u64();
+            builder.hand_size = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            GuessTooHigh other = (GuessTooHigh) obj;
+            if (!java.util.Objects.equals(this.guess, other.guess)) { return false; }
+            if (!java.util.Objects.equals(this.hand_size, other.hand_size)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.guess != null ? this.guess.hashCode() : 0);
+            value = 31 * value + (this.hand_size != null ? this.hand_size.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long guess;
+            public @com.novi.serde.Unsigned Long hand_size;
+
+            public GuessTooHigh build() {
+                return new GuessTooHigh(
+                    guess,
+                    hand_size
+                );
+            }
+        }
+    }
+
+    public static final class ForbiddenGuess extends Rejection {
+        public final @com.novi.serde.Unsigned Long guess;
+        public final @com.novi.serde.Unsigned Long hand_size;
+
+        public ForbiddenGuess(@com.novi.serde.Unsigned Long guess, @com.novi.serde.Unsigned Long hand_size) {
+            java.util.Objects.requireNonNull(guess, "guess must not be null");
+            java.util.Objects.requireNonNull(hand_size, "hand_size must not be null");
+            this.guess = guess;
+            this.hand_size = hand_size;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(2);
+            serializer.serialize_u64(guess);
+            serializer.serialize_u64(hand_size);
+            serializer.decrease_container_depth();
+        }
+
+        static ForbiddenGuess load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.guess = deserializer.deserialize_u64();
+            builder.hand_size = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            ForbiddenGuess other = (ForbiddenGuess) obj;
+            if (!java.util.Objects.equals(this.guess, other.guess)) { return false; }
+            if (!java.util.Objects.equals(this.hand_size, other.hand_size)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.guess != null ? this.guess.hashCode() : 0);
+            value = 31 * value + (this.hand_size != null ? this.hand_size.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long guess;
+            public @com.novi.serde.Unsigned Long hand_size;
+
+            public ForbiddenGuess build() {
+                return new ForbiddenGuess(
+                    guess,
+                    hand_size
+                );
+            }
+        }
+    }
+
+    public static final class NoSuchCard extends Rejection {
+        public final @com.novi.serde.Unsigned Long index;
+        public final @com.novi.serde.Unsigned Long hand_size;
+
+        public NoSuchCard(@com.novi.serde.Unsigned Long index, @com.novi.serde.Unsigned Long hand_size) {
+            java.util.Objects.requireNonNull(index, "index must not be null");
+            java.util.Objects.requireNonNull(hand_size, "hand_size must not be null");
+            this.index = index;
+            this.hand_size = hand_size;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(3);
+            serializer.serialize_u64(index);
+            serializer.serialize_u64(hand_size);
+            serializer.decrease_container_depth();
+        }
+
+        static NoSuchCard load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.index = deserializer.deserialize_u64();
+            builder.hand_size = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NoSuchCard other = (NoSuchCard) obj;
+            if (!java.util.Objects.equals(this.index, other.index)) { return false; }
+            if (!java.util.Objects.equals(this.hand_size, other.hand_size)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.index != null ? this.index.hashCode() : 0);
+            value = 31 * value + (this.hand_size != null ? this.hand_size.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long index;
+            public @com.novi.serde.Unsigned Long hand_size;
+
+            public NoSuchCard build() {
+                return new NoSuchCard(
+                    index,
+                    hand_size
+                );
+            }
+        }
+    }
+
+    public static final class MustFollowSuit extends Rejection {
+        public final @com.novi.serde.Unsigned Long suit;
+
+        public MustFollowSuit(@com.novi.serde.Unsigned Long suit) {
+            java.util.Objects.requireNonNull(suit, "suit must not be null");
+            this.suit = suit;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(4);
+            serializer.serialize_u64(suit);
+            serializer.decrease_container_depth();
+        }
+
+        static MustFollowSuit load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.suit = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            MustFollowSuit other = (MustFollowSuit) obj;
+            if (!java.util.Objects.equals(this.suit, other.suit)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.suit != null ? this.suit.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long suit;
+
+            public MustFollowSuit build() {
+                return new MustFollowSuit(
+                    suit
+                );
+            }
+        }
+    }
+
+    public static final class NotACard extends Rejection {
+        public final String value;
+
+        public NotACard(String value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(5);
+            serializer.serialize_str(value);
+            serializer.decrease_container_depth();
+        }
+
+        static NotACard load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = deserializer.deserialize_str();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NotACard other = (NotACard) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public String value;
+
+            public NotACard build() {
+                return new NotACard(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class CardNotInHand extends Rejection {
+        public final Card value;
+
+        public CardNotInHand(Card value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(6);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static CardNotInHand load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = Card.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            CardNotInHand other = (CardNotInHand) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public Card value;
+
+            public CardNotInHand build() {
+                return new CardNotInHand(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class ChatTooLong extends Rejection {
+        public final @com.novi.serde.Unsigned Long length;
+        public final @com.novi.serde.Unsigned Long max;
+
+        public ChatTooLong(@com.novi.serde.Unsigned Long length, @com.novi.serde.Unsigned Long max) {
+            java.util.Objects.requireNonNull(length, "length must not be null");
+            java.util.Objects.requireNonNull(max, "max must not be null");
+            this.length = length;
+            this.max = max;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(7);
+            serializer.serialize_u64(length);
+            serializer.serialize_u64(max);
+            serializer.decrease_container_depth();
+        }
+
+        static ChatTooLong load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.length = deserializer.deserialize_u64();
+            builder.max = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            ChatTooLong other = (ChatTooLong) obj;
+            if (!java.util.Objects.equals(this.length, other.length)) { return false; }
+            if (!java.util.Objects.equals(this.max, other.max)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.length != null ? this.length.hashCode() : 0);
+            value = 31 * value + (this.max != null ? this.max.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long length;
+            public @com.novi.serde.Unsigned Long max;
+
+            public ChatTooLong build() {
+                return new ChatTooLong(
+                    length,
+                    max
+                );
+            }
+        }
+    }
+
+    public static final class UnknownPlayer extends Rejection {
+        public final PlayerName value;
+
+        public UnknownPlayer(PlayerName value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(8);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static UnknownPlayer load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = PlayerName.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            UnknownPlayer other = (UnknownPlayer) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName value;
+
+            public UnknownPlayer build() {
+                return new UnknownPlayer(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class NotHost extends Rejection {
+        public final PlayerName value;
+
+        public NotHost(PlayerName value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(9);
+            value.serialize(serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static NotHost load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = PlayerName.deserialize(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NotHost other = (NotHost) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public PlayerName value;
+
+            public NotHost build() {
+                return new NotHost(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class NotReady extends Rejection {
+        public final java.util.List<PlayerName> value;
+
+        public NotReady(java.util.List<PlayerName> value) {
+            java.util.Objects.requireNonNull(value, "value must not be null");
+            this.value = value;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(10);
+            TraitHelpers.serialize_vector_PlayerName(value, serializer);
+            serializer.decrease_container_depth();
+        }
+
+        static NotReady load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.value = TraitHelpers.deserialize_vector_PlayerName(deserializer);
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            NotReady other = (NotReady) obj;
+            if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public java.util.List<PlayerName> value;
+
+            public NotReady build() {
+                return new NotReady(
+                    value
+                );
+            }
+        }
+    }
+
+    public static final class TooFewPlayers extends Rejection {
+        public final @com.novi.serde.Unsigned Long min;
+
+        public TooFewPlayers(@com.novi.serde.Unsigned Long min) {
+            java.util.Objects.requireNonNull(min, "min must not be null");
+            this.min = min;
+        }
+
+        public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+            serializer.increase_container_depth();
+            serializer.serialize_variant_index(11);
+            serializer.serialize_u64(min);
+            serializer.decrease_container_depth();
+        }
+
+        static TooFewPlayers load(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+            deserializer.increase_container_depth();
+            Builder builder = new Builder();
+            builder.min = deserializer.deserialize_u64();
+            deserializer.decrease_container_depth();
+            return builder.build();
+        }
+
+        public boolean equals(Object obj) {
+            if (this == obj) return true;
+            if (obj == null) return false;
+            if (getClass() != obj.getClass()) return false;
+            TooFewPlayers other = (TooFewPlayers) obj;
+            if (!java.util.Objects.equals(this.min, other.min)) { return false; }
+            return true;
+        }
+
+        public int hashCode() {
+            int value = 7;
+            value = 31 * value + (this.min != null ? this.min.hashCode() : 0);
+            return value;
+        }
+
+        public static final class Builder {
+            public @com.novi.serde.Unsigned Long min;
+
+            public TooFewPlayers build() {
+                return new TooFewPlayers(
+                    min
+                );
+            }
+        }
+    }
+}
+
@@ -0,0 +1,77 @@
+package plump_message;
+
+
+public final class ScoreSheet {
+    public final java.util.List<PlayerName> players;
+    public final java.util.List<ScoreSheetRound> rounds;
+
+    public ScoreSheet(java.util.List<PlayerName> players, java.util.List<ScoreSheetRound> rounds) {
+        java.util.Objects.requireNonNull(players, "players must not be null");
+        java.util.Objects.requireNonNull(rounds, "rounds must not be null");
+        this.players = players;
+        this.rounds = rounds;
+    }
+
+    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.increase_container_depth();
+        TraitHelpers.serialize_vector_PlayerName(players, serializer);
+        TraitHelpers.serialize_vector_ScoreSheetRound(rounds, serializer);
+        serializer.decrease_container_depth();
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static ScoreSheet deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        deserializer.increase_container_depth();
+        Builder builder = new Builder();
+        builder.players = TraitHelpers.deserialize_vector_PlayerName(deserializer);
+        builder.rounds = TraitHelpers.deserialize_vector_ScoreSheetRound(deserializer);
+        deserializer.decrease_container_depth();
+        return builder.build();
+    }
+
+    public static ScoreSheet bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        ScoreSheet value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public boolean equals(Object obj) {
+        if (this == obj) return true;
+        if (obj == null) return false;
+        if (getClass() != obj.getClass()) return false;
+        ScoreSheet other = (ScoreSheet) obj;
+        if (!java.util.Objects.equals(this.players, other.players)) { return false; }
+        if (!java.util.Objects.equals(this.rounds, other.rounds)) { return false; }
+        return true;
+    }
+
+    public int hashCode() {
+        int value = 7;
+        value = 31 * value + (this.players != null ? this.players.hashCode() : 0);
+        value = 31 * value + (this.rounds != null ? this.rounds.hashCode() : 0);
+        return value;
+    }
+
+    public static final class Builder {
+        public java.util.List<PlayerName> players;
+        public java.util.List<ScoreSheetRound> rounds;
+
+        public ScoreSheet build() {
+            return new ScoreSheet(
+                players,
+                rounds
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+package plump_message;
+
+
+public final class ScoreSheetRound {
+    public final @com.novi.serde.Unsigned Long hand_size;
+    public final java.util.List<RoundScore> scores;
+
+    public ScoreSheetRound(@com.novi.serde.Unsigned Long hand_size, java.util.List<RoundScore> scores) {
+        java.util.Objects.requireNonNull(hand_size, "hand_size must not be null");
+        java.util.Objects.requireNonNull(scores, "scores must not be null");
+        this.hand_size = hand_size;
+        this.scores = scores;
+    }
+
+    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.increase_container_depth();
+        serializer.serialize_u64(hand_size);
+        TraitHelpers.serialize_vector_RoundScore(scores, serializer);
+        serializer.decrease_container_depth();
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static ScoreSheetRound deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        deserializer.increase_container_depth();
+        Builder builder = new Builder();
+        builder.hand_size = deserializer.deserialize_u64();
+        builder.scores = TraitHelpers.deserialize_vector_RoundScore(deserializer);
+        deserializer.decrease_container_depth();
+        return builder.build();
+    }
+
+    public static ScoreSheetRound bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        ScoreSheetRound value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public boolean equals(Object obj) {
+        if (this == obj) return true;
+        if (obj == null) return false;
+        if (getClass() != obj.getClass()) return false;
+        ScoreSheetRound other = (ScoreSheetRound) obj;
+        if (!java.util.Objects.equals(this.hand_size, other.hand_size)) { return false; }
+        if (!java.util.Objects.equals(this.scores, other.scores)) { return false; }
+        return true;
+    }
+
+    public int hashCode() {
+        int value = 7;
+        value = 31 * value + (this.hand_size != null ? this.hand_size.hashCode() : 0);
+        value = 31 * value + (this.scores != null ? this.scores.hashCode() : 0);
+        return value;
+    }
+
+    public static final class Builder {
+        public @com.novi.serde.Unsigned Long hand_size;
+        public java.util.List<RoundScore> scores;
+
+        public ScoreSheetRound build() {
+            return new ScoreSheetRound(
+                hand_size,
+                scores
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+package plump_message;
+
+
+public final class SeriesScore {
+    public final PlayerName name;
+    public final @com.novi.serde.Unsigned Integer played;
+    public final @com.novi.serde.Unsigned Integer won;
+
+    public SeriesScore(PlayerName name, @com.novi.serde.Unsigned Integer played, @com.novi.serde.Unsigned Integer won) {
+        java.util.Objects.requireNonNull(name, "name must not be null");
+        java.util.Objects.requireNonNull(played, "played must not be null");
+        java.util.Objects.requireNonNull(won, "won must not be null");
+        this.name = name;
+        this.played = played;
+        this.won = won;
+    }
+
+    public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.increase_container_depth();
+        name.serialize(serializer);
+        serializer.serialize_u32(played);
+        serializer.serialize_u32(won);
+        serializer.decrease_container_depth();
+    }
+
+    public byte[] bincodeSerialize() throws com.novi.serde.SerializationError {
+        com.novi.serde.Serializer serializer = new com.novi.bincode.BincodeSerializer();
+        serialize(serializer);
+        return serializer.get_bytes();
+    }
+
+    public static SeriesScore deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        deserializer.increase_container_depth();
+        Builder builder = new Builder();
+        builder.name = PlayerName.deserialize(deserializer);
+        builder.played = deserializer.deserialize_u32();
+        builder.won = deserializer.deserialize_u32();
+        deserializer.decrease_container_depth();
+        return builder.build();
+    }
+
+    public static SeriesScore bincodeDeserialize(byte[] input) throws com.novi.serde.DeserializationError {
+        if (input == null) {
+             throw new com.novi.serde.DeserializationError("Cannot deserialize null array");
+        }
+        com.novi.serde.Deserializer deserializer = new com.novi.bincode.BincodeDeserializer(input);
+        SeriesScore value = deserialize(deserializer);
+        if (deserializer.get_buffer_offset() < input.length) {
+             throw new com.novi.serde.DeserializationError("Some input bytes were not read");
+        }
+        return value;
+    }
+
+    public boolean equals(Object obj) {
+        if (this == obj) return true;
+        if (obj == null) return false;
+        if (getClass() != obj.getClass()) return false;
+        SeriesScore other = (SeriesScore) obj;
+        if (!java.util.Objects.equals(this.name, other.name)) { return false; }
+        if (!java.util.Objects.equals(this.played, other.played)) { return false; }
+        if (!java.util.Objects.equals(this.won, other.won)) { return false; }
+        return true;
+    }
+
+    public int hashCode() {
+        int value = 7;
+        value = 31 * value + (this.name != null ? this.name.hashCode() : 0);
+        value = 31 * value + (this.played != null ? this.played.hashCode() : 0);
+        value = 31 * value + (this.won != null ? this.won.hashCode() : 0);
+        return value;
+    }
+
+    public static final class Builder {
+        public PlayerName name;
+        public @com.novi.serde.Unsigned Integer played;
+        public @com.novi.serde.Unsigned Integer won;
+
+        public SeriesScore build() {
+            return new SeriesScore(
+                name,
+                played,
+                won
+            );
+        }
+    }
+}
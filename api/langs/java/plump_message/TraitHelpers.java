@@ -35,6 +35,42 @@ final class TraitHelpers {
         return obj;
     }
 
+    static void serialize_option_PlayerName(java.util.Optional<PlayerName> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        if (value.isPresent()) {
+            serializer.serialize_option_tag(true);
+            value.get().serialize(serializer);
+        } else {
+            serializer.serialize_option_tag(false);
+        }
+    }
+
+    static java.util.Optional<PlayerName> deserialize_option_PlayerName(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        boolean tag = deserializer.deserialize_option_tag();
+        if (!tag) {
+            return java.util.Optional.empty();
+        } else {
+            return java.util.Optional.of(PlayerName.deserialize(deserializer));
+        }
+    }
+
+    static void serialize_option_Trick(java.util.Optional<Trick> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        if (value.isPresent()) {
+            serializer.serialize_option_tag(true);
+            value.get().serialize(serializer);
+        } else {
+            serializer.serialize_option_tag(false);
+        }
+    }
+
+    static java.util.Optional<Trick> deserialize_option_Trick(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        boolean tag = deserializer.deserialize_option_tag();
+        if (!tag) {
+            return java.util.Optional.empty();
+        } else {
+            return java.util.Optional.of(Trick.deserialize(deserializer));
+        }
+    }
+
     static void serialize_option_u64(java.util.Optional<@com.novi.serde.Unsigned Long> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
         if (value.isPresent()) {
             serializer.serialize_option_tag(true);
@@ -71,6 +107,22 @@ final class TraitHelpers {
         }
     }
 
+    static void serialize_vector_Capability(java.util.List<Capability> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (Capability item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<Capability> deserialize_vector_Capability(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<Capability> obj = new java.util.ArrayList<Capability>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(Capability.deserialize(deserializer));
+        }
+        return obj;
+    }
+
     static void serialize_vector_Card(java.util.List<Card> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
         serializer.serialize_len(value.size());
         for (Card item : value) {
@@ -87,18 +139,130 @@ final class TraitHelpers {
         return obj;
     }
 
-    static void serialize_vector_Player(java.util.List<Player> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+    static void serialize_vector_LobbyPlayer(java.util.List<LobbyPlayer> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (LobbyPlayer item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<LobbyPlayer> deserialize_vector_LobbyPlayer(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<LobbyPlayer> obj = new java.util.ArrayList<LobbyPlayer>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(LobbyPlayer.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_Play(java.util.List<Play> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (Play item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<Play> deserialize_vector_Play(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<Play> obj = new java.util.ArrayList<Play>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(Play.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_PlayerName(java.util.List<PlayerName> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (PlayerName item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<PlayerName> deserialize_vector_PlayerName(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<PlayerName> obj = new java.util.ArrayList<PlayerName>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(PlayerName.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_PublicPlayer(java.util.List<PublicPlayer> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (PublicPlayer item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<PublicPlayer> deserialize_vector_PublicPlayer(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<PublicPlayer> obj = new java.util.ArrayList<PublicPlayer>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(PublicPlayer.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_RoundScore(java.util.List<RoundScore> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (RoundScore item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<RoundScore> deserialize_vector_RoundScore(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<RoundScore> obj = new java.util.ArrayList<RoundScore>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(RoundScore.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_ScoreSheetRound(java.util.List<ScoreSheetRound> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (ScoreSheetRound item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<ScoreSheetRound> deserialize_vector_ScoreSheetRound(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<ScoreSheetRound> obj = new java.util.ArrayList<ScoreSheetRound>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(ScoreSheetRound.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_SeriesScore(java.util.List<SeriesScore> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
+        serializer.serialize_len(value.size());
+        for (SeriesScore item : value) {
+            item.serialize(serializer);
+        }
+    }
+
+    static java.util.List<SeriesScore> deserialize_vector_SeriesScore(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+        long length = deserializer.deserialize_len();
+        java.util.List<SeriesScore> obj = new java.util.ArrayList<SeriesScore>((int) length);
+        for (long i = 0; i < length; i++) {
+            obj.add(SeriesScore.deserialize(deserializer));
+        }
+        return obj;
+    }
+
+    static void serialize_vector_Trick(java.util.List<Trick> value, com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
         serializer.serialize_len(value.size());
-        for (Player item : value) {
+        for (Trick item : value) {
             item.serialize(serializer);
         }
     }
 
-    static java.util.List<Player> deserialize_vector_Player(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
+    static java.util.List<Trick> deserialize_vector_Trick(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
         long length = deserializer.deserialize_len();
-        java.util.List<Player> obj = new java.util.ArrayList<Player>((int) length);
+        java.util.List<Trick> obj = new java.util.ArrayList<Trick>((int) length);
         for (long i = 0; i < length; i++) {
-            obj.add(Player.deserialize(deserializer));
+            obj.add(Trick.deserialize(deserializer));
         }
         return obj;
     }
@@ -2,16 +2,24 @@ package plump_message;
 
 
 public final class Trick {
-    public final java.util.List<Card> value;
+    public final java.util.List<Play> plays;
+    public final java.util.Optional<@com.novi.serde.Unsigned Long> led_suit;
+    public final java.util.Optional<@com.novi.serde.Unsigned Long> winning;
 
-    public Trick(java.util.List<Card> value) {
-        java.util.Objects.requireNonNull(value, "value must not be null");
-        this.value = value;
+    public Trick(java.util.List<Play> plays, java.util.Optional<@com.novi.serde.Unsigned Long> led_suit, java.util.Optional<@com.novi.serde.Unsigned Long> winning) {
+        java.util.Objects.requireNonNull(plays, "plays must not be null");
+        java.util.Objects.requireNonNull(led_suit, "led_suit must not be null");
+        java.util.Objects.requireNonNull(winning, "winning must not be null");
+        this.plays = plays;
+        this.led_suit = led_suit;
+        this.winning = winning;
     }
 
     public void serialize(com.novi.serde.Serializer serializer) throws com.novi.serde.SerializationError {
         serializer.increase_container_depth();
-        TraitHelpers.serialize_vector_Card(value, serializer);
+        TraitHelpers.serialize_vector_Play(plays, serializer);
+        TraitHelpers.serialize_option_u64(led_suit, serializer);
+        TraitHelpers.serialize_option_u64(winning, serializer);
         serializer.decrease_container_depth();
     }
 
@@ -24,7 +32,9 @@ public final class Trick {
     public static Trick deserialize(com.novi.serde.Deserializer deserializer) throws com.novi.serde.DeserializationError {
         deserializer.increase_container_depth();
         Builder builder = new Builder();
-        builder.value = TraitHelpers.deserialize_vector_Card(deserializer);
+        builder.plays = TraitHelpers.deserialize_vector_Play(deserializer);
+        builder.led_suit = TraitHelpers.deserialize_option_u64(deserializer);
+        builder.winning = TraitHelpers.deserialize_option_u64(deserializer);
         deserializer.decrease_container_depth();
         return builder.build();
     }
@@ -46,22 +56,30 @@ public final class Trick {
         if (obj == null) return false;
         if (getClass() != obj.getClass()) return false;
         Trick other = (Trick) obj;
-        if (!java.util.Objects.equals(this.value, other.value)) { return false; }
+        if (!java.util.Objects.equals(this.plays, other.plays)) { return false; }
+        if (!java.util.Objects.equals(this.led_suit, other.led_suit)) { return false; }
+        if (!java.util.Objects.equals(this.winning, other.winning)) { return false; }
         return true;
     }
 
     public int hashCode() {
         int value = 7;
-        value = 31 * value + (this.value != null ? this.value.hashCode() : 0);
+        value = 31 * value + (this.plays != null ? this.plays.hashCode() : 0);
+        value = 31 * value + (this.led_suit != null ? this.led_suit.hashCode() : 0);
+        value = 31 * value + (this.winning != null ? this.winning.hashCode() : 0);
         return value;
     }
 
     public static final class Builder {
-        public java.util.List<Card> value;
+        public java.util.List<Play> plays;
+        public java.util.Optional<@com.novi.serde.Unsigned Long> led_suit;
+        public java.util.Optional<@com.novi.serde.Unsigned Long> winning;
 
         public Trick build() {
             return new Trick(
-                value
+                plays,
+                led_suit,
+                winning
             );
         }
     }
@@ -4,10 +4,39 @@ import typing
 import serde_types as st
 import bincode
 
+class Capability:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Capability]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Capability)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Capability':
+        v, buffer = bincode.deserialize(input, Capability)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class Capability__Rejections(Capability):
+    INDEX = 0  # type: int
+    pass
+
+
 @dataclass(frozen=True)
+class Capability__LegalActions(Capability):
+    INDEX = 1  # type: int
+    pass
+
+Capability.VARIANTS = [
+    Capability__Rejections,
+    Capability__LegalActions,
+]
+
+
 class Card:
-    suit: st.uint64
-    value: st.uint64
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Card]]
 
     def bincode_serialize(self) -> bytes:
         return bincode.serialize(self, Card)
@@ -20,6 +49,283 @@ class Card:
         return v
 
 
+@dataclass(frozen=True)
+class Card__Suited(Card):
+    INDEX = 0  # type: int
+    suit: st.uint64
+    value: st.uint64
+
+
+@dataclass(frozen=True)
+class Card__Joker(Card):
+    INDEX = 1  # type: int
+    value: "Colour"
+
+Card.VARIANTS = [
+    Card__Suited,
+    Card__Joker,
+]
+
+
+class ClientMessage:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[ClientMessage]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, ClientMessage)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'ClientMessage':
+        v, buffer = bincode.deserialize(input, ClientMessage)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class ClientMessage__Reply(ClientMessage):
+    INDEX = 0  # type: int
+    value: str
+
+
+@dataclass(frozen=True)
+class ClientMessage__Hello(ClientMessage):
+    INDEX = 1  # type: int
+    version: st.uint32
+    encoding: "Encoding"
+    capabilities: typing.Sequence["Capability"]
+
+
+@dataclass(frozen=True)
+class ClientMessage__LastTrick(ClientMessage):
+    INDEX = 2  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class ClientMessage__TrickHistory(ClientMessage):
+    INDEX = 3  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class ClientMessage__Chat(ClientMessage):
+    INDEX = 4  # type: int
+    to: typing.Optional["PlayerName"]
+    text: str
+
+
+@dataclass(frozen=True)
+class ClientMessage__Mute(ClientMessage):
+    INDEX = 5  # type: int
+    value: "PlayerName"
+
+
+@dataclass(frozen=True)
+class ClientMessage__Unmute(ClientMessage):
+    INDEX = 6  # type: int
+    value: "PlayerName"
+
+
+@dataclass(frozen=True)
+class ClientMessage__Ready(ClientMessage):
+    INDEX = 7  # type: int
+    value: bool
+
+
+@dataclass(frozen=True)
+class ClientMessage__Start(ClientMessage):
+    INDEX = 8  # type: int
+    bots: bool
+
+
+@dataclass(frozen=True)
+class ClientMessage__Leave(ClientMessage):
+    INDEX = 9  # type: int
+    pass
+
+ClientMessage.VARIANTS = [
+    ClientMessage__Reply,
+    ClientMessage__Hello,
+    ClientMessage__LastTrick,
+    ClientMessage__TrickHistory,
+    ClientMessage__Chat,
+    ClientMessage__Mute,
+    ClientMessage__Unmute,
+    ClientMessage__Ready,
+    ClientMessage__Start,
+    ClientMessage__Leave,
+]
+
+
+class Colour:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Colour]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Colour)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Colour':
+        v, buffer = bincode.deserialize(input, Colour)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class Colour__Red(Colour):
+    INDEX = 0  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class Colour__Black(Colour):
+    INDEX = 1  # type: int
+    pass
+
+Colour.VARIANTS = [
+    Colour__Red,
+    Colour__Black,
+]
+
+
+class Disconnect:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Disconnect]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Disconnect)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Disconnect':
+        v, buffer = bincode.deserialize(input, Disconnect)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class Disconnect__LineTooLong(Disconnect):
+    INDEX = 0  # type: int
+    max: st.uint64
+
+
+@dataclass(frozen=True)
+class Disconnect__InvalidUtf8(Disconnect):
+    INDEX = 1  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class Disconnect__Malformed(Disconnect):
+    INDEX = 2  # type: int
+    value: str
+
+
+@dataclass(frozen=True)
+class Disconnect__Idle(Disconnect):
+    INDEX = 3  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class Disconnect__NoSeat(Disconnect):
+    INDEX = 4  # type: int
+    pass
+
+Disconnect.VARIANTS = [
+    Disconnect__LineTooLong,
+    Disconnect__InvalidUtf8,
+    Disconnect__Malformed,
+    Disconnect__Idle,
+    Disconnect__NoSeat,
+]
+
+
+class Encoding:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Encoding]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Encoding)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Encoding':
+        v, buffer = bincode.deserialize(input, Encoding)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class Encoding__Text(Encoding):
+    INDEX = 0  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class Encoding__Json(Encoding):
+    INDEX = 1  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class Encoding__Binary(Encoding):
+    INDEX = 2  # type: int
+    pass
+
+Encoding.VARIANTS = [
+    Encoding__Text,
+    Encoding__Json,
+    Encoding__Binary,
+]
+
+
+class HandshakeRejection:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[HandshakeRejection]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, HandshakeRejection)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'HandshakeRejection':
+        v, buffer = bincode.deserialize(input, HandshakeRejection)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class HandshakeRejection__UnsupportedVersion(HandshakeRejection):
+    INDEX = 0  # type: int
+    requested: st.uint32
+    supported: st.uint32
+
+
+@dataclass(frozen=True)
+class HandshakeRejection__Malformed(HandshakeRejection):
+    INDEX = 1  # type: int
+    value: str
+
+HandshakeRejection.VARIANTS = [
+    HandshakeRejection__UnsupportedVersion,
+    HandshakeRejection__Malformed,
+]
+
+
+@dataclass(frozen=True)
+class LobbyPlayer:
+    name: "PlayerName"
+    ready: bool
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, LobbyPlayer)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'LobbyPlayer':
+        v, buffer = bincode.deserialize(input, LobbyPlayer)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
 class Message:
     VARIANTS = []  # type: typing.Sequence[typing.Type[Message]]
 
@@ -41,6 +347,7 @@ class Message__RequestGuessContext(Message):
     hand: typing.Sequence["Card"]
     guesses: typing.Sequence[st.uint64]
     players: st.uint64
+    legal_guesses: typing.Optional[typing.Sequence[st.uint64]]
 
 
 @dataclass(frozen=True)
@@ -52,7 +359,7 @@ class Message__Guesses(Message):
 @dataclass(frozen=True)
 class Message__Turn(Message):
     INDEX = 2  # type: int
-    whose: "Player"
+    whose: "PublicPlayer"
 
 
 @dataclass(frozen=True)
@@ -79,13 +386,13 @@ class Message__Scoreboard(Message):
 @dataclass(frozen=True)
 class Message__Winner(Message):
     INDEX = 6  # type: int
-    value: "Player"
+    value: "PublicPlayer"
 
 
 @dataclass(frozen=True)
 class Message__Winners(Message):
     INDEX = 7  # type: int
-    players: typing.Sequence["Player"]
+    players: typing.Sequence["PublicPlayer"]
     winner_indices: typing.Sequence[st.uint64]
 
 
@@ -98,13 +405,14 @@ class Message__RequestPlayerName(Message):
 @dataclass(frozen=True)
 class Message__PlayRequest(Message):
     INDEX = 9  # type: int
-    value: "Player"
+    player: "Player"
+    valid_cards: typing.Optional[typing.Sequence[st.uint64]]
 
 
 @dataclass(frozen=True)
 class Message__RequestGuess(Message):
     INDEX = 10  # type: int
-    pass
+    legal_guesses: typing.Optional[typing.Sequence[st.uint64]]
 
 
 @dataclass(frozen=True)
@@ -112,6 +420,108 @@ class Message__GameOver(Message):
     INDEX = 11  # type: int
     pass
 
+
+@dataclass(frozen=True)
+class Message__Rejected(Message):
+    INDEX = 12  # type: int
+    value: "Rejection"
+
+
+@dataclass(frozen=True)
+class Message__HandshakeAccepted(Message):
+    INDEX = 13  # type: int
+    version: st.uint32
+    encoding: "Encoding"
+    capabilities: typing.Sequence["Capability"]
+
+
+@dataclass(frozen=True)
+class Message__HandshakeRejected(Message):
+    INDEX = 14  # type: int
+    value: "HandshakeRejection"
+
+
+@dataclass(frozen=True)
+class Message__ScoreSheet(Message):
+    INDEX = 15  # type: int
+    value: "ScoreSheet"
+
+
+@dataclass(frozen=True)
+class Message__RoundStart(Message):
+    INDEX = 16  # type: int
+    round: st.uint64
+    rounds: st.uint64
+    hand_size: st.uint64
+    dealer: "PublicPlayer"
+
+
+@dataclass(frozen=True)
+class Message__RoundEnd(Message):
+    INDEX = 17  # type: int
+    round: st.uint64
+    made: typing.Sequence["PublicPlayer"]
+    missed: typing.Sequence["PublicPlayer"]
+
+
+@dataclass(frozen=True)
+class Message__LastTrick(Message):
+    INDEX = 18  # type: int
+    value: typing.Optional["Trick"]
+
+
+@dataclass(frozen=True)
+class Message__TrickHistory(Message):
+    INDEX = 19  # type: int
+    value: typing.Sequence["Trick"]
+
+
+@dataclass(frozen=True)
+class Message__Chat(Message):
+    INDEX = 20  # type: int
+    sender: "PlayerName"
+    text: str
+
+
+@dataclass(frozen=True)
+class Message__Whisper(Message):
+    INDEX = 21  # type: int
+    sender: "PlayerName"
+    to: "PlayerName"
+    text: str
+
+
+@dataclass(frozen=True)
+class Message__Disconnected(Message):
+    INDEX = 22  # type: int
+    value: "Disconnect"
+
+
+@dataclass(frozen=True)
+class Message__NameRejected(Message):
+    INDEX = 23  # type: int
+    value: "NameRejection"
+
+
+@dataclass(frozen=True)
+class Message__Lobby(Message):
+    INDEX = 24  # type: int
+    players: typing.Sequence["LobbyPlayer"]
+    seats: st.uint64
+
+
+@dataclass(frozen=True)
+class Message__Series(Message):
+    INDEX = 25  # type: int
+    games: st.uint32
+    scores: typing.Sequence["SeriesScore"]
+
+
+@dataclass(frozen=True)
+class Message__RejoinToken(Message):
+    INDEX = 26  # type: int
+    value: str
+
 Message.VARIANTS = [
     Message__RequestGuessContext,
     Message__Guesses,
@@ -125,9 +535,85 @@ Message.VARIANTS = [
     Message__PlayRequest,
     Message__RequestGuess,
     Message__GameOver,
+    Message__Rejected,
+    Message__HandshakeAccepted,
+    Message__HandshakeRejected,
+    Message__ScoreSheet,
+    Message__RoundStart,
+    Message__RoundEnd,
+    Message__LastTrick,
+    Message__TrickHistory,
+    Message__Chat,
+    Message__Whisper,
+    Message__Disconnected,
+    Message__NameRejected,
+    Message__Lobby,
+    Message__Series,
+    Message__RejoinToken,
+]
+
+
+class NameRejection:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[NameRejection]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, NameRejection)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'NameRejection':
+        v, buffer = bincode.deserialize(input, NameRejection)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class NameRejection__Empty(NameRejection):
+    INDEX = 0  # type: int
+    pass
+
+
+@dataclass(frozen=True)
+class NameRejection__TooLong(NameRejection):
+    INDEX = 1  # type: int
+    max: st.uint64
+
+
+@dataclass(frozen=True)
+class NameRejection__InvalidCharacter(NameRejection):
+    INDEX = 2  # type: int
+    value: st.char
+
+
+@dataclass(frozen=True)
+class NameRejection__Taken(NameRejection):
+    INDEX = 3  # type: int
+    value: "PlayerName"
+
+NameRejection.VARIANTS = [
+    NameRejection__Empty,
+    NameRejection__TooLong,
+    NameRejection__InvalidCharacter,
+    NameRejection__Taken,
 ]
 
 
+@dataclass(frozen=True)
+class Play:
+    player: "PlayerName"
+    card: "Card"
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Play)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Play':
+        v, buffer = bincode.deserialize(input, Play)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
 @dataclass(frozen=True)
 class Player:
     name: "PlayerName"
@@ -160,6 +646,22 @@ class PlayerName:
         return v
 
 
+@dataclass(frozen=True)
+class PublicPlayer:
+    name: "PlayerName"
+    human: bool
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, PublicPlayer)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'PublicPlayer':
+        v, buffer = bincode.deserialize(input, PublicPlayer)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
 @dataclass(frozen=True)
 class PublicState:
     guess: typing.Optional[st.uint64]
@@ -177,9 +679,182 @@ class PublicState:
         return v
 
 
+class Rejection:
+    VARIANTS = []  # type: typing.Sequence[typing.Type[Rejection]]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, Rejection)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'Rejection':
+        v, buffer = bincode.deserialize(input, Rejection)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class Rejection__NotANumber(Rejection):
+    INDEX = 0  # type: int
+    value: str
+
+
+@dataclass(frozen=True)
+class Rejection__GuessTooHigh(Rejection):
+    INDEX = 1  # type: int
+    guess: st.uint64
+    hand_size: st.uint64
+
+
+@dataclass(frozen=True)
+class Rejection__ForbiddenGuess(Rejection):
+    INDEX = 2  # type: int
+    guess: st.uint64
+    hand_size: st.uint64
+
+
+@dataclass(frozen=True)
+class Rejection__NoSuchCard(Rejection):
+    INDEX = 3  # type: int
+    index: st.uint64
+    hand_size: st.uint64
+
+
+@dataclass(frozen=True)
+class Rejection__MustFollowSuit(Rejection):
+    INDEX = 4  # type: int
+    suit: st.uint64
+
+
+@dataclass(frozen=True)
+class Rejection__NotACard(Rejection):
+    INDEX = 5  # type: int
+    value: str
+
+
+@dataclass(frozen=True)
+class Rejection__CardNotInHand(Rejection):
+    INDEX = 6  # type: int
+    value: "Card"
+
+
+@dataclass(frozen=True)
+class Rejection__ChatTooLong(Rejection):
+    INDEX = 7  # type: int
+    length: st.uint64
+    max: st.uint64
+
+
+@dataclass(frozen=True)
+class Rejection__UnknownPlayer(Rejection):
+    INDEX = 8  # type: int
+    value: "PlayerName"
+
+
+@dataclass(frozen=True)
+class Rejection__NotHost(Rejection):
+    INDEX = 9  # type: int
+    value: "PlayerName"
+
+
+@dataclass(frozen=True)
+class Rejection__NotReady(Rejection):
+    INDEX = 10  # type: int
+    value: typing.Sequence["PlayerName"]
+
+
+@dataclass(frozen=True)
+class Rejection__TooFewPlayers(Rejection):
+    INDEX = 11  # type: int
+    min: st.uint64
+
+Rejection.VARIANTS = [
+    Rejection__NotANumber,
+    Rejection__GuessTooHigh,
+    Rejection__ForbiddenGuess,
+    Rejection__NoSuchCard,
+    Rejection__MustFollowSuit,
+    Rejection__NotACard,
+    Rejection__CardNotInHand,
+    Rejection__ChatTooLong,
+    Rejection__UnknownPlayer,
+    Rejection__NotHost,
+    Rejection__NotReady,
+    Rejection__TooFewPlayers,
+]
+
+
+@dataclass(frozen=True)
+class RoundScore:
+    guess: st.uint64
+    wins: st.uint64
+    points: st.uint64
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, RoundScore)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'RoundScore':
+        v, buffer = bincode.deserialize(input, RoundScore)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class ScoreSheet:
+    players: typing.Sequence["PlayerName"]
+    rounds: typing.Sequence["ScoreSheetRound"]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, ScoreSheet)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'ScoreSheet':
+        v, buffer = bincode.deserialize(input, ScoreSheet)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class ScoreSheetRound:
+    hand_size: st.uint64
+    scores: typing.Sequence["RoundScore"]
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, ScoreSheetRound)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'ScoreSheetRound':
+        v, buffer = bincode.deserialize(input, ScoreSheetRound)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
+@dataclass(frozen=True)
+class SeriesScore:
+    name: "PlayerName"
+    played: st.uint32
+    won: st.uint32
+
+    def bincode_serialize(self) -> bytes:
+        return bincode.serialize(self, SeriesScore)
+
+    @staticmethod
+    def bincode_deserialize(input: bytes) -> 'SeriesScore':
+        v, buffer = bincode.deserialize(input, SeriesScore)
+        if buffer:
+            raise st.DeserializationError("Some input bytes were not read");
+        return v
+
+
 @dataclass(frozen=True)
 class Trick:
-    value: typing.Sequence["Card"]
+    plays: typing.Sequence["Play"]
+    led_suit: typing.Optional[st.uint64]
+    winning: typing.Optional[st.uint64]
 
     def bincode_serialize(self) -> bytes:
         return bincode.serialize(self, Trick)
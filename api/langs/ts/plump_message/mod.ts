@@ -3,170 +3,1437 @@ import { Serializer, Deserializer } from '../serde/mod.ts';
 import { BcsSerializer, BcsDeserializer } from '../bcs/mod.ts';
 import { Optional, Seq, Tuple, ListTuple, unit, bool, int8, int16, int32, int64, int128, uint8, uint16, uint32, uint64, uint128, float32, float64, char, str, bytes } from '../serde/mod.ts';
 
-export class Card {
+export abstract class Capability {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Capability {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return CapabilityVariantRejections.load(deserializer);
+    case 1: return CapabilityVariantLegalActions.load(deserializer);
+    default: throw new Error("Unknown variant index for Capability: " + index);
+  }
+}
+}
+
+
+export class CapabilityVariantRejections extends Capability {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): CapabilityVariantRejections {
+  return new CapabilityVariantRejections();
+}
+
+}
+
+export class CapabilityVariantLegalActions extends Capability {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): CapabilityVariantLegalActions {
+  return new CapabilityVariantLegalActions();
+}
+
+}
+export abstract class Card {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Card {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return CardVariantSuited.load(deserializer);
+    case 1: return CardVariantJoker.load(deserializer);
+    default: throw new Error("Unknown variant index for Card: " + index);
+  }
+}
+}
+
+
+export class CardVariantSuited extends Card {
 
 constructor (public suit: uint64, public value: uint64) {
+  super();
 }
 
 public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
   serializer.serializeU64(this.suit);
   serializer.serializeU64(this.value);
 }
 
-static deserialize(deserializer: Deserializer): Card {
-  const suit = deserializer.deserializeU64();
-  const value = deserializer.deserializeU64();
-  return new Card(suit,value);
+static load(deserializer: Deserializer): CardVariantSuited {
+  const suit = deserializer.deserializeU64();
+  const value = deserializer.deserializeU64();
+  return new CardVariantSuited(suit,value);
+}
+
+}
+
+export class CardVariantJoker extends Card {
+
+constructor (public value: Colour) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): CardVariantJoker {
+  const value = Colour.deserialize(deserializer);
+  return new CardVariantJoker(value);
+}
+
+}
+export abstract class ClientMessage {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): ClientMessage {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ClientMessageVariantReply.load(deserializer);
+    case 1: return ClientMessageVariantHello.load(deserializer);
+    case 2: return ClientMessageVariantLastTrick.load(deserializer);
+    case 3: return ClientMessageVariantTrickHistory.load(deserializer);
+    case 4: return ClientMessageVariantChat.load(deserializer);
+    case 5: return ClientMessageVariantMute.load(deserializer);
+    case 6: return ClientMessageVariantUnmute.load(deserializer);
+    case 7: return ClientMessageVariantReady.load(deserializer);
+    case 8: return ClientMessageVariantStart.load(deserializer);
+    case 9: return ClientMessageVariantLeave.load(deserializer);
+    default: throw new Error("Unknown variant index for ClientMessage: " + index);
+  }
+}
+}
+
+
+export class ClientMessageVariantReply extends ClientMessage {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantReply {
+  const value = deserializer.deserializeStr();
+  return new ClientMessageVariantReply(value);
+}
+
+}
+
+export class ClientMessageVariantHello extends ClientMessage {
+
+constructor (public version: uint32, public encoding: Encoding, public capabilities: Seq<Capability>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  serializer.serializeU32(this.version);
+  this.encoding.serialize(serializer);
+  Helpers.serializeVectorCapability(this.capabilities, serializer);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantHello {
+  const version = deserializer.deserializeU32();
+  const encoding = Encoding.deserialize(deserializer);
+  const capabilities = Helpers.deserializeVectorCapability(deserializer);
+  return new ClientMessageVariantHello(version,encoding,capabilities);
+}
+
+}
+
+export class ClientMessageVariantLastTrick extends ClientMessage {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantLastTrick {
+  return new ClientMessageVariantLastTrick();
+}
+
+}
+
+export class ClientMessageVariantTrickHistory extends ClientMessage {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantTrickHistory {
+  return new ClientMessageVariantTrickHistory();
+}
+
+}
+
+export class ClientMessageVariantChat extends ClientMessage {
+
+constructor (public to: Optional<PlayerName>, public text: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+  Helpers.serializeOptionPlayerName(this.to, serializer);
+  serializer.serializeStr(this.text);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantChat {
+  const to = Helpers.deserializeOptionPlayerName(deserializer);
+  const text = deserializer.deserializeStr();
+  return new ClientMessageVariantChat(to,text);
+}
+
+}
+
+export class ClientMessageVariantMute extends ClientMessage {
+
+constructor (public value: PlayerName) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantMute {
+  const value = PlayerName.deserialize(deserializer);
+  return new ClientMessageVariantMute(value);
+}
+
+}
+
+export class ClientMessageVariantUnmute extends ClientMessage {
+
+constructor (public value: PlayerName) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantUnmute {
+  const value = PlayerName.deserialize(deserializer);
+  return new ClientMessageVariantUnmute(value);
+}
+
+}
+
+export class ClientMessageVariantReady extends ClientMessage {
+
+constructor (public value: bool) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+  serializer.serializeBool(this.value);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantReady {
+  const value = deserializer.deserializeBool();
+  return new ClientMessageVariantReady(value);
+}
+
+}
+
+export class ClientMessageVariantStart extends ClientMessage {
+
+constructor (public bots: bool) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+  serializer.serializeBool(this.bots);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantStart {
+  const bots = deserializer.deserializeBool();
+  return new ClientMessageVariantStart(bots);
+}
+
+}
+
+export class ClientMessageVariantLeave extends ClientMessage {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+}
+
+static load(deserializer: Deserializer): ClientMessageVariantLeave {
+  return new ClientMessageVariantLeave();
+}
+
+}
+export abstract class Colour {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Colour {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return ColourVariantRed.load(deserializer);
+    case 1: return ColourVariantBlack.load(deserializer);
+    default: throw new Error("Unknown variant index for Colour: " + index);
+  }
+}
+}
+
+
+export class ColourVariantRed extends Colour {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): ColourVariantRed {
+  return new ColourVariantRed();
+}
+
+}
+
+export class ColourVariantBlack extends Colour {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): ColourVariantBlack {
+  return new ColourVariantBlack();
+}
+
+}
+export abstract class Disconnect {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Disconnect {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return DisconnectVariantLineTooLong.load(deserializer);
+    case 1: return DisconnectVariantInvalidUtf8.load(deserializer);
+    case 2: return DisconnectVariantMalformed.load(deserializer);
+    case 3: return DisconnectVariantIdle.load(deserializer);
+    case 4: return DisconnectVariantNoSeat.load(deserializer);
+    default: throw new Error("Unknown variant index for Disconnect: " + index);
+  }
+}
+}
+
+
+export class DisconnectVariantLineTooLong extends Disconnect {
+
+constructor (public max: uint64) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeU64(this.max);
+}
+
+static load(deserializer: Deserializer): DisconnectVariantLineTooLong {
+  const max = deserializer.deserializeU64();
+  return new DisconnectVariantLineTooLong(max);
+}
+
+}
+
+export class DisconnectVariantInvalidUtf8 extends Disconnect {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): DisconnectVariantInvalidUtf8 {
+  return new DisconnectVariantInvalidUtf8();
+}
+
+}
+
+export class DisconnectVariantMalformed extends Disconnect {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): DisconnectVariantMalformed {
+  const value = deserializer.deserializeStr();
+  return new DisconnectVariantMalformed(value);
+}
+
+}
+
+export class DisconnectVariantIdle extends Disconnect {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+}
+
+static load(deserializer: Deserializer): DisconnectVariantIdle {
+  return new DisconnectVariantIdle();
+}
+
+}
+
+export class DisconnectVariantNoSeat extends Disconnect {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+}
+
+static load(deserializer: Deserializer): DisconnectVariantNoSeat {
+  return new DisconnectVariantNoSeat();
+}
+
+}
+export abstract class Encoding {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Encoding {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return EncodingVariantText.load(deserializer);
+    case 1: return EncodingVariantJson.load(deserializer);
+    case 2: return EncodingVariantBinary.load(deserializer);
+    default: throw new Error("Unknown variant index for Encoding: " + index);
+  }
+}
+}
+
+
+export class EncodingVariantText extends Encoding {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): EncodingVariantText {
+  return new EncodingVariantText();
+}
+
+}
+
+export class EncodingVariantJson extends Encoding {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+}
+
+static load(deserializer: Deserializer): EncodingVariantJson {
+  return new EncodingVariantJson();
+}
+
+}
+
+export class EncodingVariantBinary extends Encoding {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+}
+
+static load(deserializer: Deserializer): EncodingVariantBinary {
+  return new EncodingVariantBinary();
+}
+
+}
+export abstract class HandshakeRejection {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): HandshakeRejection {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return HandshakeRejectionVariantUnsupportedVersion.load(deserializer);
+    case 1: return HandshakeRejectionVariantMalformed.load(deserializer);
+    default: throw new Error("Unknown variant index for HandshakeRejection: " + index);
+  }
+}
+}
+
+
+export class HandshakeRejectionVariantUnsupportedVersion extends HandshakeRejection {
+
+constructor (public requested: uint32, public supported: uint32) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  serializer.serializeU32(this.requested);
+  serializer.serializeU32(this.supported);
+}
+
+static load(deserializer: Deserializer): HandshakeRejectionVariantUnsupportedVersion {
+  const requested = deserializer.deserializeU32();
+  const supported = deserializer.deserializeU32();
+  return new HandshakeRejectionVariantUnsupportedVersion(requested,supported);
+}
+
+}
+
+export class HandshakeRejectionVariantMalformed extends HandshakeRejection {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): HandshakeRejectionVariantMalformed {
+  const value = deserializer.deserializeStr();
+  return new HandshakeRejectionVariantMalformed(value);
+}
+
+}
+export class LobbyPlayer {
+
+constructor (public name: PlayerName, public ready: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.name.serialize(serializer);
+  serializer.serializeBool(this.ready);
+}
+
+static deserialize(deserializer: Deserializer): LobbyPlayer {
+  const name = PlayerName.deserialize(deserializer);
+  const ready = deserializer.deserializeBool();
+  return new LobbyPlayer(name,ready);
+}
+
+}
+export abstract class Message {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): Message {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return MessageVariantRequestGuessContext.load(deserializer);
+    case 1: return MessageVariantGuesses.load(deserializer);
+    case 2: return MessageVariantTurn.load(deserializer);
+    case 3: return MessageVariantPlayRequestContext.load(deserializer);
+    case 4: return MessageVariantTrick.load(deserializer);
+    case 5: return MessageVariantScoreboard.load(deserializer);
+    case 6: return MessageVariantWinner.load(deserializer);
+    case 7: return MessageVariantWinners.load(deserializer);
+    case 8: return MessageVariantRequestPlayerName.load(deserializer);
+    case 9: return MessageVariantPlayRequest.load(deserializer);
+    case 10: return MessageVariantRequestGuess.load(deserializer);
+    case 11: return MessageVariantGameOver.load(deserializer);
+    case 12: return MessageVariantRejected.load(deserializer);
+    case 13: return MessageVariantHandshakeAccepted.load(deserializer);
+    case 14: return MessageVariantHandshakeRejected.load(deserializer);
+    case 15: return MessageVariantScoreSheet.load(deserializer);
+    case 16: return MessageVariantRoundStart.load(deserializer);
+    case 17: return MessageVariantRoundEnd.load(deserializer);
+    case 18: return MessageVariantLastTrick.load(deserializer);
+    case 19: return MessageVariantTrickHistory.load(deserializer);
+    case 20: return MessageVariantChat.load(deserializer);
+    case 21: return MessageVariantWhisper.load(deserializer);
+    case 22: return MessageVariantDisconnected.load(deserializer);
+    case 23: return MessageVariantNameRejected.load(deserializer);
+    case 24: return MessageVariantLobby.load(deserializer);
+    case 25: return MessageVariantSeries.load(deserializer);
+    case 26: return MessageVariantRejoinToken.load(deserializer);
+    default: throw new Error("Unknown variant index for Message: " + index);
+  }
+}
+}
+
+
+export class MessageVariantRequestGuessContext extends Message {
+
+constructor (public player: Player, public hand: Seq<Card>, public guesses: Seq<uint64>, public players: uint64, public legal_guesses: Optional<Seq<uint64>>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+  this.player.serialize(serializer);
+  Helpers.serializeVectorCard(this.hand, serializer);
+  Helpers.serializeVectorU64(this.guesses, serializer);
+  serializer.serializeU64(this.players);
+  Helpers.serializeOptionVectorU64(this.legal_guesses, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantRequestGuessContext {
+  const player = Player.deserialize(deserializer);
+  const hand = Helpers.deserializeVectorCard(deserializer);
+  const guesses = Helpers.deserializeVectorU64(deserializer);
+  const players = deserializer.deserializeU64();
+  const legal_guesses = Helpers.deserializeOptionVectorU64(deserializer);
+  return new MessageVariantRequestGuessContext(player,hand,guesses,players,legal_guesses);
+}
+
+}
+
+export class MessageVariantGuesses extends Message {
+
+constructor (public state: Map<PlayerName,PublicState>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  Helpers.serializeMapPlayerNameToPublicState(this.state, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantGuesses {
+  const state = Helpers.deserializeMapPlayerNameToPublicState(deserializer);
+  return new MessageVariantGuesses(state);
+}
+
+}
+
+export class MessageVariantTurn extends Message {
+
+constructor (public whose: PublicPlayer) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  this.whose.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantTurn {
+  const whose = PublicPlayer.deserialize(deserializer);
+  return new MessageVariantTurn(whose);
+}
+
+}
+
+export class MessageVariantPlayRequestContext extends Message {
+
+constructor (public player: Player, public hand: Seq<Card>, public trick: Trick, public valid_cards: Optional<Seq<uint64>>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+  this.player.serialize(serializer);
+  Helpers.serializeVectorCard(this.hand, serializer);
+  this.trick.serialize(serializer);
+  Helpers.serializeOptionVectorU64(this.valid_cards, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantPlayRequestContext {
+  const player = Player.deserialize(deserializer);
+  const hand = Helpers.deserializeVectorCard(deserializer);
+  const trick = Trick.deserialize(deserializer);
+  const valid_cards = Helpers.deserializeOptionVectorU64(deserializer);
+  return new MessageVariantPlayRequestContext(player,hand,trick,valid_cards);
+}
+
+}
+
+export class MessageVariantTrick extends Message {
+
+constructor (public value: Trick) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(4);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantTrick {
+  const value = Trick.deserialize(deserializer);
+  return new MessageVariantTrick(value);
+}
+
+}
+
+export class MessageVariantScoreboard extends Message {
+
+constructor (public state: Map<PlayerName,PublicState>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(5);
+  Helpers.serializeMapPlayerNameToPublicState(this.state, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantScoreboard {
+  const state = Helpers.deserializeMapPlayerNameToPublicState(deserializer);
+  return new MessageVariantScoreboard(state);
+}
+
+}
+
+export class MessageVariantWinner extends Message {
+
+constructor (public value: PublicPlayer) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(6);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantWinner {
+  const value = PublicPlayer.deserialize(deserializer);
+  return new MessageVariantWinner(value);
+}
+
+}
+
+export class MessageVariantWinners extends Message {
+
+constructor (public players: Seq<PublicPlayer>, public winner_indices: Seq<uint64>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(7);
+  Helpers.serializeVectorPublicPlayer(this.players, serializer);
+  Helpers.serializeVectorU64(this.winner_indices, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantWinners {
+  const players = Helpers.deserializeVectorPublicPlayer(deserializer);
+  const winner_indices = Helpers.deserializeVectorU64(deserializer);
+  return new MessageVariantWinners(players,winner_indices);
+}
+
+}
+
+export class MessageVariantRequestPlayerName extends Message {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(8);
+}
+
+static load(deserializer: Deserializer): MessageVariantRequestPlayerName {
+  return new MessageVariantRequestPlayerName();
+}
+
+}
+
+export class MessageVariantPlayRequest extends Message {
+
+constructor (public player: Player, public valid_cards: Optional<Seq<uint64>>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(9);
+  this.player.serialize(serializer);
+  Helpers.serializeOptionVectorU64(this.valid_cards, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantPlayRequest {
+  const player = Player.deserialize(deserializer);
+  const valid_cards = Helpers.deserializeOptionVectorU64(deserializer);
+  return new MessageVariantPlayRequest(player,valid_cards);
+}
+
+}
+
+export class MessageVariantRequestGuess extends Message {
+
+constructor (public legal_guesses: Optional<Seq<uint64>>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(10);
+  Helpers.serializeOptionVectorU64(this.legal_guesses, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantRequestGuess {
+  const legal_guesses = Helpers.deserializeOptionVectorU64(deserializer);
+  return new MessageVariantRequestGuess(legal_guesses);
+}
+
+}
+
+export class MessageVariantGameOver extends Message {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(11);
+}
+
+static load(deserializer: Deserializer): MessageVariantGameOver {
+  return new MessageVariantGameOver();
+}
+
+}
+
+export class MessageVariantRejected extends Message {
+
+constructor (public value: Rejection) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(12);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantRejected {
+  const value = Rejection.deserialize(deserializer);
+  return new MessageVariantRejected(value);
+}
+
+}
+
+export class MessageVariantHandshakeAccepted extends Message {
+
+constructor (public version: uint32, public encoding: Encoding, public capabilities: Seq<Capability>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(13);
+  serializer.serializeU32(this.version);
+  this.encoding.serialize(serializer);
+  Helpers.serializeVectorCapability(this.capabilities, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantHandshakeAccepted {
+  const version = deserializer.deserializeU32();
+  const encoding = Encoding.deserialize(deserializer);
+  const capabilities = Helpers.deserializeVectorCapability(deserializer);
+  return new MessageVariantHandshakeAccepted(version,encoding,capabilities);
+}
+
+}
+
+export class MessageVariantHandshakeRejected extends Message {
+
+constructor (public value: HandshakeRejection) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(14);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantHandshakeRejected {
+  const value = HandshakeRejection.deserialize(deserializer);
+  return new MessageVariantHandshakeRejected(value);
+}
+
+}
+
+export class MessageVariantScoreSheet extends Message {
+
+constructor (public value: ScoreSheet) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(15);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantScoreSheet {
+  const value = ScoreSheet.deserialize(deserializer);
+  return new MessageVariantScoreSheet(value);
+}
+
+}
+
+export class MessageVariantRoundStart extends Message {
+
+constructor (public round: uint64, public rounds: uint64, public hand_size: uint64, public dealer: PublicPlayer) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(16);
+  serializer.serializeU64(this.round);
+  serializer.serializeU64(this.rounds);
+  serializer.serializeU64(this.hand_size);
+  this.dealer.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantRoundStart {
+  const round = deserializer.deserializeU64();
+  const rounds = deserializer.deserializeU64();
+  const hand_size = deserializer.deserializeU64();
+  const dealer = PublicPlayer.deserialize(deserializer);
+  return new MessageVariantRoundStart(round,rounds,hand_size,dealer);
+}
+
+}
+
+export class MessageVariantRoundEnd extends Message {
+
+constructor (public round: uint64, public made: Seq<PublicPlayer>, public missed: Seq<PublicPlayer>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(17);
+  serializer.serializeU64(this.round);
+  Helpers.serializeVectorPublicPlayer(this.made, serializer);
+  Helpers.serializeVectorPublicPlayer(this.missed, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantRoundEnd {
+  const round = deserializer.deserializeU64();
+  const made = Helpers.deserializeVectorPublicPlayer(deserializer);
+  const missed = Helpers.deserializeVectorPublicPlayer(deserializer);
+  return new MessageVariantRoundEnd(round,made,missed);
+}
+
+}
+
+export class MessageVariantLastTrick extends Message {
+
+constructor (public value: Optional<Trick>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(18);
+  Helpers.serializeOptionTrick(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantLastTrick {
+  const value = Helpers.deserializeOptionTrick(deserializer);
+  return new MessageVariantLastTrick(value);
+}
+
+}
+
+export class MessageVariantTrickHistory extends Message {
+
+constructor (public value: Seq<Trick>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(19);
+  Helpers.serializeVectorTrick(this.value, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantTrickHistory {
+  const value = Helpers.deserializeVectorTrick(deserializer);
+  return new MessageVariantTrickHistory(value);
+}
+
+}
+
+export class MessageVariantChat extends Message {
+
+constructor (public sender: PlayerName, public text: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(20);
+  this.sender.serialize(serializer);
+  serializer.serializeStr(this.text);
+}
+
+static load(deserializer: Deserializer): MessageVariantChat {
+  const sender = PlayerName.deserialize(deserializer);
+  const text = deserializer.deserializeStr();
+  return new MessageVariantChat(sender,text);
+}
+
+}
+
+export class MessageVariantWhisper extends Message {
+
+constructor (public sender: PlayerName, public to: PlayerName, public text: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(21);
+  this.sender.serialize(serializer);
+  this.to.serialize(serializer);
+  serializer.serializeStr(this.text);
+}
+
+static load(deserializer: Deserializer): MessageVariantWhisper {
+  const sender = PlayerName.deserialize(deserializer);
+  const to = PlayerName.deserialize(deserializer);
+  const text = deserializer.deserializeStr();
+  return new MessageVariantWhisper(sender,to,text);
+}
+
+}
+
+export class MessageVariantDisconnected extends Message {
+
+constructor (public value: Disconnect) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(22);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantDisconnected {
+  const value = Disconnect.deserialize(deserializer);
+  return new MessageVariantDisconnected(value);
+}
+
+}
+
+export class MessageVariantNameRejected extends Message {
+
+constructor (public value: NameRejection) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(23);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantNameRejected {
+  const value = NameRejection.deserialize(deserializer);
+  return new MessageVariantNameRejected(value);
+}
+
+}
+
+export class MessageVariantLobby extends Message {
+
+constructor (public players: Seq<LobbyPlayer>, public seats: uint64) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(24);
+  Helpers.serializeVectorLobbyPlayer(this.players, serializer);
+  serializer.serializeU64(this.seats);
+}
+
+static load(deserializer: Deserializer): MessageVariantLobby {
+  const players = Helpers.deserializeVectorLobbyPlayer(deserializer);
+  const seats = deserializer.deserializeU64();
+  return new MessageVariantLobby(players,seats);
+}
+
+}
+
+export class MessageVariantSeries extends Message {
+
+constructor (public games: uint32, public scores: Seq<SeriesScore>) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(25);
+  serializer.serializeU32(this.games);
+  Helpers.serializeVectorSeriesScore(this.scores, serializer);
+}
+
+static load(deserializer: Deserializer): MessageVariantSeries {
+  const games = deserializer.deserializeU32();
+  const scores = Helpers.deserializeVectorSeriesScore(deserializer);
+  return new MessageVariantSeries(games,scores);
+}
+
+}
+
+export class MessageVariantRejoinToken extends Message {
+
+constructor (public value: str) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(26);
+  serializer.serializeStr(this.value);
+}
+
+static load(deserializer: Deserializer): MessageVariantRejoinToken {
+  const value = deserializer.deserializeStr();
+  return new MessageVariantRejoinToken(value);
+}
+
+}
+export abstract class NameRejection {
+abstract serialize(serializer: Serializer): void;
+
+static deserialize(deserializer: Deserializer): NameRejection {
+  const index = deserializer.deserializeVariantIndex();
+  switch (index) {
+    case 0: return NameRejectionVariantEmpty.load(deserializer);
+    case 1: return NameRejectionVariantTooLong.load(deserializer);
+    case 2: return NameRejectionVariantInvalidCharacter.load(deserializer);
+    case 3: return NameRejectionVariantTaken.load(deserializer);
+    default: throw new Error("Unknown variant index for NameRejection: " + index);
+  }
+}
+}
+
+
+export class NameRejectionVariantEmpty extends NameRejection {
+constructor () {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(0);
+}
+
+static load(deserializer: Deserializer): NameRejectionVariantEmpty {
+  return new NameRejectionVariantEmpty();
+}
+
+}
+
+export class NameRejectionVariantTooLong extends NameRejection {
+
+constructor (public max: uint64) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(1);
+  serializer.serializeU64(this.max);
+}
+
+static load(deserializer: Deserializer): NameRejectionVariantTooLong {
+  const max = deserializer.deserializeU64();
+  return new NameRejectionVariantTooLong(max);
+}
+
+}
+
+export class NameRejectionVariantInvalidCharacter extends NameRejection {
+
+constructor (public value: char) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(2);
+  serializer.serializeChar(this.value);
+}
+
+static load(deserializer: Deserializer): NameRejectionVariantInvalidCharacter {
+  const value = deserializer.deserializeChar();
+  return new NameRejectionVariantInvalidCharacter(value);
+}
+
+}
+
+export class NameRejectionVariantTaken extends NameRejection {
+
+constructor (public value: PlayerName) {
+  super();
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeVariantIndex(3);
+  this.value.serialize(serializer);
+}
+
+static load(deserializer: Deserializer): NameRejectionVariantTaken {
+  const value = PlayerName.deserialize(deserializer);
+  return new NameRejectionVariantTaken(value);
+}
+
+}
+export class Play {
+
+constructor (public player: PlayerName, public card: Card) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.player.serialize(serializer);
+  this.card.serialize(serializer);
+}
+
+static deserialize(deserializer: Deserializer): Play {
+  const player = PlayerName.deserialize(deserializer);
+  const card = Card.deserialize(deserializer);
+  return new Play(player,card);
+}
+
+}
+export class Player {
+
+constructor (public name: PlayerName, public human: bool, public hand: Seq<Card>) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.name.serialize(serializer);
+  serializer.serializeBool(this.human);
+  Helpers.serializeVectorCard(this.hand, serializer);
+}
+
+static deserialize(deserializer: Deserializer): Player {
+  const name = PlayerName.deserialize(deserializer);
+  const human = deserializer.deserializeBool();
+  const hand = Helpers.deserializeVectorCard(deserializer);
+  return new Player(name,human,hand);
+}
+
+}
+export class PlayerName {
+
+constructor (public value: str) {
+}
+
+public serialize(serializer: Serializer): void {
+  serializer.serializeStr(this.value);
+}
+
+static deserialize(deserializer: Deserializer): PlayerName {
+  const value = deserializer.deserializeStr();
+  return new PlayerName(value);
+}
+
+}
+export class PublicPlayer {
+
+constructor (public name: PlayerName, public human: bool) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.name.serialize(serializer);
+  serializer.serializeBool(this.human);
+}
+
+static deserialize(deserializer: Deserializer): PublicPlayer {
+  const name = PlayerName.deserialize(deserializer);
+  const human = deserializer.deserializeBool();
+  return new PublicPlayer(name,human);
+}
+
+}
+export class PublicState {
+
+constructor (public guess: Optional<uint64>, public wins: uint64, public score: uint64) {
+}
+
+public serialize(serializer: Serializer): void {
+  Helpers.serializeOptionU64(this.guess, serializer);
+  serializer.serializeU64(this.wins);
+  serializer.serializeU64(this.score);
+}
+
+static deserialize(deserializer: Deserializer): PublicState {
+  const guess = Helpers.deserializeOptionU64(deserializer);
+  const wins = deserializer.deserializeU64();
+  const score = deserializer.deserializeU64();
+  return new PublicState(guess,wins,score);
 }
 
 }
-export abstract class Message {
+export abstract class Rejection {
 abstract serialize(serializer: Serializer): void;
 
-static deserialize(deserializer: Deserializer): Message {
+static deserialize(deserializer: Deserializer): Rejection {
   const index = deserializer.deserializeVariantIndex();
   switch (index) {
-    case 0: return MessageVariantRequestGuessContext.load(deserializer);
-    case 1: return MessageVariantGuesses.load(deserializer);
-    case 2: return MessageVariantTurn.load(deserializer);
-    case 3: return MessageVariantPlayRequestContext.load(deserializer);
-    case 4: return MessageVariantTrick.load(deserializer);
-    case 5: return MessageVariantScoreboard.load(deserializer);
-    case 6: return MessageVariantWinner.load(deserializer);
-    case 7: return MessageVariantWinners.load(deserializer);
-    case 8: return MessageVariantRequestPlayerName.load(deserializer);
-    case 9: return MessageVariantPlayRequest.load(deserializer);
-    case 10: return MessageVariantRequestGuess.load(deserializer);
-    case 11: return MessageVariantGameOver.load(deserializer);
-    default: throw new Error("Unknown variant index for Message: " + index);
+    case 0: return RejectionVariantNotANumber.load(deserializer);
+    case 1: return RejectionVariantGuessTooHigh.load(deserializer);
+    case 2: return RejectionVariantForbiddenGuess.load(deserializer);
+    case 3: return RejectionVariantNoSuchCard.load(deserializer);
+    case 4: return RejectionVariantMustFollowSuit.load(deserializer);
+    case 5: return RejectionVariantNotACard.load(deserializer);
+    case 6: return RejectionVariantCardNotInHand.load(deserializer);
+    case 7: return RejectionVariantChatTooLong.load(deserializer);
+    case 8: return RejectionVariantUnknownPlayer.load(deserializer);
+    case 9: return RejectionVariantNotHost.load(deserializer);
+    case 10: return RejectionVariantNotReady.load(deserializer);
+    case 11: return RejectionVariantTooFewPlayers.load(deserializer);
+    default: throw new Error("Unknown variant index for Rejection: " + index);
   }
 }
 }
 
 
-export class MessageVariantRequestGuessContext extends Message {
+export class RejectionVariantNotANumber extends Rejection {
 
-constructor (public player: Player, public hand: Seq<Card>, public guesses: Seq<uint64>, public players: uint64) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(0);
-  this.player.serialize(serializer);
-  Helpers.serializeVectorCard(this.hand, serializer);
-  Helpers.serializeVectorU64(this.guesses, serializer);
-  serializer.serializeU64(this.players);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): MessageVariantRequestGuessContext {
-  const player = Player.deserialize(deserializer);
-  const hand = Helpers.deserializeVectorCard(deserializer);
-  const guesses = Helpers.deserializeVectorU64(deserializer);
-  const players = deserializer.deserializeU64();
-  return new MessageVariantRequestGuessContext(player,hand,guesses,players);
+static load(deserializer: Deserializer): RejectionVariantNotANumber {
+  const value = deserializer.deserializeStr();
+  return new RejectionVariantNotANumber(value);
 }
 
 }
 
-export class MessageVariantGuesses extends Message {
+export class RejectionVariantGuessTooHigh extends Rejection {
 
-constructor (public state: Map<PlayerName,PublicState>) {
+constructor (public guess: uint64, public hand_size: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(1);
-  Helpers.serializeMapPlayerNameToPublicState(this.state, serializer);
+  serializer.serializeU64(this.guess);
+  serializer.serializeU64(this.hand_size);
 }
 
-static load(deserializer: Deserializer): MessageVariantGuesses {
-  const state = Helpers.deserializeMapPlayerNameToPublicState(deserializer);
-  return new MessageVariantGuesses(state);
+static load(deserializer: Deserializer): RejectionVariantGuessTooHigh {
+  const guess = deserializer.deserializeU64();
+  const hand_size = deserializer.deserializeU64();
+  return new RejectionVariantGuessTooHigh(guess,hand_size);
 }
 
 }
 
-export class MessageVariantTurn extends Message {
+export class RejectionVariantForbiddenGuess extends Rejection {
 
-constructor (public whose: Player) {
+constructor (public guess: uint64, public hand_size: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(2);
-  this.whose.serialize(serializer);
+  serializer.serializeU64(this.guess);
+  serializer.serializeU64(this.hand_size);
 }
 
-static load(deserializer: Deserializer): MessageVariantTurn {
-  const whose = Player.deserialize(deserializer);
-  return new MessageVariantTurn(whose);
+static load(deserializer: Deserializer): RejectionVariantForbiddenGuess {
+  const guess = deserializer.deserializeU64();
+  const hand_size = deserializer.deserializeU64();
+  return new RejectionVariantForbiddenGuess(guess,hand_size);
 }
 
 }
 
-export class MessageVariantPlayRequestContext extends Message {
+export class RejectionVariantNoSuchCard extends Rejection {
 
-constructor (public player: Player, public hand: Seq<Card>, public trick: Trick, public valid_cards: Optional<Seq<uint64>>) {
+constructor (public index: uint64, public hand_size: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(3);
-  this.player.serialize(serializer);
-  Helpers.serializeVectorCard(this.hand, serializer);
-  this.trick.serialize(serializer);
-  Helpers.serializeOptionVectorU64(this.valid_cards, serializer);
+  serializer.serializeU64(this.index);
+  serializer.serializeU64(this.hand_size);
 }
 
-static load(deserializer: Deserializer): MessageVariantPlayRequestContext {
-  const player = Player.deserialize(deserializer);
-  const hand = Helpers.deserializeVectorCard(deserializer);
-  const trick = Trick.deserialize(deserializer);
-  const valid_cards = Helpers.deserializeOptionVectorU64(deserializer);
-  return new MessageVariantPlayRequestContext(player,hand,trick,valid_cards);
+static load(deserializer: Deserializer): RejectionVariantNoSuchCard {
+  const index = deserializer.deserializeU64();
+  const hand_size = deserializer.deserializeU64();
+  return new RejectionVariantNoSuchCard(index,hand_size);
 }
 
 }
 
-export class MessageVariantTrick extends Message {
+export class RejectionVariantMustFollowSuit extends Rejection {
 
-constructor (public value: Trick) {
+constructor (public suit: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(4);
-  this.value.serialize(serializer);
+  serializer.serializeU64(this.suit);
 }
 
-static load(deserializer: Deserializer): MessageVariantTrick {
-  const value = Trick.deserialize(deserializer);
-  return new MessageVariantTrick(value);
+static load(deserializer: Deserializer): RejectionVariantMustFollowSuit {
+  const suit = deserializer.deserializeU64();
+  return new RejectionVariantMustFollowSuit(suit);
 }
 
 }
 
-export class MessageVariantScoreboard extends Message {
+export class RejectionVariantNotACard extends Rejection {
 
-constructor (public state: Map<PlayerName,PublicState>) {
+constructor (public value: str) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(5);
-  Helpers.serializeMapPlayerNameToPublicState(this.state, serializer);
+  serializer.serializeStr(this.value);
 }
 
-static load(deserializer: Deserializer): MessageVariantScoreboard {
-  const state = Helpers.deserializeMapPlayerNameToPublicState(deserializer);
-  return new MessageVariantScoreboard(state);
+static load(deserializer: Deserializer): RejectionVariantNotACard {
+  const value = deserializer.deserializeStr();
+  return new RejectionVariantNotACard(value);
 }
 
 }
 
-export class MessageVariantWinner extends Message {
+export class RejectionVariantCardNotInHand extends Rejection {
 
-constructor (public value: Player) {
+constructor (public value: Card) {
   super();
 }
 
@@ -175,51 +1442,54 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): MessageVariantWinner {
-  const value = Player.deserialize(deserializer);
-  return new MessageVariantWinner(value);
+static load(deserializer: Deserializer): RejectionVariantCardNotInHand {
+  const value = Card.deserialize(deserializer);
+  return new RejectionVariantCardNotInHand(value);
 }
 
 }
 
-export class MessageVariantWinners extends Message {
+export class RejectionVariantChatTooLong extends Rejection {
 
-constructor (public players: Seq<Player>, public winner_indices: Seq<uint64>) {
+constructor (public length: uint64, public max: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(7);
-  Helpers.serializeVectorPlayer(this.players, serializer);
-  Helpers.serializeVectorU64(this.winner_indices, serializer);
+  serializer.serializeU64(this.length);
+  serializer.serializeU64(this.max);
 }
 
-static load(deserializer: Deserializer): MessageVariantWinners {
-  const players = Helpers.deserializeVectorPlayer(deserializer);
-  const winner_indices = Helpers.deserializeVectorU64(deserializer);
-  return new MessageVariantWinners(players,winner_indices);
+static load(deserializer: Deserializer): RejectionVariantChatTooLong {
+  const length = deserializer.deserializeU64();
+  const max = deserializer.deserializeU64();
+  return new RejectionVariantChatTooLong(length,max);
 }
 
 }
 
-export class MessageVariantRequestPlayerName extends Message {
-constructor () {
+export class RejectionVariantUnknownPlayer extends Rejection {
+
+constructor (public value: PlayerName) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(8);
+  this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): MessageVariantRequestPlayerName {
-  return new MessageVariantRequestPlayerName();
+static load(deserializer: Deserializer): RejectionVariantUnknownPlayer {
+  const value = PlayerName.deserialize(deserializer);
+  return new RejectionVariantUnknownPlayer(value);
 }
 
 }
 
-export class MessageVariantPlayRequest extends Message {
+export class RejectionVariantNotHost extends Rejection {
 
-constructor (public value: Player) {
+constructor (public value: PlayerName) {
   super();
 }
 
@@ -228,107 +1498,136 @@ public serialize(serializer: Serializer): void {
   this.value.serialize(serializer);
 }
 
-static load(deserializer: Deserializer): MessageVariantPlayRequest {
-  const value = Player.deserialize(deserializer);
-  return new MessageVariantPlayRequest(value);
+static load(deserializer: Deserializer): RejectionVariantNotHost {
+  const value = PlayerName.deserialize(deserializer);
+  return new RejectionVariantNotHost(value);
 }
 
 }
 
-export class MessageVariantRequestGuess extends Message {
-constructor () {
+export class RejectionVariantNotReady extends Rejection {
+
+constructor (public value: Seq<PlayerName>) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(10);
+  Helpers.serializeVectorPlayerName(this.value, serializer);
 }
 
-static load(deserializer: Deserializer): MessageVariantRequestGuess {
-  return new MessageVariantRequestGuess();
+static load(deserializer: Deserializer): RejectionVariantNotReady {
+  const value = Helpers.deserializeVectorPlayerName(deserializer);
+  return new RejectionVariantNotReady(value);
 }
 
 }
 
-export class MessageVariantGameOver extends Message {
-constructor () {
+export class RejectionVariantTooFewPlayers extends Rejection {
+
+constructor (public min: uint64) {
   super();
 }
 
 public serialize(serializer: Serializer): void {
   serializer.serializeVariantIndex(11);
+  serializer.serializeU64(this.min);
 }
 
-static load(deserializer: Deserializer): MessageVariantGameOver {
-  return new MessageVariantGameOver();
+static load(deserializer: Deserializer): RejectionVariantTooFewPlayers {
+  const min = deserializer.deserializeU64();
+  return new RejectionVariantTooFewPlayers(min);
 }
 
 }
-export class Player {
+export class RoundScore {
 
-constructor (public name: PlayerName, public human: bool, public hand: Seq<Card>) {
+constructor (public guess: uint64, public wins: uint64, public points: uint64) {
 }
 
 public serialize(serializer: Serializer): void {
-  this.name.serialize(serializer);
-  serializer.serializeBool(this.human);
-  Helpers.serializeVectorCard(this.hand, serializer);
+  serializer.serializeU64(this.guess);
+  serializer.serializeU64(this.wins);
+  serializer.serializeU64(this.points);
 }
 
-static deserialize(deserializer: Deserializer): Player {
-  const name = PlayerName.deserialize(deserializer);
-  const human = deserializer.deserializeBool();
-  const hand = Helpers.deserializeVectorCard(deserializer);
-  return new Player(name,human,hand);
+static deserialize(deserializer: Deserializer): RoundScore {
+  const guess = deserializer.deserializeU64();
+  const wins = deserializer.deserializeU64();
+  const points = deserializer.deserializeU64();
+  return new RoundScore(guess,wins,points);
 }
 
 }
-export class PlayerName {
+export class ScoreSheet {
 
-constructor (public value: str) {
+constructor (public players: Seq<PlayerName>, public rounds: Seq<ScoreSheetRound>) {
 }
 
 public serialize(serializer: Serializer): void {
-  serializer.serializeStr(this.value);
+  Helpers.serializeVectorPlayerName(this.players, serializer);
+  Helpers.serializeVectorScoreSheetRound(this.rounds, serializer);
 }
 
-static deserialize(deserializer: Deserializer): PlayerName {
-  const value = deserializer.deserializeStr();
-  return new PlayerName(value);
+static deserialize(deserializer: Deserializer): ScoreSheet {
+  const players = Helpers.deserializeVectorPlayerName(deserializer);
+  const rounds = Helpers.deserializeVectorScoreSheetRound(deserializer);
+  return new ScoreSheet(players,rounds);
 }
 
 }
-export class PublicState {
+export class ScoreSheetRound {
 
-constructor (public guess: Optional<uint64>, public wins: uint64, public score: uint64) {
+constructor (public hand_size: uint64, public scores: Seq<RoundScore>) {
 }
 
 public serialize(serializer: Serializer): void {
-  Helpers.serializeOptionU64(this.guess, serializer);
-  serializer.serializeU64(this.wins);
-  serializer.serializeU64(this.score);
+  serializer.serializeU64(this.hand_size);
+  Helpers.serializeVectorRoundScore(this.scores, serializer);
 }
 
-static deserialize(deserializer: Deserializer): PublicState {
-  const guess = Helpers.deserializeOptionU64(deserializer);
-  const wins = deserializer.deserializeU64();
-  const score = deserializer.deserializeU64();
-  return new PublicState(guess,wins,score);
+static deserialize(deserializer: Deserializer): ScoreSheetRound {
+  const hand_size = deserializer.deserializeU64();
+  const scores = Helpers.deserializeVectorRoundScore(deserializer);
+  return new ScoreSheetRound(hand_size,scores);
+}
+
+}
+export class SeriesScore {
+
+constructor (public name: PlayerName, public played: uint32, public won: uint32) {
+}
+
+public serialize(serializer: Serializer): void {
+  this.name.serialize(serializer);
+  serializer.serializeU32(this.played);
+  serializer.serializeU32(this.won);
+}
+
+static deserialize(deserializer: Deserializer): SeriesScore {
+  const name = PlayerName.deserialize(deserializer);
+  const played = deserializer.deserializeU32();
+  const won = deserializer.deserializeU32();
+  return new SeriesScore(name,played,won);
 }
 
 }
 export class Trick {
 
-constructor (public value: Seq<Card>) {
+constructor (public plays: Seq<Play>, public led_suit: Optional<uint64>, public winning: Optional<uint64>) {
 }
 
 public serialize(serializer: Serializer): void {
-  Helpers.serializeVectorCard(this.value, serializer);
+  Helpers.serializeVectorPlay(this.plays, serializer);
+  Helpers.serializeOptionU64(this.led_suit, serializer);
+  Helpers.serializeOptionU64(this.winning, serializer);
 }
 
 static deserialize(deserializer: Deserializer): Trick {
-  const value = Helpers.deserializeVectorCard(deserializer);
-  return new Trick(value);
+  const plays = Helpers.deserializeVectorPlay(deserializer);
+  const led_suit = Helpers.deserializeOptionU64(deserializer);
+  const winning = Helpers.deserializeOptionU64(deserializer);
+  return new Trick(plays,led_suit,winning);
 }
 
 }
@@ -366,6 +1665,42 @@ export class Helpers {
     return obj;
   }
 
+  static serializeOptionPlayerName(value: Optional<PlayerName>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionPlayerName(deserializer: Deserializer): Optional<PlayerName> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return PlayerName.deserialize(deserializer);
+    }
+  }
+
+  static serializeOptionTrick(value: Optional<Trick>, serializer: Serializer): void {
+    if (value) {
+        serializer.serializeOptionTag(true);
+        value.serialize(serializer);
+    } else {
+        serializer.serializeOptionTag(false);
+    }
+  }
+
+  static deserializeOptionTrick(deserializer: Deserializer): Optional<Trick> {
+    const tag = deserializer.deserializeOptionTag();
+    if (!tag) {
+        return null;
+    } else {
+        return Trick.deserialize(deserializer);
+    }
+  }
+
   static serializeOptionU64(value: Optional<uint64>, serializer: Serializer): void {
     if (value) {
         serializer.serializeOptionTag(true);
@@ -402,6 +1737,22 @@ export class Helpers {
     }
   }
 
+  static serializeVectorCapability(value: Seq<Capability>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: Capability) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorCapability(deserializer: Deserializer): Seq<Capability> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<Capability> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(Capability.deserialize(deserializer));
+    }
+    return list;
+  }
+
   static serializeVectorCard(value: Seq<Card>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
     value.forEach((item: Card) => {
@@ -418,18 +1769,130 @@ export class Helpers {
     return list;
   }
 
-  static serializeVectorPlayer(value: Seq<Player>, serializer: Serializer): void {
+  static serializeVectorLobbyPlayer(value: Seq<LobbyPlayer>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: LobbyPlayer) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorLobbyPlayer(deserializer: Deserializer): Seq<LobbyPlayer> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<LobbyPlayer> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(LobbyPlayer.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorPlay(value: Seq<Play>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: Play) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorPlay(deserializer: Deserializer): Seq<Play> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<Play> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(Play.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorPlayerName(value: Seq<PlayerName>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: PlayerName) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorPlayerName(deserializer: Deserializer): Seq<PlayerName> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<PlayerName> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(PlayerName.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorPublicPlayer(value: Seq<PublicPlayer>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: PublicPlayer) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorPublicPlayer(deserializer: Deserializer): Seq<PublicPlayer> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<PublicPlayer> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(PublicPlayer.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorRoundScore(value: Seq<RoundScore>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: RoundScore) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorRoundScore(deserializer: Deserializer): Seq<RoundScore> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<RoundScore> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(RoundScore.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorScoreSheetRound(value: Seq<ScoreSheetRound>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: ScoreSheetRound) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorScoreSheetRound(deserializer: Deserializer): Seq<ScoreSheetRound> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<ScoreSheetRound> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(ScoreSheetRound.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorSeriesScore(value: Seq<SeriesScore>, serializer: Serializer): void {
+    serializer.serializeLen(value.length);
+    value.forEach((item: SeriesScore) => {
+        item.serialize(serializer);
+    });
+  }
+
+  static deserializeVectorSeriesScore(deserializer: Deserializer): Seq<SeriesScore> {
+    const length = deserializer.deserializeLen();
+    const list: Seq<SeriesScore> = [];
+    for (let i = 0; i < length; i++) {
+        list.push(SeriesScore.deserialize(deserializer));
+    }
+    return list;
+  }
+
+  static serializeVectorTrick(value: Seq<Trick>, serializer: Serializer): void {
     serializer.serializeLen(value.length);
-    value.forEach((item: Player) => {
+    value.forEach((item: Trick) => {
         item.serialize(serializer);
     });
   }
 
-  static deserializeVectorPlayer(deserializer: Deserializer): Seq<Player> {
+  static deserializeVectorTrick(deserializer: Deserializer): Seq<Trick> {
     const length = deserializer.deserializeLen();
-    const list: Seq<Player> = [];
+    const list: Seq<Trick> = [];
     for (let i = 0; i < length; i++) {
-        list.push(Player.deserialize(deserializer));
+        list.push(Trick.deserialize(deserializer));
     }
     return list;
   }
@@ -1,4 +1,8 @@
-use protocol::message::Message;
+use playing_cards::structs::{Card, Colour};
+use protocol::{
+    message::{ClientMessage, Message},
+    structs::{Capability, Disconnect, Encoding, HandshakeRejection, NameRejection, Rejection},
+};
 use serde_generate::SourceInstaller;
 use serde_reflection::Tracer;
 use serde_reflection::TracerConfig;
@@ -83,7 +87,16 @@ fn trace_message() -> (
     serde_generate::CodeGeneratorConfig,
 ) {
     let mut tracer = Tracer::new(TracerConfig::default());
+    tracer.trace_simple_type::<Colour>().unwrap();
+    tracer.trace_simple_type::<Card>().unwrap();
     tracer.trace_simple_type::<Message>().unwrap();
+    tracer.trace_simple_type::<Rejection>().unwrap();
+    tracer.trace_simple_type::<ClientMessage>().unwrap();
+    tracer.trace_simple_type::<Encoding>().unwrap();
+    tracer.trace_simple_type::<Capability>().unwrap();
+    tracer.trace_simple_type::<HandshakeRejection>().unwrap();
+    tracer.trace_simple_type::<Disconnect>().unwrap();
+    tracer.trace_simple_type::<NameRejection>().unwrap();
 
     let registry = tracer.registry().unwrap();
 
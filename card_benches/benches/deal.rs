@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use playing_cards::{
+    card_set::CardSet,
+    helpers::{create_deck, draw_hand},
+    structs::{Card, Suit},
+};
+use rand::seq::IteratorRandom;
+
+const PLAYERS: usize = 4;
+const HAND_SIZE: usize = 10;
+
+/// The `draw_hand` the engine used to have, which dealt from a `HashSet`.
+fn draw_hash_set_hand(deck: HashSet<Card>, num: usize) -> (HashSet<Card>, Vec<Card>) {
+    let hand = HashSet::from_iter(
+        deck.iter()
+            .copied()
+            .choose_multiple(&mut rand::thread_rng(), num),
+    );
+    let mut sorted: Vec<Card> = hand.iter().copied().collect();
+    sorted.sort();
+    (deck.difference(&hand).copied().collect(), sorted)
+}
+
+fn deal_hash_set(mut deck: HashSet<Card>) -> Vec<Vec<Card>> {
+    let mut hands = Vec::with_capacity(PLAYERS);
+    for _ in 0..PLAYERS {
+        let hand;
+        (deck, hand) = draw_hash_set_hand(deck, HAND_SIZE);
+        hands.push(hand);
+    }
+    hands
+}
+
+fn deal_vec(mut deck: Vec<Card>) -> Vec<Vec<Card>> {
+    let mut hands = Vec::with_capacity(PLAYERS);
+    for _ in 0..PLAYERS {
+        let hand;
+        (deck, hand) = draw_hand(deck, HAND_SIZE);
+        hands.push(hand);
+    }
+    hands
+}
+
+fn deal_card_set(mut deck: CardSet) -> [CardSet; PLAYERS] {
+    let mut rng = rand::thread_rng();
+    [(); PLAYERS].map(|()| deck.deal(&mut rng, HAND_SIZE))
+}
+
+fn bench_deal(c: &mut Criterion) {
+    let deck = create_deck();
+    let hash_set: HashSet<Card> = deck.iter().copied().collect();
+    let set: CardSet = deck.iter().copied().collect();
+
+    let mut group = c.benchmark_group("deal");
+    group.bench_function("hash set", |b| {
+        b.iter(|| deal_hash_set(black_box(&hash_set).clone()));
+    });
+    group.bench_function("vec", |b| b.iter(|| deal_vec(black_box(&deck).clone())));
+    group.bench_function("card set", |b| b.iter(|| deal_card_set(black_box(set))));
+    group.finish();
+}
+
+fn bench_follow_suit(c: &mut Criterion) {
+    let mut deck: CardSet = create_deck().into_iter().collect();
+    let set = deck.deal(&mut rand::thread_rng(), HAND_SIZE);
+    let hand: Vec<Card> = set.iter().collect();
+
+    let mut group = c.benchmark_group("follow suit");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            Suit::iter()
+                .filter(|suit| {
+                    black_box(&hand)
+                        .iter()
+                        .any(|card| card.suit() == Some(*suit))
+                })
+                .count()
+        });
+    });
+    group.bench_function("card set", |b| {
+        b.iter(|| {
+            Suit::iter()
+                .filter(|suit| !black_box(set).suit(*suit).is_empty())
+                .count()
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_deal, bench_follow_suit);
+criterion_main!(benches);
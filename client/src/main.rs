@@ -1,21 +1,25 @@
 use std::{
-    collections::HashSet,
+    collections::BTreeSet,
     io::{Error as IoError, ErrorKind, Result as IoResult},
     net::IpAddr,
-    os::unix::thread,
     result::Result as StdResult,
 };
 
 use clap::Parser;
-use playing_cards::structs::Card;
-use protocol::message::Message;
-use rand::{distributions::Alphanumeric, seq::IteratorRandom, thread_rng, Rng};
+use playing_cards::structs::{Card, Rank};
+use protocol::{
+    message::{ClientMessage, Message, PROTOCOL_VERSION},
+    structs::{Capability, Encoding},
+};
+use rand::{distributions::Alphanumeric, seq::IteratorRandom, Rng};
 use tokio::{
     io::{copy, stdin, stdout, AsyncReadExt, AsyncWriteExt},
     net::TcpStream,
     task::JoinError,
 };
 
+// The fields are only read through `Debug` when `main` returns an error.
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Error {
     Connect(IoError),
@@ -45,62 +49,81 @@ async fn main() -> Result<()> {
     socket.set_nodelay(true).expect("set_nodelay failed");
 
     if args.ai {
-        let name: String = rand::thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(7)
-            .map(char::from)
-            .map(|c| c.to_uppercase().to_string())
-            .collect::<String>();
+        let mut name = random_name();
+
+        let hello = ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+            encoding: Encoding::Json,
+            capabilities: vec![Capability::Rejections, Capability::LegalActions],
+        };
+        send_to_remote(&mut socket, serde_json::to_string(&hello).unwrap() + "\n")
+            .await
+            .unwrap();
 
         loop {
             let server_message = readline_from_remote(&mut socket).await.unwrap();
 
             for line in server_message.lines() {
-                let message: Message = serde_json::from_str(line).unwrap();
+                // The name prompt is sent as text before the handshake is answered.
+                let Ok(message) = serde_json::from_str::<Message>(line) else {
+                    continue;
+                };
 
                 match message {
-                    // Message::RequestGuessContext {
-                    //     player: _,
-                    //     hand,
-                    //     guesses,
-                    //     players,
-                    // } => {
-                    //     let guess = make_guess(players, hand, guesses);
-                    //     send_to_remote(&mut socket, guess.to_string() + "\n")
-                    //         .await
-                    //         .unwrap();
-                    // }
-                    // Message::PlayRequestContext {
-                    //     player: _,
-                    //     hand,
-                    //     trick: _,
-                    //     valid_cards,
-                    // } => {
-                    //     let play = make_play(hand, valid_cards);
-                    //     send_to_remote(&mut socket, play.to_string() + "\n")
-                    //         .await
-                    //         .unwrap();
-                    // }
-                    // Message::RequestPlayerName => {
-                    //     send_to_remote(&mut socket, name.to_string() + "\n")
-                    //         .await
-                    //         .unwrap();
-                    // }
-                    // Message::Winners {
-                    //     players: _,
-                    //     winner_indices: _,
-                    // } => {
-                    //     println!("{}", message.to_string());
-                    // }
-                    // Message::GameOver => {
-                    //     return Ok(());
-                    // }
-                    _ => send_to_remote(
-                        &mut socket,
-                        format!("{}\n", (0..10).choose(&mut thread_rng()).unwrap()),
-                    )
-                    .await
-                    .unwrap(),
+                    Message::RequestGuessContext {
+                        player: _,
+                        hand,
+                        guesses: _,
+                        players: _,
+                        legal_guesses,
+                    } => {
+                        let guess = make_guess(hand, legal_guesses.unwrap());
+                        send_to_remote(&mut socket, guess.to_string() + "\n")
+                            .await
+                            .unwrap();
+                    }
+                    Message::PlayRequestContext {
+                        player: _,
+                        hand: _,
+                        trick: _,
+                        valid_cards,
+                    } => {
+                        let play = make_play(valid_cards.unwrap());
+                        send_to_remote(&mut socket, play.to_string() + "\n")
+                            .await
+                            .unwrap();
+                    }
+                    Message::RequestPlayerName => {
+                        send_to_remote(&mut socket, name.to_string() + "\n")
+                            .await
+                            .unwrap();
+                    }
+                    Message::Winners {
+                        players: _,
+                        winner_indices: _,
+                    } => {
+                        println!("{message}");
+                    }
+                    Message::GameOver => {
+                        return Ok(());
+                    }
+                    // Picks another name, the server asks again right away.
+                    Message::NameRejected(_) => name = random_name(),
+                    // The game starts once everyone at a full table is ready.
+                    Message::Lobby { players, seats: _ } => {
+                        let waiting = players
+                            .iter()
+                            .any(|player| player.name.as_str() == name && !player.ready);
+                        if waiting {
+                            let ready = serde_json::to_string(&ClientMessage::Ready(true)).unwrap();
+                            send_to_remote(&mut socket, ready + "\n").await.unwrap();
+                        }
+                    }
+                    Message::HandshakeRejected(_) => {
+                        println!("{message}");
+                        return Ok(());
+                    }
+                    _ => (),
                 }
             }
         }
@@ -124,20 +147,31 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-fn make_play(hand: Vec<Card>, valid_cards: Option<HashSet<usize>>) -> usize {
-    valid_cards.map_or_else(
-        || rand::thread_rng().gen_range(0..hand.len()),
-        |choices| *choices.iter().next().unwrap(),
-    )
+fn random_name() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(7)
+        .map(char::from)
+        .map(|c| c.to_uppercase().to_string())
+        .collect::<String>()
 }
 
-fn make_guess(players: usize, hand: Vec<Card>, guesses: Vec<usize>) -> usize {
-    let guess = hand.iter().filter(|x| x.value >= 10).count();
-    if guesses.len() == players - 1 && guess + guesses.iter().sum::<usize>() == hand.len() {
-        return if guess != 0 { guess - 1 } else { guess + 1 };
-    }
+fn make_play(valid_cards: BTreeSet<usize>) -> usize {
+    valid_cards
+        .into_iter()
+        .choose(&mut rand::thread_rng())
+        .unwrap()
+}
 
-    guess
+fn make_guess(hand: Vec<Card>, legal_guesses: BTreeSet<usize>) -> usize {
+    let guess = hand
+        .iter()
+        .filter(|x| x.value().is_none_or(|value| value >= Rank::Queen))
+        .count();
+    legal_guesses
+        .into_iter()
+        .min_by_key(|legal| legal.abs_diff(guess))
+        .unwrap()
 }
 
 async fn send_to_remote(socket: &mut TcpStream, text: String) -> IoResult<()> {
@@ -174,6 +208,6 @@ async fn readline_from_remote(socket: &mut TcpStream) -> IoResult<String> {
             continue;
         }
 
-        return String::from_utf8(all).map_err(|_| IoError::new(ErrorKind::Other, "invalid UTF-8"));
+        return String::from_utf8(all).map_err(|_| IoError::other("invalid UTF-8"));
     }
 }
@@ -0,0 +1,330 @@
+use std::ops::{BitAnd, BitOr, Not, Sub};
+
+use rand::Rng;
+
+use crate::structs::{Card, Colour, Rank, Suit};
+
+const RANKS: usize = Rank::ALL.len();
+const SUIT_MASK: u64 = (1 << RANKS) - 1;
+/// The jokers come after the cards of every suit.
+const FIRST_JOKER: usize = Suit::ALL.len() * RANKS;
+const JOKER_MASK: u64 = ((1 << Colour::ALL.len()) - 1) << FIRST_JOKER;
+const ALL_MASK: u64 = (1 << (FIRST_JOKER + Colour::ALL.len())) - 1;
+
+/// A set of distinct cards stored as a bitmask, with one bit per suit and
+/// rank and one per joker colour. Iterates in the same order as sorting the
+/// cards.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct CardSet(u64);
+
+const fn bit(card: Card) -> u64 {
+    let position = match card {
+        Card::Suited { suit, value } => suit.index() * RANKS + value.index(),
+        Card::Joker(colour) => FIRST_JOKER + colour.index(),
+    };
+    1 << position
+}
+
+const fn card_at(position: u32) -> Card {
+    let position = position as usize;
+    if position >= FIRST_JOKER {
+        return Card::Joker(Colour::ALL[position - FIRST_JOKER]);
+    }
+    Card::new(Suit::ALL[position / RANKS], Rank::ALL[position % RANKS])
+}
+
+impl CardSet {
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Every card of every suit, and a joker of each colour.
+    pub const fn all() -> Self {
+        Self(ALL_MASK)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, card: Card) -> bool {
+        self.0 & bit(card) != 0
+    }
+
+    /// Returns whether the card was not already in the set.
+    pub const fn insert(&mut self, card: Card) -> bool {
+        let inserted = !self.contains(card);
+        self.0 |= bit(card);
+        inserted
+    }
+
+    /// Returns whether the card was in the set.
+    pub const fn remove(&mut self, card: Card) -> bool {
+        let removed = self.contains(card);
+        self.0 &= !bit(card);
+        removed
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// The cards of `suit`, which never include jokers.
+    pub const fn suit(self, suit: Suit) -> Self {
+        Self(self.0 & (SUIT_MASK << (suit.index() * RANKS)))
+    }
+
+    pub const fn jokers(self) -> Self {
+        Self(self.0 & JOKER_MASK)
+    }
+
+    pub const fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+
+    pub const fn iter_suit(self, suit: Suit) -> Iter {
+        self.suit(suit).iter()
+    }
+
+    /// Removes `num` random cards from the set and returns them, or all
+    /// remaining cards if there are fewer than that.
+    pub fn deal<R: Rng + ?Sized>(&mut self, rng: &mut R, num: usize) -> Self {
+        let mut random = RandomBits::new(rng);
+        let mut hand = Self::new();
+
+        for remaining in (1..=self.len() as u32).rev().take(num) {
+            // Guessing positions is quickest while most of them are taken.
+            let position = if remaining * 2 >= u64::BITS {
+                loop {
+                    let position = random.take(u64::BITS.trailing_zeros());
+                    if self.0 & (1 << position) != 0 {
+                        break position;
+                    }
+                }
+            } else {
+                let mut bits = self.0;
+                for _ in 0..random.below(remaining) {
+                    bits &= bits - 1;
+                }
+                u64::from(bits.trailing_zeros())
+            };
+
+            self.0 &= !(1 << position);
+            hand.0 |= 1 << position;
+        }
+
+        hand
+    }
+}
+
+/// Hands out random bits a few at a time, since drawing a whole number for
+/// every card is what dominates the cost of dealing.
+struct RandomBits<'a, R: ?Sized> {
+    rng: &'a mut R,
+    bits: u64,
+    available: u32,
+}
+
+impl<'a, R: Rng + ?Sized> RandomBits<'a, R> {
+    const fn new(rng: &'a mut R) -> Self {
+        Self {
+            rng,
+            bits: 0,
+            available: 0,
+        }
+    }
+
+    fn take(&mut self, count: u32) -> u64 {
+        if self.available < count {
+            self.bits = self.rng.next_u64();
+            self.available = u64::BITS;
+        }
+
+        let taken = self.bits & ((1 << count) - 1);
+        self.bits >>= count;
+        self.available -= count;
+        taken
+    }
+
+    /// An unbiased number below `bound`, using Lemire's method.
+    fn below(&mut self, bound: u32) -> u32 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.take(u32::BITS) * u64::from(bound);
+            if product as u32 >= threshold {
+                return (product >> u32::BITS) as u32;
+            }
+        }
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & ALL_MASK)
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut set = Self::new();
+        set.extend(cards);
+        set
+    }
+}
+
+impl Extend<Card> for CardSet {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        for card in cards {
+            self.insert(card);
+        }
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Iter(u64);
+
+impl Iterator for Iter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let position = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(card_at(position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_matches_btree_set(a in any::<BTreeSet<Card>>(), b in any::<BTreeSet<Card>>()) {
+            let set_a: CardSet = a.iter().copied().collect();
+            let set_b: CardSet = b.iter().copied().collect();
+
+            prop_assert_eq!(set_a.len(), a.len());
+            prop_assert!(set_a.iter().eq(a.iter().copied()));
+            prop_assert!((set_a | set_b).iter().eq(a.union(&b).copied()));
+            prop_assert!((set_a & set_b).iter().eq(a.intersection(&b).copied()));
+            prop_assert!((set_a - set_b).iter().eq(a.difference(&b).copied()));
+            prop_assert_eq!(set_a.is_subset(set_b), a.is_subset(&b));
+            prop_assert_eq!((!set_a).len(), CardSet::all().len() - a.len());
+        }
+
+        #[test]
+        fn test_suit(cards in any::<BTreeSet<Card>>(), suit in any::<Suit>()) {
+            let set: CardSet = cards.iter().copied().collect();
+            let expected = cards
+                .iter()
+                .copied()
+                .filter(|card| card.suit() == Some(suit));
+
+            prop_assert!(set.iter_suit(suit).eq(expected));
+        }
+
+        #[test]
+        fn test_deal(cards in any::<BTreeSet<Card>>(), num in 0..20_usize) {
+            let mut deck: CardSet = cards.iter().copied().collect();
+            let original = deck;
+            let hand = deck.deal(&mut rand::thread_rng(), num);
+
+            prop_assert_eq!(hand.len(), num.min(original.len()));
+            prop_assert!(hand.is_subset(original));
+            prop_assert_eq!(hand | deck, original);
+            prop_assert!((hand & deck).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = CardSet::new();
+        let card: Card = "QS".parse().unwrap();
+
+        assert!(set.insert(card));
+        assert!(!set.insert(card));
+        assert!(set.contains(card));
+        assert_eq!(set.len(), 1);
+        assert!(set.remove(card));
+        assert!(!set.remove(card));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_all() {
+        let all = CardSet::all();
+        assert_eq!(all.len(), 54);
+        assert_eq!(all.jokers().len(), 2);
+        assert!(all.iter().tuple_windows().all(|(a, b)| a < b));
+    }
+}
@@ -1,29 +1,188 @@
-use std::collections::HashSet;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
 
 use itertools::{iproduct, Itertools};
-use rand::seq::IteratorRandom;
+use rand::seq::SliceRandom;
 
-use crate::structs::Card;
+use crate::{
+    card_set::CardSet,
+    structs::{Card, Colour, Rank, Suit},
+};
 
-pub type Deck = HashSet<Card>;
+/// May hold several identical cards when it is built from more than one
+/// deck.
+pub type Deck = Vec<Card>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DeckKind {
+    /// 52 cards, two to ace.
+    #[default]
+    French,
+    /// 36 cards, six to ace.
+    ThirtySix,
+    /// 32 cards, seven to ace.
+    Piquet,
+}
+
+impl DeckKind {
+    pub const fn lowest_rank(self) -> Rank {
+        match self {
+            DeckKind::French => Rank::Two,
+            DeckKind::ThirtySix => Rank::Six,
+            DeckKind::Piquet => Rank::Seven,
+        }
+    }
+
+    pub fn ranks(self) -> impl Iterator<Item = Rank> + Clone {
+        Rank::iter().filter(move |rank| *rank >= self.lowest_rank())
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct UnknownDeckKind(pub String);
+
+impl Display for UnknownDeckKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Unknown deck '{}', expected french, thirty-six or piquet",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnknownDeckKind {}
+
+impl FromStr for DeckKind {
+    type Err = UnknownDeckKind;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.to_ascii_lowercase().as_str() {
+            "french" | "52" => Ok(DeckKind::French),
+            "thirty-six" | "36" => Ok(DeckKind::ThirtySix),
+            "piquet" | "32" => Ok(DeckKind::Piquet),
+            _ => Err(UnknownDeckKind(text.to_owned())),
+        }
+    }
+}
+
+/// Describes the cards to play with: one or more copies of a deck, plus
+/// jokers. Jokers alternate between red and black.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DeckBuilder {
+    kind: DeckKind,
+    copies: usize,
+    jokers: usize,
+}
+
+impl Default for DeckBuilder {
+    fn default() -> Self {
+        Self::new(DeckKind::default())
+    }
+}
+
+impl DeckBuilder {
+    pub const fn new(kind: DeckKind) -> Self {
+        Self {
+            kind,
+            copies: 1,
+            jokers: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn copies(mut self, copies: usize) -> Self {
+        self.copies = copies;
+        self
+    }
+
+    #[must_use]
+    pub const fn jokers(mut self, jokers: usize) -> Self {
+        self.jokers = jokers;
+        self
+    }
+
+    pub fn size(&self) -> usize {
+        Suit::ALL.len() * self.kind.ranks().count() * self.copies + self.jokers
+    }
+
+    pub fn build(&self) -> Deck {
+        let jokers = Colour::ALL
+            .into_iter()
+            .cycle()
+            .take(self.jokers)
+            .map(Card::Joker);
+
+        (0..self.copies)
+            .flat_map(|_| {
+                iproduct!(Suit::iter(), self.kind.ranks())
+                    .map(|(suit, value)| Card::new(suit, value))
+            })
+            .chain(jokers)
+            .collect()
+    }
+
+    /// A fresh shoe to deal a round from.
+    pub fn shoe(&self) -> Shoe {
+        let deck = self.build();
+        let cards: CardSet = deck.iter().copied().collect();
+
+        if cards.len() == deck.len() {
+            Shoe::Distinct(cards)
+        } else {
+            Shoe::Duplicates(deck)
+        }
+    }
+}
+
+/// The undealt cards of a round. Decks without duplicate cards are kept as
+/// a `CardSet`, which is much faster to deal from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Shoe {
+    Distinct(CardSet),
+    Duplicates(Deck),
+}
+
+impl Shoe {
+    pub const fn len(&self) -> usize {
+        match self {
+            Shoe::Distinct(cards) => cards.len(),
+            Shoe::Duplicates(deck) => deck.len(),
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Deals a sorted hand of `num` cards, or of all remaining cards if
+    /// there are fewer than that.
+    pub fn draw_hand(&mut self, num: usize) -> Vec<Card> {
+        match self {
+            Shoe::Distinct(cards) => cards.deal(&mut rand::thread_rng(), num).iter().collect(),
+            Shoe::Duplicates(deck) => {
+                let hand;
+                (*deck, hand) = draw_hand(std::mem::take(deck), num);
+                hand
+            }
+        }
+    }
+}
 
 pub fn create_deck() -> Deck {
-    iproduct!(0..4, 0..13)
-        .map(|(x, y)| Card { suit: x, value: y })
-        .collect()
-}
-
-pub fn draw_hand(deck: Deck, num: usize) -> (Deck, Vec<Card>) {
-    let hand = HashSet::from_iter(
-        deck.iter()
-            .copied()
-            .choose_multiple(&mut rand::thread_rng(), num),
-    );
-    (
-        deck.difference(&hand).copied().collect(),
-        hand.into_iter().sorted().collect(),
-    )
+    DeckBuilder::default().build()
+}
+
+pub fn draw_hand(mut deck: Deck, num: usize) -> (Deck, Vec<Card>) {
+    let num = num.min(deck.len());
+    // Only the drawn cards need shuffling, and they end up last.
+    deck.partial_shuffle(&mut rand::thread_rng(), num);
+    let hand = deck.split_off(deck.len() - num);
+    (deck, hand.into_iter().sorted().collect())
 }
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -40,11 +199,10 @@ mod test {
         #[test]
         fn test_draw_hand((deck, hand_size) in deck_and_hand_size()) {
             let (new_deck, hand) = draw_hand(deck.clone(), hand_size);
-            prop_assert!(new_deck.is_subset(&deck));
             prop_assert_eq!(hand.len(), hand_size);
             prop_assert_eq!(new_deck.len() + hand_size, deck.len());
-            let hand_set: Deck = hand.into_iter().collect();
-            prop_assert!(hand_set.is_subset(&deck));
+            let all_cards: Deck = new_deck.into_iter().chain(hand).sorted().collect();
+            prop_assert_eq!(all_cards, deck.into_iter().sorted().collect::<Deck>());
         }
     }
 
@@ -52,5 +210,51 @@ mod test {
     fn test_create_deck() {
         let deck = create_deck();
         assert_eq!(deck.len(), 52);
+        assert_eq!(deck.iter().unique().count(), 52);
+    }
+
+    #[test]
+    fn test_deck_builder() {
+        let piquet = DeckBuilder::new(DeckKind::Piquet);
+        assert_eq!(piquet.size(), 32);
+        assert_eq!(piquet.build().len(), 32);
+        assert!(piquet
+            .build()
+            .iter()
+            .all(|card| card.value() >= Some(Rank::Seven)));
+
+        let thirty_six = DeckBuilder::new(DeckKind::ThirtySix);
+        assert_eq!(thirty_six.build().len(), thirty_six.size());
+        assert_eq!(thirty_six.size(), 36);
+
+        let big = DeckBuilder::default().copies(2).jokers(3);
+        let deck = big.build();
+        assert_eq!(big.size(), 107);
+        assert_eq!(deck.len(), 107);
+        assert_eq!(deck.iter().unique().count(), 54);
+        assert_eq!(deck.iter().filter(|card| card.is_joker()).count(), 3);
+    }
+
+    #[test]
+    fn test_shoe() {
+        let mut shoe = DeckBuilder::default().jokers(2).shoe();
+        assert!(matches!(shoe, Shoe::Distinct(_)));
+        let hand = shoe.draw_hand(10);
+        assert_eq!(hand.len(), 10);
+        assert!(hand.iter().tuple_windows().all(|(a, b)| a < b));
+        assert_eq!(shoe.len(), 44);
+
+        let mut shoe = DeckBuilder::new(DeckKind::Piquet).copies(2).shoe();
+        assert!(matches!(shoe, Shoe::Duplicates(_)));
+        assert_eq!(shoe.draw_hand(60).len(), 60);
+        assert_eq!(shoe.draw_hand(10).len(), 4);
+        assert!(shoe.is_empty());
+    }
+
+    #[test]
+    fn test_parse_deck_kind() {
+        assert_eq!("Piquet".parse(), Ok(DeckKind::Piquet));
+        assert_eq!("36".parse(), Ok(DeckKind::ThirtySix));
+        assert!("tarot".parse::<DeckKind>().is_err());
     }
 }
@@ -1,2 +1,4 @@
+pub mod card_set;
 pub mod helpers;
+pub mod notation;
 pub mod structs;
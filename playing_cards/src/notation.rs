@@ -0,0 +1,288 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use crate::structs::{Card, Colour, Rank, Suit};
+
+const SUIT_LETTERS: [&str; 4] = ["H", "C", "D", "S"];
+const SUIT_SYMBOLS: [[char; 2]; 4] = [['♥', '♡'], ['♣', '♧'], ['♦', '♢'], ['♠', '♤']];
+const SUIT_NAMES: [&str; 4] = ["Hearts", "Clubs", "Diamonds", "Spades"];
+const RANK_SYMBOLS: [&str; 13] = [
+    "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A",
+];
+const RANK_NAMES: [&str; 13] = [
+    "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen",
+    "King", "Ace",
+];
+const COLOUR_LETTERS: [&str; 2] = ["R", "B"];
+const COLOUR_NAMES: [&str; 2] = ["Red", "Black"];
+const JOKER_SYMBOL: &str = "JK";
+const JOKER_NAME: &str = "Joker";
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ParseCardError(pub String);
+
+impl Display for ParseCardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "'{}' is not a card", self.0)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+/// Written as a letter (`H`), or as a name (`Hearts`) with `{:#}`.
+impl Display for Suit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            f.write_str(SUIT_NAMES[self.index()])
+        } else {
+            f.write_str(SUIT_LETTERS[self.index()])
+        }
+    }
+}
+
+/// Written as a symbol (`10`, `A`), or as a name (`Ten`, `Ace`) with `{:#}`.
+impl Display for Rank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            f.write_str(RANK_NAMES[self.index()])
+        } else {
+            f.write_str(RANK_SYMBOLS[self.index()])
+        }
+    }
+}
+
+/// Written as a letter (`R`), or as a name (`Red`) with `{:#}`.
+impl Display for Colour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            f.write_str(COLOUR_NAMES[self.index()])
+        } else {
+            f.write_str(COLOUR_LETTERS[self.index()])
+        }
+    }
+}
+
+/// Written as rank and suit (`AH`, `10S`, `JKR` for a red joker), or in full
+/// (`Ace of Hearts`, `Red Joker`) with `{:#}`.
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self, f.alternate()) {
+            (Card::Suited { suit, value }, true) => write!(f, "{value:#} of {suit:#}"),
+            (Card::Suited { suit, value }, false) => write!(f, "{value}{suit}"),
+            (Card::Joker(colour), true) => write!(f, "{colour:#} {JOKER_NAME}"),
+            (Card::Joker(colour), false) => write!(f, "{JOKER_SYMBOL}{colour}"),
+        }
+    }
+}
+
+/// Accepts a letter, a symbol or a name, in any case.
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        let single = chars.next().filter(|_| chars.next().is_none());
+
+        Suit::iter()
+            .find(|suit| {
+                let index = suit.index();
+                single.is_some_and(|c| SUIT_SYMBOLS[index].contains(&c))
+                    || text.eq_ignore_ascii_case(SUIT_LETTERS[index])
+                    || text.eq_ignore_ascii_case(SUIT_NAMES[index])
+                    || text.eq_ignore_ascii_case(SUIT_NAMES[index].trim_end_matches('s'))
+            })
+            .ok_or_else(|| ParseCardError(text.to_owned()))
+    }
+}
+
+/// Accepts a symbol (`T` also means ten) or a name, in any case.
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+
+        if text.eq_ignore_ascii_case("T") {
+            return Ok(Rank::Ten);
+        }
+
+        Rank::iter()
+            .find(|rank| {
+                text.eq_ignore_ascii_case(RANK_SYMBOLS[rank.index()])
+                    || text.eq_ignore_ascii_case(RANK_NAMES[rank.index()])
+            })
+            .ok_or_else(|| ParseCardError(text.to_owned()))
+    }
+}
+
+/// Accepts a letter or a name, in any case.
+impl FromStr for Colour {
+    type Err = ParseCardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+
+        Colour::ALL
+            .into_iter()
+            .find(|colour| {
+                text.eq_ignore_ascii_case(COLOUR_LETTERS[colour.index()])
+                    || text.eq_ignore_ascii_case(COLOUR_NAMES[colour.index()])
+            })
+            .ok_or_else(|| ParseCardError(text.to_owned()))
+    }
+}
+
+/// Accepts rank before suit (`AH`, `10s`, `A♥`), suit symbol before rank
+/// (`♥A`), or the full name (`Ace of Hearts`). Jokers are written `JK` and
+/// their colour (`JKR`), or in full (`Red Joker`).
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let error = || ParseCardError(text.to_owned());
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        match words.as_slice() {
+            [rank, of, suit] if of.eq_ignore_ascii_case("of") => {
+                return Ok(Card::new(
+                    suit.parse().map_err(|_| error())?,
+                    rank.parse().map_err(|_| error())?,
+                ));
+            }
+            [colour, joker] if joker.eq_ignore_ascii_case(JOKER_NAME) => {
+                return colour.parse().map(Card::Joker).map_err(|_| error());
+            }
+            [_] => {}
+            _ => return Err(error()),
+        }
+
+        if let Some((symbol, colour)) = text.split_at_checked(JOKER_SYMBOL.len()) {
+            if symbol.eq_ignore_ascii_case(JOKER_SYMBOL) {
+                return colour.parse().map(Card::Joker).map_err(|_| error());
+            }
+        }
+
+        let first = text.chars().next().ok_or_else(error)?;
+        let last = text.chars().next_back().ok_or_else(error)?;
+
+        let (suit, rank) = if SUIT_SYMBOLS.iter().any(|symbols| symbols.contains(&first)) {
+            text.split_at(first.len_utf8())
+        } else {
+            let (rank, suit) = text.split_at(text.len() - last.len_utf8());
+            (suit, rank)
+        };
+
+        Ok(Card::new(
+            suit.parse().map_err(|_| error())?,
+            rank.parse().map_err(|_| error())?,
+        ))
+    }
+}
+
+/// Parses a list of cards, such as a hand or a trick. Cards are separated by
+/// commas if there are any (`Ace of Hearts, Two of Clubs`), and by
+/// whitespace otherwise (`AH KH 2C`).
+///
+/// # Errors
+///
+/// Fails on the first entry that isn't a card.
+pub fn parse_cards(text: &str) -> Result<Vec<Card>, ParseCardError> {
+    if text.contains(',') {
+        text.split(',')
+            .map(str::trim)
+            .filter(|card| !card.is_empty())
+            .map(str::parse)
+            .collect()
+    } else {
+        text.split_whitespace().map(str::parse).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(card in any::<Card>()) {
+            prop_assert_eq!(card.to_string().parse::<Card>(), Ok(card));
+            prop_assert_eq!(format!("{card:#}").parse::<Card>(), Ok(card));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let card = Card::new(Suit::Hearts, Rank::Ace);
+        assert_eq!(card.to_string(), "AH");
+        assert_eq!(format!("{card:#}"), "Ace of Hearts");
+
+        let card = Card::new(Suit::Spades, Rank::Ten);
+        assert_eq!(card.to_string(), "10S");
+        assert_eq!(format!("{card:#}"), "Ten of Spades");
+
+        let joker = Card::Joker(Colour::Red);
+        assert_eq!(joker.to_string(), "JKR");
+        assert_eq!(format!("{joker:#}"), "Red Joker");
+    }
+
+    #[test]
+    fn test_parse_notations() {
+        let ace_of_hearts = Card::new(Suit::Hearts, Rank::Ace);
+        let ten_of_spades = Card::new(Suit::Spades, Rank::Ten);
+
+        for text in [
+            "AH",
+            "ah",
+            "A♥",
+            "♥A",
+            "♡a",
+            "Ace of Hearts",
+            "ace of heart",
+        ] {
+            assert_eq!(text.parse(), Ok(ace_of_hearts), "{text}");
+        }
+        for text in ["10s", "TS", "♠10", "ten of spades"] {
+            assert_eq!(text.parse(), Ok(ten_of_spades), "{text}");
+        }
+        for text in ["JKR", "jkr", "Red Joker", "red joker"] {
+            assert_eq!(text.parse(), Ok(Card::Joker(Colour::Red)), "{text}");
+        }
+        for text in [
+            "",
+            "A",
+            "JK",
+            "JKH",
+            "Joker of Hearts",
+            "1H",
+            "AX",
+            "♥",
+            "Ace in Hearts",
+            "Ace of Hearts and Clubs",
+        ] {
+            assert!(text.parse::<Card>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_cards() {
+        let expected = vec![
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Clubs, Rank::Two),
+        ];
+
+        assert_eq!(parse_cards("AH KH 2C"), Ok(expected.clone()));
+        assert_eq!(parse_cards("AH, ♥K, 2c"), Ok(expected.clone()));
+        assert_eq!(
+            parse_cards("Ace of Hearts, King of Hearts, Two of Clubs"),
+            Ok(expected)
+        );
+        assert_eq!(parse_cards(""), Ok(vec![]));
+        assert_eq!(parse_cards("AH XX"), Err(ParseCardError("XX".to_owned())));
+    }
+}
@@ -1,11 +1,306 @@
-use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(test)]
 use proptest_derive::Arbitrary;
 
 #[derive(Hash, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, derive(Arbitrary))]
-pub struct Card {
-    pub suit: usize,
-    pub value: usize,
+pub enum Colour {
+    Red,
+    Black,
+}
+
+impl Colour {
+    pub const ALL: [Colour; 2] = [Colour::Red, Colour::Black];
+
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Serialized as its index in [`Suit::ALL`].
+#[derive(Hash, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(try_from = "usize", into = "usize")]
+#[cfg_attr(test, derive(Arbitrary))]
+pub enum Suit {
+    Hearts,
+    Clubs,
+    Diamonds,
+    Spades,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Clubs, Suit::Diamonds, Suit::Spades];
+
+    pub fn iter() -> impl Iterator<Item = Suit> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    pub const fn colour(self) -> Colour {
+        match self {
+            Suit::Hearts | Suit::Diamonds => Colour::Red,
+            Suit::Clubs | Suit::Spades => Colour::Black,
+        }
+    }
+}
+
+/// Serialized as its index in [`Rank::ALL`], so two is 0 and ace is 12.
+#[derive(Hash, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(try_from = "usize", into = "usize")]
+#[cfg_attr(test, derive(Arbitrary))]
+pub enum Rank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Rank> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct InvalidSuit(pub usize);
+
+impl Display for InvalidSuit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} is not a suit, expected 0 to 3", self.0)
+    }
+}
+
+impl std::error::Error for InvalidSuit {}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct InvalidRank(pub usize);
+
+impl Display for InvalidRank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} is not a rank, expected 0 to 12", self.0)
+    }
+}
+
+impl std::error::Error for InvalidRank {}
+
+impl TryFrom<usize> for Suit {
+    type Error = InvalidSuit;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Self::ALL.get(index).copied().ok_or(InvalidSuit(index))
+    }
+}
+
+impl From<Suit> for usize {
+    fn from(suit: Suit) -> Self {
+        suit.index()
+    }
+}
+
+impl TryFrom<usize> for Rank {
+    type Error = InvalidRank;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Self::ALL.get(index).copied().ok_or(InvalidRank(index))
+    }
+}
+
+impl From<Rank> for usize {
+    fn from(rank: Rank) -> Self {
+        rank.index()
+    }
+}
+
+/// Jokers have a colour but neither suit nor rank, and sort after every
+/// other card.
+///
+/// In JSON a suited card is the `{"suit":2,"value":12}` object it has always
+/// been, and a joker is `{"joker":"Red"}`. Binary formats and the generated
+/// bindings, which can't tell the variants apart by their fields, get the
+/// enum as it is.
+#[derive(Hash, Eq, PartialEq, Clone, Copy, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+#[serde(remote = "Self")]
+#[cfg_attr(test, derive(Arbitrary))]
+pub enum Card {
+    Suited { suit: Suit, value: Rank },
+    Joker(Colour),
+}
+
+/// A [`Card`] as it is written in JSON.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonCard {
+    Suited { suit: Suit, value: Rank },
+    Joker { joker: Colour },
+}
+
+impl From<Card> for JsonCard {
+    fn from(card: Card) -> Self {
+        match card {
+            Card::Suited { suit, value } => JsonCard::Suited { suit, value },
+            Card::Joker(joker) => JsonCard::Joker { joker },
+        }
+    }
+}
+
+impl From<JsonCard> for Card {
+    fn from(card: JsonCard) -> Self {
+        match card {
+            JsonCard::Suited { suit, value } => Card::Suited { suit, value },
+            JsonCard::Joker { joker } => Card::Joker(joker),
+        }
+    }
+}
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            JsonCard::from(*self).serialize(serializer)
+        } else {
+            Card::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            JsonCard::deserialize(deserializer).map(Card::from)
+        } else {
+            Card::deserialize(deserializer)
+        }
+    }
+}
+
+impl Card {
+    pub const fn new(suit: Suit, value: Rank) -> Self {
+        Card::Suited { suit, value }
+    }
+
+    /// `None` for a joker.
+    pub const fn suit(self) -> Option<Suit> {
+        match self {
+            Card::Suited { suit, .. } => Some(suit),
+            Card::Joker(_) => None,
+        }
+    }
+
+    /// `None` for a joker.
+    pub const fn value(self) -> Option<Rank> {
+        match self {
+            Card::Suited { value, .. } => Some(value),
+            Card::Joker(_) => None,
+        }
+    }
+
+    pub const fn is_joker(self) -> bool {
+        matches!(self, Card::Joker(_))
+    }
+
+    pub const fn colour(self) -> Colour {
+        match self {
+            Card::Suited { suit, .. } => suit.colour(),
+            Card::Joker(colour) => colour,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        for (index, suit) in Suit::iter().enumerate() {
+            assert_eq!(Suit::try_from(index), Ok(suit));
+            assert_eq!(usize::from(suit), index);
+        }
+        for (index, rank) in Rank::iter().enumerate() {
+            assert_eq!(Rank::try_from(index), Ok(rank));
+            assert_eq!(usize::from(rank), index);
+        }
+
+        assert_eq!(Suit::try_from(4), Err(InvalidSuit(4)));
+        assert_eq!(Rank::try_from(13), Err(InvalidRank(13)));
+    }
+
+    #[test]
+    fn test_ordering_and_colour() {
+        assert!(Rank::iter().zip(Rank::iter().skip(1)).all(|(a, b)| a < b));
+        assert!(Rank::Ace > Rank::King);
+        assert_eq!(Suit::Hearts.colour(), Colour::Red);
+        assert_eq!(Suit::Spades.colour(), Colour::Black);
+    }
+
+    #[test]
+    fn test_serde() {
+        let card = Card::new(Suit::Diamonds, Rank::Ace);
+        let json = r#"{"suit":2,"value":12}"#;
+
+        assert_eq!(serde_json::to_string(&card).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Card>(json).unwrap(), card);
+        assert!(serde_json::from_str::<Card>(r#"{"suit":4,"value":0}"#).is_err());
+        assert!(serde_json::from_str::<Card>(r#"{"suit":0,"value":13}"#).is_err());
+    }
+
+    #[test]
+    fn test_serde_joker() {
+        let joker = Card::Joker(Colour::Black);
+        let json = r#"{"joker":"Black"}"#;
+
+        assert_eq!(serde_json::to_string(&joker).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Card>(json).unwrap(), joker);
+        assert!(serde_json::from_str::<Card>(r#"{"joker":"Green"}"#).is_err());
+    }
+
+    #[test]
+    fn test_card_parts() {
+        let card = Card::new(Suit::Diamonds, Rank::Ace);
+        assert_eq!(card.suit(), Some(Suit::Diamonds));
+        assert_eq!(card.value(), Some(Rank::Ace));
+        assert_eq!(card.colour(), Colour::Red);
+        assert!(!card.is_joker());
+
+        let joker = Card::Joker(Colour::Black);
+        assert_eq!(joker.suit(), None);
+        assert_eq!(joker.value(), None);
+        assert_eq!(joker.colour(), Colour::Black);
+        assert!(joker.is_joker());
+        assert!(joker > Card::new(Suit::Spades, Rank::Ace));
+    }
 }
@@ -0,0 +1,221 @@
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Frames larger than this are refused instead of being allocated.
+pub const MAX_FRAME_LENGTH: u32 = 1 << 20;
+
+/// Encodes `value` as a frame: a little-endian `u32` length followed by the
+/// bincode payload, as produced by `bincode_serialize` in the bindings
+/// generated by the `api` crate.
+///
+/// # Errors
+///
+/// Fails if `value` can't be serialized or doesn't fit in a frame.
+pub fn encode_frame<T: Serialize>(value: &T) -> IoResult<Vec<u8>> {
+    let payload = bincode::serialize(value).map_err(invalid_data)?;
+    let length = u32::try_from(payload.len())
+        .ok()
+        .filter(|length| *length <= MAX_FRAME_LENGTH)
+        .ok_or_else(|| invalid_data("Frame is too large"))?;
+
+    let mut frame = Vec::with_capacity(payload.len() + 4);
+    frame.extend_from_slice(&length.to_le_bytes());
+    frame.extend_from_slice(&payload);
+    Ok(frame)
+}
+
+/// Decodes the payload of a frame, without its length prefix.
+///
+/// # Errors
+///
+/// Fails if the payload isn't a valid bincode encoding of `T`.
+pub fn decode_payload<T: DeserializeOwned>(payload: &[u8]) -> IoResult<T> {
+    bincode::deserialize(payload).map_err(invalid_data)
+}
+
+/// Writes `value` as a single frame.
+///
+/// # Errors
+///
+/// Fails if encoding fails or the writer returns an error.
+pub fn write_frame<W: Write, T: Serialize>(writer: &mut W, value: &T) -> IoResult<()> {
+    writer.write_all(&encode_frame(value)?)
+}
+
+/// Reads a single frame and decodes its payload.
+///
+/// # Errors
+///
+/// Fails if the reader returns an error, the announced length is larger
+/// than [`MAX_FRAME_LENGTH`], or the payload can't be decoded.
+pub fn read_frame<R: Read, T: DeserializeOwned>(reader: &mut R) -> IoResult<T> {
+    let mut length = [0_u8; 4];
+    reader.read_exact(&mut length)?;
+    let length = u32::from_le_bytes(length);
+
+    if length > MAX_FRAME_LENGTH {
+        return Err(invalid_data("Frame is too large"));
+    }
+
+    let mut payload = vec![0_u8; length as usize];
+    reader.read_exact(&mut payload)?;
+    decode_payload(&payload)
+}
+
+fn invalid_data<E>(error: E) -> IoError
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    IoError::new(ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use playing_cards::structs::{Card, Colour, Rank, Suit};
+
+    use super::*;
+    use crate::{
+        message::{ClientMessage, Message},
+        structs::{Capability, Encoding, PlayerName, Rejection},
+    };
+
+    // The generated bindings encode enum variants as a `u32` index and
+    // sequence lengths as a `u64`, both little-endian.
+    #[test]
+    fn test_message_matches_generated_encoding() {
+        let message = Message::Rejected(Rejection::NoSuchCard {
+            index: 7,
+            hand_size: 3,
+        });
+
+        let mut expected = vec![];
+        expected.extend_from_slice(&24_u32.to_le_bytes());
+        expected.extend_from_slice(&12_u32.to_le_bytes());
+        expected.extend_from_slice(&3_u32.to_le_bytes());
+        expected.extend_from_slice(&7_u64.to_le_bytes());
+        expected.extend_from_slice(&3_u64.to_le_bytes());
+
+        assert_eq!(encode_frame(&message).unwrap(), expected);
+
+        let decoded: Message = read_frame(&mut expected.as_slice()).unwrap();
+        assert!(matches!(
+            decoded,
+            Message::Rejected(Rejection::NoSuchCard {
+                index: 7,
+                hand_size: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_prompt_round_trip() {
+        let message = Message::RequestGuess {
+            legal_guesses: Some(BTreeSet::from([0, 2])),
+        };
+
+        let mut expected = vec![];
+        expected.extend_from_slice(&29_u32.to_le_bytes());
+        expected.extend_from_slice(&10_u32.to_le_bytes());
+        expected.push(1);
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+        expected.extend_from_slice(&0_u64.to_le_bytes());
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+
+        let mut written = vec![];
+        write_frame(&mut written, &message).unwrap();
+        assert_eq!(written, expected);
+
+        let decoded: Message = read_frame(&mut written.as_slice()).unwrap();
+        assert!(matches!(
+            decoded,
+            Message::RequestGuess { legal_guesses } if legal_guesses == Some(BTreeSet::from([0, 2]))
+        ));
+    }
+
+    #[test]
+    fn test_client_message_round_trip() {
+        let mut frame = vec![];
+        frame.extend_from_slice(&13_u32.to_le_bytes());
+        frame.extend_from_slice(&0_u32.to_le_bytes());
+        frame.extend_from_slice(&1_u64.to_le_bytes());
+        frame.push(b'3');
+
+        let decoded: ClientMessage = read_frame(&mut frame.as_slice()).unwrap();
+        assert_eq!(decoded, ClientMessage::Reply("3".to_owned()));
+        assert_eq!(encode_frame(&decoded).unwrap(), frame);
+    }
+
+    /// Decodes payloads written by the generated C++ bindings, see
+    /// `api/fixtures/generate.cpp`, and encodes them back.
+    #[test]
+    fn test_generated_fixtures() {
+        fn decode<T: Serialize + DeserializeOwned>(payload: &[u8]) -> T {
+            let decoded = decode_payload(payload).unwrap();
+            assert_eq!(&encode_frame(&decoded).unwrap()[4..], payload);
+            decoded
+        }
+
+        let rejected = decode(include_bytes!("../../api/fixtures/rejected.bin"));
+        assert!(matches!(
+            rejected,
+            Message::Rejected(Rejection::NoSuchCard {
+                index: 7,
+                hand_size: 3
+            })
+        ));
+
+        let request = decode(include_bytes!("../../api/fixtures/request_guess.bin"));
+        assert!(matches!(
+            request,
+            Message::RequestGuess { legal_guesses } if legal_guesses == Some(BTreeSet::from([0, 2]))
+        ));
+
+        let card = decode(include_bytes!("../../api/fixtures/card.bin"));
+        assert!(matches!(
+            card,
+            Message::Rejected(Rejection::CardNotInHand(card))
+                if card == Card::new(Suit::Diamonds, Rank::Ace)
+        ));
+
+        let joker = decode(include_bytes!("../../api/fixtures/joker.bin"));
+        assert!(matches!(
+            joker,
+            Message::Rejected(Rejection::CardNotInHand(Card::Joker(Colour::Black)))
+        ));
+
+        let chat = decode(include_bytes!("../../api/fixtures/chat.bin"));
+        assert!(matches!(
+            chat,
+            Message::Chat { sender, text } if sender.as_str() == "Åsa" && text == "Good luck!"
+        ));
+
+        let hello: ClientMessage = decode(include_bytes!("../../api/fixtures/hello.bin"));
+        assert_eq!(
+            hello,
+            ClientMessage::Hello {
+                version: 1,
+                encoding: Encoding::Binary,
+                capabilities: vec![Capability::Rejections, Capability::LegalActions],
+            }
+        );
+
+        let whisper: ClientMessage = decode(include_bytes!("../../api/fixtures/whisper.bin"));
+        assert_eq!(
+            whisper,
+            ClientMessage::Chat {
+                to: Some(PlayerName("Bob".to_owned())),
+                text: "Lead hearts".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_oversized_frame() {
+        let frame = (MAX_FRAME_LENGTH + 1).to_le_bytes();
+        let result: IoResult<Message> = read_frame(&mut frame.as_slice());
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
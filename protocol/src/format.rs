@@ -1,117 +1,322 @@
-use std::collections::HashSet;
+use std::{
+    collections::BTreeSet,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 use crate::{
+    locale::{fill, translate, Language, Phrase, UnknownLanguage},
     message::Message,
-    structs::{Player, PublicState, StatePerPlayer, Trick},
+    options::{
+        InvalidSetting, Profile, RenderOptions, Setting, Theme, UnknownProfile, UnknownTheme,
+    },
+    structs::{
+        Disconnect, Encoding, HandshakeRejection, LobbyPlayer, NameRejection, Play, Player,
+        PlayerName, PublicPlayer, PublicState, Rejection, RoundScore, ScoreSheet, SeriesScore,
+        StatePerPlayer, Trick,
+    },
 };
 use itertools::Itertools;
-use playing_cards::structs::Card;
+use playing_cards::structs::{Card, Colour, Suit};
 
 const SUIT_SYMBOLS: [&str; 4] = ["♥", "♣", "♦", "♠"];
 const CARD_SYMBOLS: [&str; 13] = [
     "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K", "A",
 ];
+const JOKER_SYMBOL: &str = "🃏";
 
 fn darken(text: &str) -> String {
     format!("\x1b[90m{text}\x1b[0m")
 }
 
-fn format_card(card: Card, darkened: bool, index: Option<usize>) -> String {
-    let suit_symbol = SUIT_SYMBOLS[card.suit];
-    let card_symbol = CARD_SYMBOLS[card.value];
+/// The SGR colour of `suit` in `theme`, or `None` for the terminal's own.
+const fn suit_colour(suit: Suit, theme: Theme) -> Option<&'static str> {
+    match (theme, suit) {
+        (Theme::Plain, _)
+        | (Theme::Classic | Theme::FourColour, Suit::Spades)
+        | (Theme::Classic, Suit::Clubs) => None,
+        (Theme::Classic, Suit::Diamonds) | (Theme::Classic | Theme::FourColour, Suit::Hearts) => {
+            Some("31")
+        }
+        (Theme::FourColour, Suit::Diamonds) => Some("34"),
+        (Theme::FourColour, Suit::Clubs) => Some("32"),
+    }
+}
+
+/// The SGR colour of `card` in `theme`. Red jokers are painted like hearts.
+const fn card_colour(card: Card, theme: Theme) -> Option<&'static str> {
+    match card {
+        Card::Suited { suit, .. } => suit_colour(suit, theme),
+        Card::Joker(Colour::Red) => suit_colour(Suit::Hearts, theme),
+        Card::Joker(Colour::Black) => None,
+    }
+}
+
+/// Colours `text` in `colour`. Darkened text in a colour is made faint
+/// rather than grey, so that the suit can still be told apart.
+fn paint(text: &str, colour: Option<&str>, darkened: bool) -> String {
+    match (colour, darkened) {
+        (None, false) => text.to_owned(),
+        (None, true) => darken(text),
+        (Some(colour), false) => format!("\x1b[{colour}m{text}\x1b[0m"),
+        (Some(colour), true) => format!("\x1b[2;{colour}m{text}\x1b[0m"),
+    }
+}
+
+fn format_suit(suit: Suit, options: RenderOptions) -> String {
+    const DARKENED: bool = false;
+
+    match options.profile {
+        Profile::Full => paint(
+            SUIT_SYMBOLS[suit.index()],
+            suit_colour(suit, options.theme),
+            DARKENED,
+        ),
+        Profile::Unicode => SUIT_SYMBOLS[suit.index()].to_owned(),
+        Profile::Ascii => suit.to_string(),
+        Profile::Accessible => options.text(Phrase::SuitName(suit)).to_owned(),
+    }
+}
+
+/// Such as "ten of hearts", or "red joker".
+fn format_card_name(card: Card, options: RenderOptions) -> String {
+    match card {
+        Card::Suited { suit, value } => fill(
+            options.text(Phrase::CardName),
+            &[
+                ("rank", options.text(Phrase::RankName(value))),
+                ("suit", options.text(Phrase::SuitName(suit))),
+            ],
+        ),
+        Card::Joker(colour) => fill(
+            options.text(Phrase::JokerName),
+            &[("colour", options.text(Phrase::ColourName(colour)))],
+        ),
+    }
+}
+
+fn format_card(card: Card, darkened: bool, index: Option<usize>, options: RenderOptions) -> String {
+    let card_string = match options.profile {
+        Profile::Full | Profile::Unicode => match card {
+            Card::Suited { suit, value } => {
+                let suit_symbol = SUIT_SYMBOLS[suit.index()];
+                let card_symbol = CARD_SYMBOLS[value.index()];
+                format!("{suit_symbol}{card_symbol}")
+            }
+            Card::Joker(_) => JOKER_SYMBOL.to_owned(),
+        },
+        Profile::Ascii => card.to_string(),
+        Profile::Accessible => {
+            let name = format_card_name(card, options);
+            let Some(index) = index else {
+                return name;
+            };
+
+            let playable = if darkened {
+                Phrase::NotPlayable
+            } else {
+                Phrase::Playable
+            };
+            return format!("{index}: {name}, {}", options.text(playable));
+        }
+    };
 
     let index_string = index.map_or_else(String::new, |index| format!("{index}|"));
 
-    let text = format!("{index_string}{suit_symbol}{card_symbol}");
+    let text = format!("{index_string}{card_string}");
 
-    if darkened {
-        darken(&text)
-    } else {
-        text
+    match options.profile {
+        Profile::Full => paint(&text, card_colour(card, options.theme), darkened),
+        _ if !darkened => text,
+        Profile::Unicode | Profile::Ascii | Profile::Accessible => format!("[{text}]"),
+    }
+}
+
+const fn card_separator(options: RenderOptions) -> &'static str {
+    match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => " ",
+        Profile::Accessible => "; ",
+    }
+}
+
+const fn play_separator(options: RenderOptions) -> &'static str {
+    match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => ", ",
+        Profile::Accessible => "; ",
     }
 }
 
-fn format_trick(Trick(cards): &Trick) -> Option<String> {
+/// Formats the cards on the table with `phrase`, which is given the plays as
+/// `{trick}` and the player who is winning, or has won, as `{player}`.
+fn format_trick_with(trick: &Trick, phrase: Phrase, options: RenderOptions) -> Option<String> {
     const DARKENED: bool = false;
     const INDEX: Option<usize> = None;
 
-    (!cards.is_empty()).then(|| {
-        cards
-            .iter()
-            .map(|c| format_card(*c, DARKENED, INDEX))
-            .join(" ")
-    })
+    let winner = trick.winner()?;
+    let plays = trick
+        .plays()
+        .iter()
+        .map(|Play { player, card }| {
+            fill(
+                options.text(Phrase::TrickPlay),
+                &[
+                    ("player", player.as_str()),
+                    ("card", &format_card(*card, DARKENED, INDEX, options)),
+                ],
+            )
+        })
+        .join(play_separator(options));
+
+    Some(fill(
+        options.text(phrase),
+        &[("trick", &plays), ("player", winner.player.as_str())],
+    ))
+}
+
+fn format_trick(trick: &Trick, options: RenderOptions) -> Option<String> {
+    format_trick_with(trick, Phrase::Winning, options)
+}
+
+fn format_last_trick(trick: Option<&Trick>, options: RenderOptions) -> String {
+    trick
+        .and_then(|trick| format_trick_with(trick, Phrase::TakenBy, options))
+        .map_or_else(
+            || options.text(Phrase::NoTricks).to_owned(),
+            |text| fill(options.text(Phrase::LastTrick), &[("trick", &text)]),
+        )
+}
+
+fn format_trick_history(tricks: &[Trick], options: RenderOptions) -> String {
+    if tricks.is_empty() {
+        return options.text(Phrase::NoTricks).to_owned();
+    }
+
+    tricks
+        .iter()
+        .filter_map(|trick| format_trick_with(trick, Phrase::TakenBy, options))
+        .enumerate()
+        .map(|(index, text)| format!("{}. {text}", index + 1))
+        .join("\n")
 }
 
-fn format_hand(hand: &[Card], valid_cards: &Option<HashSet<usize>>, with_indices: bool) -> String {
+fn format_hand(
+    hand: &[Card],
+    valid_cards: Option<&BTreeSet<usize>>,
+    with_indices: bool,
+    options: RenderOptions,
+) -> String {
     hand.iter()
         .enumerate()
         .map(|(index, card)| {
-            let darkened = valid_cards
-                .as_ref()
-                .map_or(false, |cards| !cards.contains(&index));
+            let darkened = valid_cards.is_some_and(|cards| !cards.contains(&index));
 
             let index = with_indices.then_some(index);
-            format_card(*card, darkened, index)
+            format_card(*card, darkened, index, options)
         })
-        .join(" ")
+        .join(card_separator(options))
 }
 
 fn format_guess(state: &PublicState) -> String {
     state.guess.map_or_else(|| "?".into(), |g| g.to_string())
 }
 
-fn format_guesses(state: &StatePerPlayer) -> String {
+fn format_guesses(state: &StatePerPlayer, options: RenderOptions) -> String {
     let guesses = state
         .iter()
         .map(|(name, state)| format!("{}: {}", name.as_str(), format_guess(state)))
         .join(", ");
 
-    "Guesses: ".to_owned() + &guesses
+    fill(
+        options.text(Phrase::Guesses),
+        &[("guesses", guesses.as_str())],
+    )
 }
 
-const UPSIDE_DOWN_FACE: char = '\u{1F643}';
-const SLIGHTLY_SMILING_FACE: char = '\u{1F642}';
+const UPSIDE_DOWN_FACE: &str = "\u{1F643}";
+const SLIGHTLY_SMILING_FACE: &str = "\u{1F642}";
 
-fn format_scoreboard(public: &StatePerPlayer) -> String {
-    fn format_state(public: &PublicState) -> String {
+fn format_scoreboard(public: &StatePerPlayer, options: RenderOptions) -> String {
+    let format_state = |name: &PlayerName, public: &PublicState| {
         let PublicState { guess, wins, score } = *public;
         let did_plump = guess.filter(|guess| wins == *guess).is_none();
 
-        let face = if did_plump {
-            UPSIDE_DOWN_FACE
-        } else {
-            SLIGHTLY_SMILING_FACE
+        let face = match (options.profile, did_plump) {
+            (Profile::Full | Profile::Unicode, true) => UPSIDE_DOWN_FACE,
+            (Profile::Full | Profile::Unicode, false) => SLIGHTLY_SMILING_FACE,
+            (Profile::Ascii, true) => ":(",
+            (Profile::Ascii, false) => ":)",
+            (Profile::Accessible, _) => {
+                return fill(
+                    options.text(Phrase::VerboseScore),
+                    &[
+                        ("player", name.as_str()),
+                        ("wins", &wins.to_string()),
+                        ("guess", &format_guess(public)),
+                        ("score", &score.to_string()),
+                    ],
+                );
+            }
         };
 
-        let guess_text = format_guess(public);
-        format!("{wins}/{guess_text} {face} (total: {score})")
-    }
+        let state = fill(
+            options.text(Phrase::Score),
+            &[
+                ("wins", &wins.to_string()),
+                ("guess", &format_guess(public)),
+                ("face", face),
+                ("score", &score.to_string()),
+            ],
+        );
+        format!("{}: {state}", name.as_str())
+    };
+
+    let separator = match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => ", ",
+        Profile::Accessible => " ",
+    };
 
     public
         .keys()
         .sorted()
-        .map(|name| {
-            let state = format_state(public.get(name).unwrap());
-            format!("{}: {state}", name.as_str())
-        })
-        .join(", ")
+        .map(|name| format_state(name, public.get(name).unwrap()))
+        .join(separator)
 }
 
-fn format_player_prompt(trick: &Trick) -> String {
-    let trick_string = format_trick(trick);
+fn format_player_prompt(trick: &Trick, options: RenderOptions) -> String {
+    let trick_string = format_trick(trick, options);
     trick_string.map_or_else(
-        || "You go first!".to_owned(),
-        |text| "Trick: ".to_owned() + &text,
+        || options.text(Phrase::YouGoFirst).to_owned(),
+        |text| fill(options.text(Phrase::Trick), &[("trick", &text)]),
     )
 }
 
-fn format_turn(player: &Player) -> String {
-    format!("{}'s turn", player.name.as_str())
+fn format_trick_message(trick: &Trick, options: RenderOptions) -> String {
+    let trick_string = format_trick(trick, options).unwrap_or_default();
+
+    match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => trick_string,
+        Profile::Accessible => fill(
+            options.text(Phrase::VerboseTrick),
+            &[("trick", &trick_string)],
+        ),
+    }
 }
 
-fn format_winner(player: &Player) -> String {
-    format!("{} won!", player.name.as_str())
+fn format_turn(player: &PublicPlayer, options: RenderOptions) -> String {
+    let phrase = match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => Phrase::Turn,
+        Profile::Accessible => Phrase::VerboseTurn,
+    };
+
+    fill(options.text(phrase), &[("player", player.name.as_str())])
+}
+
+fn format_winner(player: &PublicPlayer, options: RenderOptions) -> String {
+    let phrase = match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Ascii => Phrase::Winner,
+        Profile::Accessible => Phrase::VerboseWinner,
+    };
+
+    fill(options.text(phrase), &[("player", player.name.as_str())])
 }
 
 fn format_request_guess_context(
@@ -119,94 +324,758 @@ fn format_request_guess_context(
     hand: &[Card],
     guesses: &[usize],
     players: usize,
+    options: RenderOptions,
 ) -> String {
-    const VALID_CARDS: Option<HashSet<usize>> = None;
+    const VALID_CARDS: Option<&BTreeSet<usize>> = None;
     const WITH_INDICES: bool = false;
 
-    let hand_string = format_hand(hand, &VALID_CARDS, WITH_INDICES);
+    let hand_string = format_hand(hand, VALID_CARDS, WITH_INDICES, options);
     let guesses_string = guesses
         .iter()
         .map(std::string::ToString::to_string)
         .join(" ");
 
-    format!(
-        "{}: Hand: {hand_string}, Previous Guesses: {guesses_string}, Players: {players}",
-        player.name.as_str()
+    if options.profile == Profile::Accessible {
+        let guesses_string = if guesses.is_empty() {
+            options.text(Phrase::NoGuesses).to_owned()
+        } else {
+            guesses.iter().join(", ")
+        };
+
+        return fill(
+            options.text(Phrase::VerboseGuessContext),
+            &[
+                ("hand", &hand_string),
+                ("guesses", &guesses_string),
+                ("players", &players.to_string()),
+            ],
+        );
+    }
+
+    fill(
+        options.text(Phrase::RequestGuessContext),
+        &[
+            ("player", player.name.as_str()),
+            ("hand", &hand_string),
+            ("guesses", &guesses_string),
+            ("players", &players.to_string()),
+        ],
     )
 }
 
-fn format_request_guess() -> String {
-    "Please make a guess: ".to_owned()
+fn format_request_guess(legal_guesses: Option<&BTreeSet<usize>>, options: RenderOptions) -> String {
+    let choices = legal_guesses.into_iter().flatten().join(", ");
+    fill(options.text(Phrase::RequestGuess), &[("choices", &choices)])
 }
 
 fn format_play_request_context(
     player: &Player,
     hand: &[Card],
     trick: &Trick,
-    valid_cards: &Option<HashSet<usize>>,
+    valid_cards: Option<&BTreeSet<usize>>,
+    options: RenderOptions,
 ) -> String {
     const WITH_INDICES: bool = true;
-    let hand_string = format_hand(hand, valid_cards, WITH_INDICES);
+    let hand_string = format_hand(hand, valid_cards, WITH_INDICES, options);
+
+    let state = format_player_prompt(trick, options);
+
+    if options.profile == Profile::Accessible {
+        return fill(
+            options.text(Phrase::VerbosePlayContext),
+            &[("hand", &hand_string), ("state", &state)],
+        );
+    }
 
-    let state = format_player_prompt(trick);
-    format!("{}: Hand: {hand_string}, {state}", player.name.as_str())
+    fill(
+        options.text(Phrase::PlayRequestContext),
+        &[
+            ("player", player.name.as_str()),
+            ("hand", &hand_string),
+            ("state", &state),
+        ],
+    )
 }
 
-fn format_play_request(player: &Player) -> String {
-    format!(
-        "{}: Select card to play (leftmost is 0): ",
-        player.name.as_str()
+fn format_play_request(player: &Player, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::PlayRequest),
+        &[("player", player.name.as_str())],
     )
 }
 
-fn format_winners(players: &[Player], winners: &[usize]) -> String {
+fn format_winners(players: &[PublicPlayer], winners: &[usize], options: RenderOptions) -> String {
     let winners_text = winners.iter().map(|i| players[*i].name.as_str()).join(", ");
-    format!("The winner(s) is/are {winners_text}!")
+    fill(options.text(Phrase::Winners), &[("winners", &winners_text)])
 }
 
-fn format_request_player_name() -> String {
-    "Please input player name: ".to_owned()
+fn format_round_start(
+    round: usize,
+    rounds: usize,
+    hand_size: usize,
+    dealer: &PublicPlayer,
+    options: RenderOptions,
+) -> String {
+    fill(
+        options.text(Phrase::RoundStart),
+        &[
+            ("round", &round.to_string()),
+            ("rounds", &rounds.to_string()),
+            ("hand_size", &hand_size.to_string()),
+            ("dealer", dealer.name.as_str()),
+        ],
+    )
+}
+
+fn format_round_end(
+    round: usize,
+    made: &[PublicPlayer],
+    missed: &[PublicPlayer],
+    options: RenderOptions,
+) -> String {
+    let names = |players: &[PublicPlayer]| {
+        if players.is_empty() {
+            return options.text(Phrase::Nobody).to_owned();
+        }
+        players.iter().map(|player| player.name.as_str()).join(", ")
+    };
+
+    fill(
+        options.text(Phrase::RoundEnd),
+        &[
+            ("round", &round.to_string()),
+            ("made", &names(made)),
+            ("missed", &names(missed)),
+        ],
+    )
 }
 
-fn format_game_over() -> String {
-    "Game over!".to_owned()
+/// Right-aligns every column of `rows`.
+fn format_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.first().map_or(0, Vec::len);
+    let widths = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect_vec();
+
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:>width$}"))
+                .join(" | ")
+        })
+        .join("\n")
 }
 
-impl ToString for Message {
-    fn to_string(&self) -> String {
-        match self {
+fn format_score_sheet(sheet: &ScoreSheet, options: RenderOptions) -> String {
+    let totals = sheet.totals();
+
+    if options.profile == Profile::Accessible {
+        let rounds = sheet.rounds.iter().enumerate().map(|(index, round)| {
+            let scores = sheet
+                .players
+                .iter()
+                .zip(&round.scores)
+                .map(|(name, score)| {
+                    fill(
+                        options.text(Phrase::VerbosePlayerRound),
+                        &[
+                            ("player", name.as_str()),
+                            ("guess", &score.guess.to_string()),
+                            ("wins", &score.wins.to_string()),
+                            ("points", &score.points.to_string()),
+                        ],
+                    )
+                })
+                .join("; ");
+
+            fill(
+                options.text(Phrase::VerboseRoundScores),
+                &[
+                    ("round", &(index + 1).to_string()),
+                    ("cards", &round.hand_size.to_string()),
+                    ("scores", &scores),
+                ],
+            )
+        });
+
+        let totals = sheet
+            .players
+            .iter()
+            .zip(&totals)
+            .map(|(name, total)| format!("{} {total}", name.as_str()))
+            .join(", ");
+        let totals = fill(options.text(Phrase::VerboseTotals), &[("scores", &totals)]);
+
+        return rounds.chain([totals]).join("\n");
+    }
+
+    let header = [Phrase::Round, Phrase::Cards]
+        .into_iter()
+        .map(|phrase| options.text(phrase).to_owned())
+        .chain(sheet.players.iter().map(|name| name.as_str().to_owned()));
+
+    let rounds = sheet.rounds.iter().enumerate().map(|(index, round)| {
+        [(index + 1).to_string(), round.hand_size.to_string()]
+            .into_iter()
+            .chain(round.scores.iter().map(|score| {
+                let RoundScore {
+                    guess,
+                    wins,
+                    points,
+                } = score;
+                format!("{wins}/{guess} {points}")
+            }))
+            .collect_vec()
+    });
+
+    let total = [options.text(Phrase::Total).to_owned(), String::new()]
+        .into_iter()
+        .chain(totals.iter().map(ToString::to_string));
+
+    let rows = std::iter::once(header.collect_vec())
+        .chain(rounds)
+        .chain([total.collect_vec()])
+        .collect_vec();
+
+    format_table(&rows)
+}
+
+fn format_request_player_name(options: RenderOptions) -> String {
+    options.text(Phrase::RequestPlayerName).to_owned()
+}
+
+fn format_game_over(options: RenderOptions) -> String {
+    options.text(Phrase::GameOver).to_owned()
+}
+
+fn format_not_a_number(text: &str, options: RenderOptions) -> String {
+    fill(options.text(Phrase::NotANumber), &[("text", text)])
+}
+
+fn format_guess_too_high(guess: usize, hand_size: usize, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::GuessTooHigh),
+        &[
+            ("guess", &guess.to_string()),
+            ("hand_size", &hand_size.to_string()),
+        ],
+    )
+}
+
+fn format_forbidden_guess(guess: usize, hand_size: usize, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::ForbiddenGuess),
+        &[
+            ("guess", &guess.to_string()),
+            ("hand_size", &hand_size.to_string()),
+        ],
+    )
+}
+
+fn format_no_such_card(index: usize, hand_size: usize, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::NoSuchCard),
+        &[
+            ("index", &index.to_string()),
+            ("hand_size", &hand_size.to_string()),
+        ],
+    )
+}
+
+fn format_must_follow_suit(suit: Suit, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::MustFollowSuit),
+        &[("suit", &format_suit(suit, options))],
+    )
+}
+
+fn format_not_a_card(text: &str, options: RenderOptions) -> String {
+    fill(options.text(Phrase::NotACard), &[("text", text)])
+}
+
+fn format_card_not_in_hand(card: Card, options: RenderOptions) -> String {
+    const DARKENED: bool = false;
+    const INDEX: Option<usize> = None;
+
+    fill(
+        options.text(Phrase::CardNotInHand),
+        &[("card", &format_card(card, DARKENED, INDEX, options))],
+    )
+}
+
+fn format_rejection(rejection: &Rejection, options: RenderOptions) -> String {
+    match rejection {
+        Rejection::NotANumber(text) => format_not_a_number(text, options),
+        Rejection::GuessTooHigh { guess, hand_size } => {
+            format_guess_too_high(*guess, *hand_size, options)
+        }
+        Rejection::ForbiddenGuess { guess, hand_size } => {
+            format_forbidden_guess(*guess, *hand_size, options)
+        }
+        Rejection::NoSuchCard { index, hand_size } => {
+            format_no_such_card(*index, *hand_size, options)
+        }
+        Rejection::MustFollowSuit { suit } => format_must_follow_suit(*suit, options),
+        Rejection::NotACard(text) => format_not_a_card(text, options),
+        Rejection::CardNotInHand(card) => format_card_not_in_hand(*card, options),
+        Rejection::ChatTooLong { length, max } => fill(
+            options.text(Phrase::ChatTooLong),
+            &[("length", &length.to_string()), ("max", &max.to_string())],
+        ),
+        Rejection::UnknownPlayer(player) => fill(
+            options.text(Phrase::UnknownPlayer),
+            &[("player", player.as_str())],
+        ),
+        Rejection::NotHost(host) => {
+            fill(options.text(Phrase::NotHost), &[("player", host.as_str())])
+        }
+        Rejection::NotReady(players) => fill(
+            options.text(Phrase::WaitingForReady),
+            &[(
+                "players",
+                &players.iter().map(PlayerName::as_str).join(", "),
+            )],
+        ),
+        Rejection::TooFewPlayers { min } => fill(
+            options.text(Phrase::TooFewPlayers),
+            &[("min", &min.to_string())],
+        ),
+    }
+}
+
+fn format_lobby(players: &[LobbyPlayer], seats: usize, options: RenderOptions) -> String {
+    let names = players
+        .iter()
+        .enumerate()
+        .map(|(index, player)| {
+            let ready = options.text(if player.ready {
+                Phrase::Ready
+            } else {
+                Phrase::NotReady
+            });
+            if index == 0 {
+                format!(
+                    "{} ({}, {ready})",
+                    player.name.as_str(),
+                    options.text(Phrase::Host)
+                )
+            } else {
+                format!("{} ({ready})", player.name.as_str())
+            }
+        })
+        .join(", ");
+
+    fill(
+        options.text(Phrase::Lobby),
+        &[
+            ("count", &players.len().to_string()),
+            ("seats", &seats.to_string()),
+            ("players", &names),
+        ],
+    )
+}
+
+fn format_series(games: u32, scores: &[SeriesScore], options: RenderOptions) -> String {
+    let scores = scores
+        .iter()
+        .map(|score| {
+            fill(
+                options.text(Phrase::SeriesScore),
+                &[
+                    ("player", score.name.as_str()),
+                    ("won", &score.won.to_string()),
+                    ("played", &score.played.to_string()),
+                ],
+            )
+        })
+        .join(", ");
+
+    fill(
+        options.text(Phrase::Series),
+        &[("games", &games.to_string()), ("scores", &scores)],
+    )
+}
+
+fn format_rejoin_token(token: &str, options: RenderOptions) -> String {
+    fill(options.text(Phrase::RejoinToken), &[("token", token)])
+}
+
+fn format_chat(
+    sender: &PlayerName,
+    to: Option<&PlayerName>,
+    text: &str,
+    options: RenderOptions,
+) -> String {
+    to.map_or_else(
+        || {
+            fill(
+                options.text(Phrase::Chat),
+                &[("player", sender.as_str()), ("text", text)],
+            )
+        },
+        |to| {
+            fill(
+                options.text(Phrase::Whisper),
+                &[
+                    ("player", sender.as_str()),
+                    ("to", to.as_str()),
+                    ("text", text),
+                ],
+            )
+        },
+    )
+}
+
+const fn format_encoding(encoding: Encoding) -> &'static str {
+    match encoding {
+        Encoding::Text => "text",
+        Encoding::Json => "JSON",
+        Encoding::Binary => "binary",
+    }
+}
+
+fn format_handshake_accepted(version: u32, encoding: Encoding, options: RenderOptions) -> String {
+    fill(
+        options.text(Phrase::HandshakeAccepted),
+        &[
+            ("version", &version.to_string()),
+            ("encoding", format_encoding(encoding)),
+        ],
+    )
+}
+
+fn format_handshake_rejected(rejection: &HandshakeRejection, options: RenderOptions) -> String {
+    match rejection {
+        HandshakeRejection::UnsupportedVersion {
+            requested,
+            supported,
+        } => fill(
+            options.text(Phrase::UnsupportedVersion),
+            &[
+                ("requested", &requested.to_string()),
+                ("supported", &supported.to_string()),
+            ],
+        ),
+        HandshakeRejection::Malformed(error) => fill(
+            options.text(Phrase::MalformedHandshake),
+            &[("error", error)],
+        ),
+    }
+}
+
+fn format_name_rejected(rejection: &NameRejection, options: RenderOptions) -> String {
+    match rejection {
+        NameRejection::Empty => options.text(Phrase::NameEmpty).to_owned(),
+        NameRejection::TooLong { max } => fill(
+            options.text(Phrase::NameTooLong),
+            &[("max", &max.to_string())],
+        ),
+        // Control characters are written as code points, as they may well
+        // move the cursor.
+        NameRejection::InvalidCharacter(character) => {
+            let character = if character.is_control() {
+                format!("U+{:04X}", u32::from(*character))
+            } else {
+                character.to_string()
+            };
+            fill(
+                options.text(Phrase::NameCharacter),
+                &[("character", &character)],
+            )
+        }
+        NameRejection::Taken(player) => fill(
+            options.text(Phrase::NameTaken),
+            &[("player", player.as_str())],
+        ),
+    }
+}
+
+fn format_disconnect(reason: &Disconnect, options: RenderOptions) -> String {
+    match reason {
+        Disconnect::LineTooLong { max } => fill(
+            options.text(Phrase::LineTooLong),
+            &[("max", &max.to_string())],
+        ),
+        Disconnect::InvalidUtf8 => options.text(Phrase::InvalidUtf8).to_owned(),
+        Disconnect::Malformed(error) => {
+            fill(options.text(Phrase::MalformedInput), &[("error", error)])
+        }
+        Disconnect::Idle => options.text(Phrase::Idle).to_owned(),
+        Disconnect::NoSeat => options.text(Phrase::NoSeat).to_owned(),
+    }
+}
+
+/// Confirms a changed setting, in the new language if that is what changed.
+pub fn format_setting_changed(setting: Setting, options: RenderOptions) -> String {
+    let text = match setting {
+        Setting::Language(_) => fill(
+            options.text(Phrase::LanguageChanged),
+            &[("language", options.text(Phrase::LanguageName))],
+        ),
+        Setting::Profile(profile) => fill(
+            options.text(Phrase::ProfileChanged),
+            &[("profile", profile.code())],
+        ),
+        Setting::Theme(theme) => fill(
+            options.text(Phrase::ThemeChanged),
+            &[("theme", theme.code())],
+        ),
+    };
+
+    for_profile(text, options)
+}
+
+pub fn format_invalid_setting(error: &InvalidSetting, options: RenderOptions) -> String {
+    let text = match error {
+        InvalidSetting::Language(UnknownLanguage(text)) => {
+            let languages = Language::ALL
+                .iter()
+                .map(|language| language.code())
+                .join(", ");
+            fill(
+                options.text(Phrase::UnknownLanguage),
+                &[("text", text), ("languages", &languages)],
+            )
+        }
+        InvalidSetting::Profile(UnknownProfile(text)) => {
+            let profiles = Profile::ALL.iter().map(|profile| profile.code()).join(", ");
+            fill(
+                options.text(Phrase::UnknownProfile),
+                &[("text", text), ("profiles", &profiles)],
+            )
+        }
+        InvalidSetting::Theme(UnknownTheme(text)) => {
+            let themes = Theme::ALL.iter().map(|theme| theme.code()).join(", ");
+            fill(
+                options.text(Phrase::UnknownTheme),
+                &[("text", text), ("themes", &themes)],
+            )
+        }
+    };
+
+    for_profile(text, options)
+}
+
+/// The reply to a text client asking to see its hand before it has one.
+pub fn format_no_hand(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::NoHand).to_owned(), options)
+}
+
+/// The reply to `/score` before the first round is over.
+pub fn format_no_score(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::NoScore).to_owned(), options)
+}
+
+/// The reply to `/trick` between tricks.
+pub fn format_empty_table(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::EmptyTable).to_owned(), options)
+}
+
+pub fn format_help(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::Help).to_owned(), options)
+}
+
+pub fn format_rules(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::Rules).to_owned(), options)
+}
+
+pub fn format_goodbye(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::Goodbye).to_owned(), options)
+}
+
+/// The reply to `/who`, with the player the server is waiting for if it is
+/// waiting for anyone.
+pub fn format_who(
+    players: &[PlayerName],
+    waiting_for: Option<&PlayerName>,
+    options: RenderOptions,
+) -> String {
+    let names = if players.is_empty() {
+        options.text(Phrase::Nobody).to_owned()
+    } else {
+        players.iter().map(PlayerName::as_str).join(", ")
+    };
+
+    let text = waiting_for.map_or_else(
+        || fill(options.text(Phrase::Who), &[("players", &names)]),
+        |player| {
+            fill(
+                options.text(Phrase::WhoWaiting),
+                &[("players", &names), ("player", player.as_str())],
+            )
+        },
+    );
+
+    for_profile(text, options)
+}
+
+/// The reply to a text client that answers when no one asked it to.
+pub fn format_not_your_turn(waiting_for: &PlayerName, options: RenderOptions) -> String {
+    let text = fill(
+        options.text(Phrase::NotYourTurn),
+        &[("player", waiting_for.as_str())],
+    );
+    for_profile(text, options)
+}
+
+/// Explains the waiting room to a text client that answers before the game.
+pub fn format_lobby_hint(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::LobbyHint).to_owned(), options)
+}
+
+/// Asks the players of a game that is over whether to play another.
+pub fn format_rematch_hint(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::RematchHint).to_owned(), options)
+}
+
+/// The reply to `/ready` and `/start` while a game is played.
+pub fn format_not_in_lobby(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::NotInLobby).to_owned(), options)
+}
+
+/// The reply to `/mute` and `/unmute`.
+pub fn format_muted(player: &PlayerName, muted: bool, options: RenderOptions) -> String {
+    let phrase = if muted {
+        Phrase::Muted
+    } else {
+        Phrase::Unmuted
+    };
+    for_profile(
+        fill(options.text(phrase), &[("player", player.as_str())]),
+        options,
+    )
+}
+
+/// The reply to chat commands before the game has started.
+pub fn format_no_chat_yet(options: RenderOptions) -> String {
+    for_profile(options.text(Phrase::NoChatYet).to_owned(), options)
+}
+
+pub fn format_unknown_command(command: &str, options: RenderOptions) -> String {
+    let text = fill(
+        options.text(Phrase::UnknownCommand),
+        &[("command", command)],
+    );
+    for_profile(text, options)
+}
+
+/// Replaces the letters of the Swedish catalog and drops anything else
+/// outside of ASCII, such as emoji in player names.
+fn to_ascii(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| match c {
+            'å' | 'ä' => Some('a'),
+            'Å' | 'Ä' => Some('A'),
+            'ö' => Some('o'),
+            'Ö' => Some('O'),
+            c if c.is_ascii() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+fn for_profile(text: String, options: RenderOptions) -> String {
+    match options.profile {
+        Profile::Full | Profile::Unicode | Profile::Accessible => text,
+        Profile::Ascii => to_ascii(&text),
+    }
+}
+
+impl RenderOptions {
+    fn text(self, phrase: Phrase) -> &'static str {
+        translate(self.language, phrase)
+    }
+}
+
+impl Message {
+    pub fn render(&self, options: RenderOptions) -> String {
+        let text = match self {
             Message::RequestGuessContext {
                 player,
                 hand,
                 guesses,
                 players,
-            } => format_request_guess_context(player, hand, guesses, *players),
+                legal_guesses: _,
+            } => format_request_guess_context(player, hand, guesses, *players, options),
 
-            Message::Guesses { state } => format_guesses(state),
+            Message::Guesses { state } => format_guesses(state, options),
 
-            Message::Turn { whose: player } => format_turn(player),
+            Message::Turn { whose: player } => format_turn(player, options),
 
             Message::PlayRequestContext {
                 player,
                 hand,
                 trick,
                 valid_cards,
-            } => format_play_request_context(player, hand, trick, valid_cards),
+            } => format_play_request_context(player, hand, trick, valid_cards.as_ref(), options),
 
-            Message::Trick(trick) => format_trick(trick).unwrap_or_default(),
+            Message::Trick(trick) => format_trick_message(trick, options),
 
-            Message::Scoreboard { state } => format_scoreboard(state),
+            Message::Scoreboard { state } => format_scoreboard(state, options),
 
-            Message::Winner(player) => format_winner(player),
+            Message::Winner(player) => format_winner(player, options),
 
             Message::Winners {
                 players,
                 winner_indices,
-            } => format_winners(players, winner_indices),
-            Message::RequestPlayerName => format_request_player_name(),
-            Message::PlayRequest(player) => format_play_request(player),
-            Message::RequestGuess => format_request_guess(),
-            Message::GameOver => format_game_over(),
-        }
+            } => format_winners(players, winner_indices, options),
+            Message::RequestPlayerName => format_request_player_name(options),
+            Message::PlayRequest {
+                player,
+                valid_cards: _,
+            } => format_play_request(player, options),
+            Message::RequestGuess { legal_guesses } => {
+                format_request_guess(legal_guesses.as_ref(), options)
+            }
+            Message::GameOver => format_game_over(options),
+            Message::Rejected(rejection) => format_rejection(rejection, options),
+            Message::HandshakeAccepted {
+                version,
+                encoding,
+                capabilities: _,
+            } => format_handshake_accepted(*version, *encoding, options),
+            Message::HandshakeRejected(rejection) => format_handshake_rejected(rejection, options),
+            Message::ScoreSheet(sheet) => format_score_sheet(sheet, options),
+            Message::RoundStart {
+                round,
+                rounds,
+                hand_size,
+                dealer,
+            } => format_round_start(*round, *rounds, *hand_size, dealer, options),
+            Message::RoundEnd {
+                round,
+                made,
+                missed,
+            } => format_round_end(*round, made, missed, options),
+            Message::LastTrick(trick) => format_last_trick(trick.as_ref(), options),
+            Message::TrickHistory(tricks) => format_trick_history(tricks, options),
+            Message::Chat { sender, text } => format_chat(sender, None, text, options),
+            Message::Whisper { sender, to, text } => format_chat(sender, Some(to), text, options),
+            Message::Disconnected(reason) => format_disconnect(reason, options),
+            Message::NameRejected(rejection) => format_name_rejected(rejection, options),
+            Message::Lobby { players, seats } => format_lobby(players, *seats, options),
+            Message::Series { games, scores } => format_series(*games, scores, options),
+            Message::RejoinToken(token) => format_rejoin_token(token, options),
+        };
+
+        for_profile(text, options)
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.render(RenderOptions::default()))
+    }
+}
+
+impl Display for Disconnect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&format_disconnect(self, RenderOptions::default()))
     }
 }
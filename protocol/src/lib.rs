@@ -1,3 +1,6 @@
+pub mod binary;
 pub mod format;
+pub mod locale;
 pub mod message;
+pub mod options;
 pub mod structs;
@@ -0,0 +1,594 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use playing_cards::structs::{Colour, Rank, Suit};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Swedish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Swedish];
+
+    pub const fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Swedish => "sv",
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct UnknownLanguage(pub String);
+
+impl Display for UnknownLanguage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let codes = Language::ALL
+            .iter()
+            .map(|language| language.code())
+            .join(", ");
+        write!(f, "Unknown language '{}', expected one of {codes}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownLanguage {}
+
+impl FromStr for Language {
+    type Err = UnknownLanguage;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim().to_lowercase().as_str() {
+            "en" | "english" => Ok(Language::English),
+            "sv" | "swedish" | "svenska" => Ok(Language::Swedish),
+            _ => Err(UnknownLanguage(text.to_owned())),
+        }
+    }
+}
+
+/// Every player-facing text. Arguments are written as `{name}` and filled in
+/// with [`fill`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Phrase {
+    LanguageName,
+    LanguageChanged,
+    UnknownLanguage,
+    ProfileChanged,
+    UnknownProfile,
+    ThemeChanged,
+    UnknownTheme,
+    SuitName(Suit),
+    RankName(Rank),
+    ColourName(Colour),
+    CardName,
+    JokerName,
+    Playable,
+    NotPlayable,
+    NoGuesses,
+    NoHand,
+    NoScore,
+    EmptyTable,
+    Who,
+    WhoWaiting,
+    NotYourTurn,
+    UnknownCommand,
+    Goodbye,
+    Help,
+    Rules,
+    Round,
+    Cards,
+    Total,
+    TrickPlay,
+    Winning,
+    TakenBy,
+    LastTrick,
+    NoTricks,
+    Nobody,
+    RoundStart,
+    RoundEnd,
+    VerboseGuessContext,
+    VerbosePlayContext,
+    VerboseTrick,
+    VerboseTurn,
+    VerboseWinner,
+    VerboseScore,
+    VerboseRoundScores,
+    VerbosePlayerRound,
+    VerboseTotals,
+    RequestGuessContext,
+    Guesses,
+    Score,
+    YouGoFirst,
+    Trick,
+    Turn,
+    PlayRequestContext,
+    PlayRequest,
+    RequestGuess,
+    Winner,
+    Winners,
+    RequestPlayerName,
+    GameOver,
+    NotANumber,
+    GuessTooHigh,
+    ForbiddenGuess,
+    NoSuchCard,
+    MustFollowSuit,
+    NotACard,
+    CardNotInHand,
+    ChatTooLong,
+    UnknownPlayer,
+    Chat,
+    Whisper,
+    Muted,
+    Unmuted,
+    NoChatYet,
+    LineTooLong,
+    InvalidUtf8,
+    MalformedInput,
+    Idle,
+    NameEmpty,
+    NameTooLong,
+    NameCharacter,
+    NameTaken,
+    NoSeat,
+    Lobby,
+    Host,
+    Ready,
+    NotReady,
+    LobbyHint,
+    NotInLobby,
+    NotHost,
+    WaitingForReady,
+    TooFewPlayers,
+    RematchHint,
+    Series,
+    SeriesScore,
+    RejoinToken,
+    HandshakeAccepted,
+    UnsupportedVersion,
+    MalformedHandshake,
+}
+
+const fn english(phrase: Phrase) -> &'static str {
+    match phrase {
+        Phrase::LanguageName => "English",
+        Phrase::LanguageChanged => "Language: {language}",
+        Phrase::UnknownLanguage => "Unknown language '{text}', choose one of: {languages}",
+        Phrase::ProfileChanged => "Display: {profile}",
+        Phrase::UnknownProfile => "Unknown display '{text}', choose one of: {profiles}",
+        Phrase::ThemeChanged => "Colours: {theme}",
+        Phrase::UnknownTheme => "Unknown colours '{text}', choose one of: {themes}",
+        Phrase::SuitName(suit) => match suit {
+            Suit::Hearts => "hearts",
+            Suit::Clubs => "clubs",
+            Suit::Diamonds => "diamonds",
+            Suit::Spades => "spades",
+        },
+        Phrase::RankName(rank) => match rank {
+            Rank::Two => "two",
+            Rank::Three => "three",
+            Rank::Four => "four",
+            Rank::Five => "five",
+            Rank::Six => "six",
+            Rank::Seven => "seven",
+            Rank::Eight => "eight",
+            Rank::Nine => "nine",
+            Rank::Ten => "ten",
+            Rank::Jack => "jack",
+            Rank::Queen => "queen",
+            Rank::King => "king",
+            Rank::Ace => "ace",
+        },
+        Phrase::ColourName(colour) => match colour {
+            Colour::Red => "red",
+            Colour::Black => "black",
+        },
+        Phrase::CardName => "{rank} of {suit}",
+        Phrase::JokerName => "{colour} joker",
+        Phrase::Playable => "playable",
+        Phrase::NotPlayable => "not playable",
+        Phrase::NoGuesses => "none",
+        Phrase::NoHand => "You don't have any cards yet",
+        Phrase::NoScore => "No rounds have been played yet",
+        Phrase::EmptyTable => "There are no cards on the table",
+        Phrase::Who => "At the table: {players}",
+        Phrase::WhoWaiting => "At the table: {players}, waiting for {player}",
+        Phrase::NotYourTurn => "It's not your turn, waiting for {player}",
+        Phrase::UnknownCommand => "Unknown command '/{command}', type /help to see the commands",
+        Phrase::Goodbye => "Goodbye!",
+        Phrase::Help => {
+            "/hand: show your hand\n\
+             /score: show the score sheet\n\
+             /trick: show the cards on the table\n\
+             /last: show the last trick\n\
+             /history: show every trick of this round\n\
+             /who: show who is playing\n\
+             /rules: explain the rules\n\
+             /say <text>: talk to the table\n\
+             /whisper <player> <text>: talk to one player\n\
+             /mute <player>, /unmute <player>: hide or show what a player says\n\
+             /ready, /unready: say whether you are ready to play, in the waiting room\n\
+             /rematch: play another game once this one is over\n\
+             /start, /start bots: start the game as the host, with bots in the empty seats\n\
+             /language, /profile, /theme: change how the game is shown\n\
+             /quit: leave the game"
+        }
+        Phrase::Rules => {
+            "Every round, each player guesses how many tricks they will take. The last \
+             player to guess may not make the guesses add up to the number of cards. The \
+             highest guess leads the first trick, and whoever takes a trick leads the next. \
+             Follow the led suit if you can, a joker may always be played and beats \
+             everything. Otherwise the highest card of the led suit takes the trick. Taking \
+             exactly as many tricks as you guessed scores 10 points per trick, or 10 for a \
+             guess of zero, anything else scores nothing. The hands shrink to a single card \
+             and then grow again."
+        }
+        Phrase::Round => "Round",
+        Phrase::Cards => "Cards",
+        Phrase::Total => "Total",
+        Phrase::TrickPlay => "{player} {card}",
+        Phrase::Winning => "{trick} (winning: {player})",
+        Phrase::TakenBy => "{trick} (taken by {player})",
+        Phrase::LastTrick => "Last trick: {trick}",
+        Phrase::NoTricks => "No tricks have been taken this round",
+        Phrase::Nobody => "nobody",
+        Phrase::RoundStart => "Round {round} of {rounds}: {hand_size} card(s), {dealer} deals",
+        Phrase::RoundEnd => "Round {round} is over. Made their guess: {made}. Missed: {missed}.",
+        Phrase::VerboseGuessContext => {
+            "Your hand: {hand}. Guesses so far: {guesses}. There are {players} players."
+        }
+        Phrase::VerbosePlayContext => "Your hand: {hand}. {state}",
+        Phrase::VerboseTrick => "On the table: {trick}.",
+        Phrase::VerboseTurn => "It is {player}'s turn.",
+        Phrase::VerboseWinner => "{player} won the trick.",
+        Phrase::VerboseScore => {
+            "{player} has taken {wins} tricks and guessed {guess}, total score {score}."
+        }
+        Phrase::VerboseRoundScores => "Round {round}, {cards} cards: {scores}.",
+        Phrase::VerbosePlayerRound => "{player} guessed {guess} and took {wins}, {points} points",
+        Phrase::VerboseTotals => "Total: {scores}.",
+        Phrase::RequestGuessContext => {
+            "{player}: Hand: {hand}, Previous Guesses: {guesses}, Players: {players}"
+        }
+        Phrase::Guesses => "Guesses: {guesses}",
+        Phrase::Score => "{wins}/{guess} {face} (total: {score})",
+        Phrase::YouGoFirst => "You go first!",
+        Phrase::Trick => "Trick: {trick}",
+        Phrase::Turn => "{player}'s turn",
+        Phrase::PlayRequestContext => "{player}: Hand: {hand}, {state}",
+        Phrase::PlayRequest => {
+            "{player}: Select card to play (leftmost is 0, or type a card like AH): "
+        }
+        Phrase::RequestGuess => "Please make a guess ({choices}): ",
+        Phrase::Winner => "{player} won!",
+        Phrase::Winners => "The winner(s) is/are {winners}!",
+        Phrase::RequestPlayerName => "Please input player name: ",
+        Phrase::GameOver => "Game over!",
+        Phrase::NotANumber => "'{text}' is not a number, please try again",
+        Phrase::GuessTooHigh => "You can't guess {guess} with only {hand_size} card(s) in hand",
+        Phrase::ForbiddenGuess => {
+            "You can't guess {guess}, the guesses would add up to {hand_size}"
+        }
+        Phrase::NoSuchCard => "There is no card {index}, you only have {hand_size} card(s)",
+        Phrase::MustFollowSuit => "You must follow suit ({suit})",
+        Phrase::NotACard => "'{text}' is neither a card index nor a card, please try again",
+        Phrase::CardNotInHand => "You don't have {card}",
+        Phrase::ChatTooLong => {
+            "Your message has {length} characters, the most you can send is {max}"
+        }
+        Phrase::UnknownPlayer => "There is no player called '{player}'",
+        Phrase::Chat => "{player} says: {text}",
+        Phrase::Whisper => "{player} whispers to {to}: {text}",
+        Phrase::Muted => "You won't see what {player} says",
+        Phrase::Unmuted => "You will see what {player} says again",
+        Phrase::NoChatYet => "You can chat once everyone has joined",
+        Phrase::LineTooLong => "Closing the connection, lines can be at most {max} bytes long",
+        Phrase::InvalidUtf8 => "Closing the connection, only UTF-8 text is understood",
+        Phrase::MalformedInput => "Closing the connection: {error}",
+        Phrase::Idle => "Closing the connection, nothing was received in time",
+        Phrase::NameEmpty => "Your name can't be empty",
+        Phrase::NameTooLong => "Your name can be at most {max} characters long",
+        Phrase::NameCharacter => {
+            "Names can only have letters, digits and - _ . ' in them, not '{character}'"
+        }
+        Phrase::NameTaken => "Someone called {player} is already here",
+        Phrase::NoSeat => "Closing the connection, there is no free seat at the table",
+        Phrase::Lobby => "Waiting room, {count} of {seats} seats taken: {players}",
+        Phrase::Host => "host",
+        Phrase::Ready => "ready",
+        Phrase::NotReady => "not ready",
+        Phrase::LobbyHint => {
+            "The game hasn't started yet. Type /ready when you are ready to play, then the \
+             host starts the game with /start, or with /start bots to fill the empty seats \
+             with bots."
+        }
+        Phrase::NotInLobby => "That only works in the waiting room, between games",
+        Phrase::NotHost => "Only {player}, the host, can start the game",
+        Phrase::WaitingForReady => "Waiting for {players} to be ready",
+        Phrase::TooFewPlayers => {
+            "At least {min} players are needed, /start bots fills the empty seats with bots"
+        }
+        Phrase::RematchHint => {
+            "The game is over. Type /rematch to play another one, or /quit to leave the \
+             table. The host starts the next game with /start, or with /start bots to fill \
+             the empty seats with bots."
+        }
+        Phrase::Series => "Games won after {games} game(s): {scores}",
+        Phrase::SeriesScore => "{player} {won} of {played}",
+        Phrase::RejoinToken => {
+            "If your connection drops, give {token} instead of your name to get your seat back"
+        }
+        Phrase::HandshakeAccepted => "Connected using protocol version {version} ({encoding})",
+        Phrase::UnsupportedVersion => {
+            "Protocol version {requested} is not supported, the server speaks {supported}"
+        }
+        Phrase::MalformedHandshake => "Malformed handshake: {error}",
+    }
+}
+
+const fn swedish(phrase: Phrase) -> Option<&'static str> {
+    let text = match phrase {
+        Phrase::LanguageName => "svenska",
+        Phrase::LanguageChanged => "Språk: {language}",
+        Phrase::UnknownLanguage => "Okänt språk '{text}', välj ett av: {languages}",
+        Phrase::ProfileChanged => "Visning: {profile}",
+        Phrase::UnknownProfile => "Okänd visning '{text}', välj en av: {profiles}",
+        Phrase::ThemeChanged => "Färger: {theme}",
+        Phrase::UnknownTheme => "Okända färger '{text}', välj en av: {themes}",
+        Phrase::SuitName(suit) => match suit {
+            Suit::Hearts => "hjärter",
+            Suit::Clubs => "klöver",
+            Suit::Diamonds => "ruter",
+            Suit::Spades => "spader",
+        },
+        Phrase::RankName(rank) => match rank {
+            Rank::Two => "två",
+            Rank::Three => "tre",
+            Rank::Four => "fyra",
+            Rank::Five => "fem",
+            Rank::Six => "sex",
+            Rank::Seven => "sju",
+            Rank::Eight => "åtta",
+            Rank::Nine => "nio",
+            Rank::Ten => "tio",
+            Rank::Jack => "knekt",
+            Rank::Queen => "dam",
+            Rank::King => "kung",
+            Rank::Ace => "ess",
+        },
+        Phrase::ColourName(colour) => match colour {
+            Colour::Red => "röd",
+            Colour::Black => "svart",
+        },
+        Phrase::CardName => "{suit} {rank}",
+        Phrase::JokerName => "{colour} joker",
+        Phrase::Playable => "spelbart",
+        Phrase::NotPlayable => "inte spelbart",
+        Phrase::NoGuesses => "inga",
+        Phrase::NoHand => "Du har inga kort än",
+        Phrase::NoScore => "Inga omgångar har spelats än",
+        Phrase::EmptyTable => "Det finns inga kort på bordet",
+        Phrase::Who => "Vid bordet: {players}",
+        Phrase::WhoWaiting => "Vid bordet: {players}, väntar på {player}",
+        Phrase::NotYourTurn => "Det är inte din tur, väntar på {player}",
+        Phrase::UnknownCommand => "Okänt kommando '/{command}', skriv /help för att se kommandona",
+        Phrase::Goodbye => "Hej då!",
+        Phrase::Help => {
+            "/hand: visa din hand\n\
+             /score: visa protokollet\n\
+             /trick: visa korten på bordet\n\
+             /last: visa förra sticket\n\
+             /history: visa omgångens alla stick\n\
+             /who: visa vilka som spelar\n\
+             /rules: förklara reglerna\n\
+             /say <text>: prata med bordet\n\
+             /whisper <spelare> <text>: prata med en spelare\n\
+             /mute <spelare>, /unmute <spelare>: dölj eller visa vad en spelare säger\n\
+             /ready, /unready: säg om du är redo att spela, i väntrummet\n\
+             /rematch: spela ett spel till när det här är slut\n\
+             /start, /start bots: starta spelet som värd, med botar på de tomma platserna\n\
+             /language, /profile, /theme: ändra hur spelet visas\n\
+             /quit: lämna spelet"
+        }
+        Phrase::Rules => {
+            "Varje omgång gissar alla hur många stick de kommer att ta. Den som gissar sist \
+             får inte göra så att gissningarna blir lika många som korten. Den som gissade \
+             högst spelar ut först, och den som tar ett stick spelar ut nästa. Bekänn färg \
+             om du kan, en joker får alltid spelas och slår allt. Annars tar det högsta \
+             kortet i utspelad färg sticket. Tar du exakt så många stick som du gissade får \
+             du 10 poäng per stick, eller 10 för en nollgissning, annars får du inget. \
+             Händerna krymper till ett enda kort och växer sedan igen."
+        }
+        Phrase::Round => "Omgång",
+        Phrase::Cards => "Kort",
+        Phrase::Total => "Totalt",
+        Phrase::TrickPlay => "{player} {card}",
+        Phrase::Winning => "{trick} (vinner: {player})",
+        Phrase::TakenBy => "{trick} (togs av {player})",
+        Phrase::LastTrick => "Förra sticket: {trick}",
+        Phrase::NoTricks => "Inga stick har tagits i den här omgången",
+        Phrase::Nobody => "ingen",
+        Phrase::RoundStart => "Omgång {round} av {rounds}: {hand_size} kort, {dealer} delar ut",
+        Phrase::RoundEnd => "Omgång {round} är slut. Klarade sin gissning: {made}. Missade: {missed}.",
+        Phrase::VerboseGuessContext => {
+            "Din hand: {hand}. Gissningar hittills: {guesses}. Ni är {players} spelare."
+        }
+        Phrase::VerbosePlayContext => "Din hand: {hand}. {state}",
+        Phrase::VerboseTrick => "På bordet: {trick}.",
+        Phrase::VerboseTurn => "Det är {player}s tur.",
+        Phrase::VerboseWinner => "{player} vann sticket.",
+        Phrase::VerboseScore => {
+            "{player} har tagit {wins} stick och gissade {guess}, totalt {score} poäng."
+        }
+        Phrase::VerboseRoundScores => "Omgång {round}, {cards} kort: {scores}.",
+        Phrase::VerbosePlayerRound => "{player} gissade {guess} och tog {wins}, {points} poäng",
+        Phrase::VerboseTotals => "Totalt: {scores}.",
+        Phrase::RequestGuessContext => {
+            "{player}: Hand: {hand}, Tidigare gissningar: {guesses}, Spelare: {players}"
+        }
+        Phrase::Guesses => "Gissningar: {guesses}",
+        Phrase::Score => "{wins}/{guess} {face} (totalt: {score})",
+        Phrase::YouGoFirst => "Du börjar!",
+        Phrase::Trick => "Stick: {trick}",
+        Phrase::Turn => "{player}s tur",
+        Phrase::PlayRequestContext => "{player}: Hand: {hand}, {state}",
+        Phrase::PlayRequest => {
+            "{player}: Välj ett kort att spela (längst till vänster är 0, eller skriv ett kort som AH): "
+        }
+        Phrase::RequestGuess => "Hur många stick tar du ({choices}): ",
+        Phrase::Winner => "{player} vann!",
+        Phrase::Winners => "Vinnare: {winners}!",
+        Phrase::RequestPlayerName => "Skriv ditt namn: ",
+        Phrase::GameOver => "Spelet är slut!",
+        Phrase::NotANumber => "'{text}' är inte ett tal, försök igen",
+        Phrase::GuessTooHigh => "Du kan inte gissa {guess} med bara {hand_size} kort på hand",
+        Phrase::ForbiddenGuess => {
+            "Du kan inte gissa {guess}, gissningarna skulle bli {hand_size} totalt"
+        }
+        Phrase::NoSuchCard => "Det finns inget kort {index}, du har bara {hand_size} kort",
+        Phrase::MustFollowSuit => "Du måste bekänna färg ({suit})",
+        Phrase::NotACard => "'{text}' är varken ett kortnummer eller ett kort, försök igen",
+        Phrase::CardNotInHand => "Du har inte {card}",
+        Phrase::ChatTooLong => "Ditt meddelande har {length} tecken, som mest kan du skicka {max}",
+        Phrase::UnknownPlayer => "Det finns ingen spelare som heter '{player}'",
+        Phrase::Chat => "{player} säger: {text}",
+        Phrase::Whisper => "{player} viskar till {to}: {text}",
+        Phrase::Muted => "Du kommer inte att se vad {player} säger",
+        Phrase::Unmuted => "Du kommer att se vad {player} säger igen",
+        Phrase::NoChatYet => "Du kan chatta när alla har anslutit",
+        Phrase::LineTooLong => "Stänger anslutningen, rader får vara högst {max} byte långa",
+        Phrase::InvalidUtf8 => "Stänger anslutningen, bara UTF-8-text förstås",
+        Phrase::MalformedInput => "Stänger anslutningen: {error}",
+        Phrase::Idle => "Stänger anslutningen, inget togs emot i tid",
+        Phrase::NameEmpty => "Ditt namn kan inte vara tomt",
+        Phrase::NameTooLong => "Ditt namn får vara högst {max} tecken långt",
+        Phrase::NameCharacter => {
+            "Namn får bara innehålla bokstäver, siffror och - _ . ', inte '{character}'"
+        }
+        Phrase::NameTaken => "Någon som heter {player} är redan här",
+        Phrase::NoSeat => "Stänger anslutningen, det finns ingen ledig plats vid bordet",
+        Phrase::Lobby => "Väntrum, {count} av {seats} platser tagna: {players}",
+        Phrase::Host => "värd",
+        Phrase::Ready => "redo",
+        Phrase::NotReady => "inte redo",
+        Phrase::LobbyHint => {
+            "Spelet har inte börjat än. Skriv /ready när du är redo att spela, sedan startar \
+             värden spelet med /start, eller med /start bots för att fylla de tomma platserna \
+             med botar."
+        }
+        Phrase::NotInLobby => "Det går bara i väntrummet, mellan spelen",
+        Phrase::NotHost => "Bara {player}, som är värd, kan starta spelet",
+        Phrase::WaitingForReady => "Väntar på att {players} ska bli redo",
+        Phrase::TooFewPlayers => {
+            "Det behövs minst {min} spelare, /start bots fyller de tomma platserna med botar"
+        }
+        Phrase::RematchHint => {
+            "Spelet är slut. Skriv /rematch för att spela ett till, eller /quit för att \
+             lämna bordet. Värden startar nästa spel med /start, eller med /start bots för \
+             att fylla de tomma platserna med botar."
+        }
+        Phrase::Series => "Vunna spel efter {games} spel: {scores}",
+        Phrase::SeriesScore => "{player} {won} av {played}",
+        Phrase::RejoinToken => {
+            "Om anslutningen bryts, ange {token} i stället för ditt namn för att få tillbaka \
+             din plats"
+        }
+        // Only bots and the server log see these.
+        Phrase::HandshakeAccepted | Phrase::UnsupportedVersion | Phrase::MalformedHandshake => {
+            return None
+        }
+    };
+
+    Some(text)
+}
+
+/// Looks `phrase` up in the catalog of `language`, falling back to English
+/// when it hasn't been translated.
+pub(crate) fn translate(language: Language, phrase: Phrase) -> &'static str {
+    let translated = match language {
+        Language::English => None,
+        Language::Swedish => swedish(phrase),
+    };
+
+    translated.unwrap_or_else(|| english(phrase))
+}
+
+/// Replaces each `{name}` in `template` with its value from `arguments`.
+/// Values are inserted as they are, even if they contain braces.
+pub(crate) fn fill(template: &str, arguments: &[(&str, &str)]) -> String {
+    let mut text = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            let (_, value) = arguments.iter().find(|(key, _)| *key == name)?;
+            Some((value, end))
+        });
+
+        match value {
+            Some((value, end)) => {
+                text.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                text.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_language() {
+        assert_eq!("sv".parse(), Ok(Language::Swedish));
+        assert_eq!("Svenska".parse(), Ok(Language::Swedish));
+        assert_eq!(" en\n".parse(), Ok(Language::English));
+        assert_eq!(
+            "fi".parse::<Language>(),
+            Err(UnknownLanguage("fi".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(
+            translate(Language::Swedish, Phrase::YouGoFirst),
+            "Du börjar!"
+        );
+        assert_eq!(
+            translate(Language::Swedish, Phrase::MalformedHandshake),
+            english(Phrase::MalformedHandshake)
+        );
+    }
+
+    #[test]
+    fn test_fill() {
+        let text = fill(
+            translate(Language::English, Phrase::NoSuchCard),
+            &[("index", "7"), ("hand_size", "3")],
+        );
+        assert_eq!(text, "There is no card 7, you only have 3 card(s)");
+
+        let text = fill(
+            "{player}: {hand} {missing}",
+            &[("player", "{hand}"), ("hand", "AH")],
+        );
+        assert_eq!(text, "{hand}: AH {missing}");
+    }
+}
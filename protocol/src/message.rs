@@ -1,17 +1,32 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 use playing_cards::structs::Card;
 use serde::{Deserialize, Serialize};
 
-use crate::structs::{Player, StatePerPlayer, Trick};
+#[cfg(test)]
+use strum_macros::{EnumDiscriminants, EnumIter};
+
+use crate::structs::{
+    Capability, Disconnect, Encoding, HandshakeRejection, LobbyPlayer, NameRejection, Player,
+    PlayerName, PublicPlayer, Rejection, ScoreSheet, SeriesScore, StatePerPlayer, Trick,
+};
+
+/// The protocol version announced in [`ClientMessage::Hello`].
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Chat messages longer than this many characters are rejected.
+pub const MAX_CHAT_LENGTH: usize = 200;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(test, derive(EnumDiscriminants), strum_discriminants(derive(EnumIter)))]
 pub enum Message {
     RequestGuessContext {
         player: Player,
         hand: Vec<Card>,
         guesses: Vec<usize>,
         players: usize,
+        /// `None` unless the client asked for [`Capability::LegalActions`].
+        legal_guesses: Option<BTreeSet<usize>>,
     },
 
     Guesses {
@@ -19,14 +34,14 @@ pub enum Message {
     },
 
     Turn {
-        whose: Player,
+        whose: PublicPlayer,
     },
 
     PlayRequestContext {
         player: Player,
         hand: Vec<Card>,
         trick: Trick,
-        valid_cards: Option<HashSet<usize>>,
+        valid_cards: Option<BTreeSet<usize>>,
     },
 
     Trick(Trick),
@@ -35,14 +50,652 @@ pub enum Message {
         state: StatePerPlayer,
     },
 
-    Winner(Player),
+    Winner(PublicPlayer),
 
     Winners {
-        players: Vec<Player>,
+        players: Vec<PublicPlayer>,
         winner_indices: Vec<usize>,
     },
     RequestPlayerName,
-    PlayRequest(Player),
-    RequestGuess,
+    PlayRequest {
+        player: Player,
+        valid_cards: Option<BTreeSet<usize>>,
+    },
+    RequestGuess {
+        legal_guesses: Option<BTreeSet<usize>>,
+    },
     GameOver,
+    Rejected(Rejection),
+
+    HandshakeAccepted {
+        version: u32,
+        encoding: Encoding,
+        capabilities: Vec<Capability>,
+    },
+    HandshakeRejected(HandshakeRejection),
+
+    /// Sent after every round, so the last one is the final result.
+    ScoreSheet(ScoreSheet),
+
+    /// `round` counts from 1 up to `rounds`, the length of the schedule.
+    RoundStart {
+        round: usize,
+        rounds: usize,
+        hand_size: usize,
+        dealer: PublicPlayer,
+    },
+    RoundEnd {
+        round: usize,
+        made: Vec<PublicPlayer>,
+        missed: Vec<PublicPlayer>,
+    },
+
+    /// The answer to [`ClientMessage::LastTrick`], `None` before the first
+    /// trick of the round has been taken.
+    LastTrick(Option<Trick>),
+    /// The answer to [`ClientMessage::TrickHistory`]: every trick taken this
+    /// round, oldest first.
+    TrickHistory(Vec<Trick>),
+
+    /// Said to the whole table.
+    Chat {
+        sender: PlayerName,
+        text: String,
+    },
+    /// Said to a single player, and sent to both of them.
+    Whisper {
+        sender: PlayerName,
+        to: PlayerName,
+        text: String,
+    },
+    /// The last message before the server closes the connection.
+    Disconnected(Disconnect),
+    /// Followed by another [`Message::RequestPlayerName`].
+    NameRejected(NameRejection),
+    /// Who is in the waiting room, sent whenever it changes. The first
+    /// player is the host, who starts the game with [`ClientMessage::Start`].
+    Lobby {
+        players: Vec<LobbyPlayer>,
+        seats: usize,
+    },
+    /// The games won by every player who has played at the table, sent
+    /// after [`Message::Winners`].
+    Series {
+        games: u32,
+        scores: Vec<SeriesScore>,
+    },
+    /// Sent to a player who takes a seat. After losing the connection, the
+    /// player gets the seat back by giving the token instead of a name.
+    RejoinToken(String),
+}
+
+impl Message {
+    /// Whether the message is meant for a single player only. Anything else
+    /// may be sent to every client and must not reveal any player's hand.
+    pub const fn is_private(&self) -> bool {
+        match self {
+            Message::RequestGuessContext { .. }
+            | Message::PlayRequestContext { .. }
+            | Message::RequestPlayerName
+            | Message::PlayRequest { .. }
+            | Message::RequestGuess { .. }
+            | Message::Rejected(_)
+            | Message::HandshakeAccepted { .. }
+            | Message::HandshakeRejected(_)
+            | Message::LastTrick(_)
+            | Message::TrickHistory(_)
+            | Message::Whisper { .. }
+            | Message::Disconnected(_)
+            | Message::NameRejected(_)
+            | Message::RejoinToken(_) => true,
+
+            Message::Guesses { .. }
+            | Message::Turn { .. }
+            | Message::Trick(_)
+            | Message::Scoreboard { .. }
+            | Message::Winner(_)
+            | Message::Winners { .. }
+            | Message::GameOver
+            | Message::ScoreSheet(_)
+            | Message::RoundStart { .. }
+            | Message::RoundEnd { .. }
+            | Message::Chat { .. }
+            | Message::Lobby { .. }
+            | Message::Series { .. } => false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ClientMessage {
+    Reply(String),
+    Hello {
+        version: u32,
+        encoding: Encoding,
+        capabilities: Vec<Capability>,
+    },
+    /// Asks for the trick taken last, answered with [`Message::LastTrick`].
+    LastTrick,
+    /// Asks for every trick taken this round, answered with
+    /// [`Message::TrickHistory`].
+    TrickHistory,
+    /// Says `text` to the table, or only to `to`.
+    Chat {
+        to: Option<PlayerName>,
+        text: String,
+    },
+    /// Stops showing the chat of a player.
+    Mute(PlayerName),
+    Unmute(PlayerName),
+    /// Tells the waiting room whether the player is ready to play.
+    Ready(bool),
+    /// Starts the game, which only the host may do. With `bots`, the empty
+    /// seats are taken by bots.
+    Start {
+        bots: bool,
+    },
+    /// Leaves the table, which between games gives up the seat.
+    Leave,
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashMap};
+
+    use playing_cards::{
+        notation::parse_cards,
+        structs::{Card, Rank, Suit},
+    };
+    use serde_json::Value;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::{
+        locale::Language,
+        options::{Profile, RenderOptions, Theme},
+        structs::{PlayerName, PublicState, RoundScore, ScoreSheetRound},
+    };
+
+    fn player() -> Player {
+        Player {
+            name: PlayerName("Alice".to_owned()),
+            human: true,
+            hand: vec![Card::new(Suit::Diamonds, Rank::Ace)],
+        }
+    }
+
+    fn score_sheet() -> ScoreSheet {
+        let round = |hand_size, scores: [(usize, usize, usize); 2]| ScoreSheetRound {
+            hand_size,
+            scores: scores
+                .into_iter()
+                .map(|(guess, wins, points)| RoundScore {
+                    guess,
+                    wins,
+                    points,
+                })
+                .collect(),
+        };
+
+        ScoreSheet {
+            players: vec![player().name, PlayerName("Bob".to_owned())],
+            rounds: vec![
+                round(10, [(3, 3, 30), (4, 7, 0)]),
+                round(9, [(0, 0, 10), (9, 9, 90)]),
+            ],
+        }
+    }
+
+    /// Plays `text` in turn by Bob, Alice and Carol.
+    fn trick(text: &str) -> Trick {
+        let names = ["Bob", "Alice", "Carol"];
+        let mut trick = Trick::new();
+        for (name, card) in names.iter().zip(parse_cards(text).unwrap()) {
+            trick.play(PlayerName((*name).to_owned()), card);
+        }
+        trick
+    }
+
+    fn sample(kind: MessageDiscriminants) -> Message {
+        let state = HashMap::from([(
+            player().name,
+            PublicState {
+                guess: Some(1),
+                wins: 0,
+                score: 10,
+            },
+        )]);
+
+        match kind {
+            MessageDiscriminants::RequestGuessContext => Message::RequestGuessContext {
+                player: player(),
+                hand: player().hand,
+                guesses: vec![1],
+                players: 2,
+                legal_guesses: Some(BTreeSet::from([1])),
+            },
+            MessageDiscriminants::Guesses => Message::Guesses { state },
+            MessageDiscriminants::Turn => Message::Turn {
+                whose: player().public(),
+            },
+            MessageDiscriminants::PlayRequestContext => Message::PlayRequestContext {
+                player: player(),
+                hand: player().hand,
+                trick: trick("5D"),
+                valid_cards: Some(BTreeSet::from([0])),
+            },
+            MessageDiscriminants::Trick => Message::Trick(trick("5D")),
+            MessageDiscriminants::Scoreboard => Message::Scoreboard { state },
+            MessageDiscriminants::Winner => Message::Winner(player().public()),
+            MessageDiscriminants::Winners => Message::Winners {
+                players: vec![player().public()],
+                winner_indices: vec![0],
+            },
+            MessageDiscriminants::RequestPlayerName => Message::RequestPlayerName,
+            MessageDiscriminants::PlayRequest => Message::PlayRequest {
+                player: player(),
+                valid_cards: Some(BTreeSet::from([0])),
+            },
+            MessageDiscriminants::RequestGuess => Message::RequestGuess {
+                legal_guesses: Some(BTreeSet::from([0, 1])),
+            },
+            MessageDiscriminants::GameOver => Message::GameOver,
+            MessageDiscriminants::Rejected => Message::Rejected(Rejection::NoSuchCard {
+                index: 3,
+                hand_size: 1,
+            }),
+            MessageDiscriminants::HandshakeAccepted => Message::HandshakeAccepted {
+                version: PROTOCOL_VERSION,
+                encoding: Encoding::Json,
+                capabilities: vec![Capability::LegalActions],
+            },
+            MessageDiscriminants::HandshakeRejected => {
+                Message::HandshakeRejected(HandshakeRejection::Malformed(String::new()))
+            }
+            MessageDiscriminants::ScoreSheet => Message::ScoreSheet(score_sheet()),
+            MessageDiscriminants::RoundStart => Message::RoundStart {
+                round: 2,
+                rounds: 19,
+                hand_size: 9,
+                dealer: player().public(),
+            },
+            MessageDiscriminants::RoundEnd => Message::RoundEnd {
+                round: 2,
+                made: vec![player().public()],
+                missed: vec![],
+            },
+            MessageDiscriminants::LastTrick => Message::LastTrick(Some(trick("5D 7D"))),
+            MessageDiscriminants::TrickHistory => {
+                Message::TrickHistory(vec![trick("5D 7D"), trick("JKB 2C")])
+            }
+            MessageDiscriminants::Chat => Message::Chat {
+                sender: player().name,
+                text: "Good luck!".to_owned(),
+            },
+            MessageDiscriminants::Whisper => Message::Whisper {
+                sender: player().name,
+                to: PlayerName("Bob".to_owned()),
+                text: "Lead hearts".to_owned(),
+            },
+            MessageDiscriminants::Disconnected => {
+                Message::Disconnected(Disconnect::LineTooLong { max: 4096 })
+            }
+            MessageDiscriminants::NameRejected => {
+                Message::NameRejected(NameRejection::InvalidCharacter('\u{1b}'))
+            }
+            MessageDiscriminants::Lobby => Message::Lobby {
+                players: vec![
+                    LobbyPlayer {
+                        name: player().name,
+                        ready: true,
+                    },
+                    LobbyPlayer {
+                        name: PlayerName("Bob".to_owned()),
+                        ready: false,
+                    },
+                ],
+                seats: 4,
+            },
+            MessageDiscriminants::Series => Message::Series {
+                games: 3,
+                scores: vec![
+                    SeriesScore {
+                        name: player().name,
+                        played: 3,
+                        won: 2,
+                    },
+                    SeriesScore {
+                        name: PlayerName("Bob".to_owned()),
+                        played: 1,
+                        won: 0,
+                    },
+                ],
+            },
+            MessageDiscriminants::RejoinToken => {
+                Message::RejoinToken("3f9c2a417be05d68".to_owned())
+            }
+        }
+    }
+
+    fn contains_hand(value: &Value) -> bool {
+        match value {
+            Value::Object(fields) => fields
+                .iter()
+                .any(|(key, value)| key == "hand" || contains_hand(value)),
+            Value::Array(values) => values.iter().any(contains_hand),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn test_broadcasts_do_not_reveal_hands() {
+        for kind in MessageDiscriminants::iter() {
+            let message = sample(kind);
+            let value = serde_json::to_value(&message).unwrap();
+
+            assert!(
+                message.is_private() || !contains_hand(&value),
+                "{value} is broadcast but reveals a hand"
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_message_is_rendered_in_every_language() {
+        for kind in MessageDiscriminants::iter() {
+            let message = sample(kind);
+
+            for language in Language::ALL {
+                let text = message.render(RenderOptions {
+                    language,
+                    ..RenderOptions::default()
+                });
+                assert!(!text.contains('{'), "{text} has an unfilled argument");
+            }
+        }
+
+        let options = RenderOptions {
+            language: Language::Swedish,
+            ..RenderOptions::default()
+        };
+        assert_eq!(
+            sample(MessageDiscriminants::Turn).render(options),
+            "Alices tur"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::Rejected).render(options),
+            "Det finns inget kort 3, du har bara 1 kort"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::HandshakeRejected).render(options),
+            sample(MessageDiscriminants::HandshakeRejected).to_string()
+        );
+    }
+
+    #[test]
+    fn test_ascii_profile() {
+        let options = RenderOptions {
+            profile: Profile::Ascii,
+            ..RenderOptions::default()
+        };
+
+        for kind in MessageDiscriminants::iter() {
+            for language in Language::ALL {
+                let text = sample(kind).render(RenderOptions {
+                    language,
+                    ..options
+                });
+                assert!(text.is_ascii(), "{text} isn't ASCII");
+            }
+        }
+
+        let context = Message::PlayRequestContext {
+            player: player(),
+            hand: parse_cards("AD 10S").unwrap(),
+            trick: trick("5D"),
+            valid_cards: Some(BTreeSet::from([0])),
+        };
+        assert_eq!(
+            context.render(options),
+            "Alice: Hand: 0|AD [1|10S], Trick: Bob 5D (winning: Bob)"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::Scoreboard).render(options),
+            "Alice: 0/1 :( (total: 10)"
+        );
+
+        let unicode = RenderOptions {
+            profile: Profile::Unicode,
+            ..options
+        };
+        assert_eq!(
+            context.render(unicode),
+            "Alice: Hand: 0|♦A [1|♠10], Trick: Bob ♦5 (winning: Bob)"
+        );
+    }
+
+    #[test]
+    fn test_accessible_profile() {
+        let options = RenderOptions {
+            profile: Profile::Accessible,
+            ..RenderOptions::default()
+        };
+
+        for kind in MessageDiscriminants::iter() {
+            let text = sample(kind).render(options);
+            assert!(
+                text.chars().all(|c| c.is_alphanumeric() || c.is_ascii()),
+                "{text} has symbols"
+            );
+        }
+
+        let context = Message::PlayRequestContext {
+            player: player(),
+            hand: parse_cards("AD 10S JKR").unwrap(),
+            trick: trick("5D"),
+            valid_cards: Some(BTreeSet::from([0, 2])),
+        };
+        assert_eq!(
+            context.render(options),
+            "Your hand: 0: ace of diamonds, playable; 1: ten of spades, not playable; \
+             2: red joker, playable. Trick: Bob five of diamonds (winning: Bob)"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::Turn).render(options),
+            "It is Alice's turn."
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::Scoreboard).render(options),
+            "Alice has taken 0 tricks and guessed 1, total score 10."
+        );
+
+        let swedish = RenderOptions {
+            language: Language::Swedish,
+            ..options
+        };
+        assert_eq!(
+            sample(MessageDiscriminants::Trick).render(swedish),
+            "På bordet: Bob ruter fem (vinner: Bob)."
+        );
+    }
+
+    #[test]
+    fn test_themes() {
+        let context = Message::PlayRequestContext {
+            player: player(),
+            hand: parse_cards("AD 10S 2C").unwrap(),
+            trick: trick("5D"),
+            valid_cards: Some(BTreeSet::from([0])),
+        };
+
+        let plain = RenderOptions::default();
+        assert_eq!(
+            context.render(plain),
+            "Alice: Hand: 0|♦A \x1b[90m1|♠10\x1b[0m \x1b[90m2|♣2\x1b[0m, Trick: Bob ♦5 (winning: Bob)"
+        );
+
+        let classic = RenderOptions {
+            theme: Theme::Classic,
+            ..plain
+        };
+        assert_eq!(
+            context.render(classic),
+            "Alice: Hand: \x1b[31m0|♦A\x1b[0m \x1b[90m1|♠10\x1b[0m \x1b[90m2|♣2\x1b[0m, \
+             Trick: Bob \x1b[31m♦5\x1b[0m (winning: Bob)"
+        );
+
+        let four_colour = RenderOptions {
+            theme: Theme::FourColour,
+            ..plain
+        };
+        assert_eq!(
+            context.render(four_colour),
+            "Alice: Hand: \x1b[34m0|♦A\x1b[0m \x1b[90m1|♠10\x1b[0m \x1b[2;32m2|♣2\x1b[0m, \
+             Trick: Bob \x1b[34m♦5\x1b[0m (winning: Bob)"
+        );
+
+        let ascii = RenderOptions {
+            profile: Profile::Ascii,
+            ..four_colour
+        };
+        assert!(context.render(ascii).is_ascii());
+        assert!(!context.render(ascii).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_trick_names_players() {
+        let trick = trick("3H AH");
+        assert_eq!(
+            Message::Trick(trick.clone()).to_string(),
+            "Bob ♥3, Alice ♥A (winning: Alice)"
+        );
+
+        let options = RenderOptions {
+            profile: Profile::Accessible,
+            ..RenderOptions::default()
+        };
+        assert_eq!(
+            Message::Trick(trick).render(options),
+            "On the table: Bob three of hearts; Alice ace of hearts (winning: Alice)."
+        );
+    }
+
+    #[test]
+    fn test_trick_review() {
+        assert_eq!(
+            sample(MessageDiscriminants::LastTrick).to_string(),
+            "Last trick: Bob ♦5, Alice ♦7 (taken by Alice)"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::TrickHistory).to_string(),
+            "1. Bob ♦5, Alice ♦7 (taken by Alice)\n2. Bob 🃏, Alice ♣2 (taken by Bob)"
+        );
+        assert_eq!(
+            Message::LastTrick(None).to_string(),
+            "No tricks have been taken this round"
+        );
+        assert_eq!(
+            Message::TrickHistory(vec![]).to_string(),
+            "No tricks have been taken this round"
+        );
+    }
+
+    #[test]
+    fn test_chat() {
+        assert_eq!(
+            sample(MessageDiscriminants::Chat).to_string(),
+            "Alice says: Good luck!"
+        );
+        assert_eq!(
+            sample(MessageDiscriminants::Whisper).to_string(),
+            "Alice whispers to Bob: Lead hearts"
+        );
+
+        let rejection = Message::Rejected(Rejection::ChatTooLong {
+            length: 201,
+            max: MAX_CHAT_LENGTH,
+        });
+        assert_eq!(
+            rejection.to_string(),
+            "Your message has 201 characters, the most you can send is 200"
+        );
+    }
+
+    #[test]
+    fn test_lobby() {
+        assert_eq!(
+            sample(MessageDiscriminants::Lobby).to_string(),
+            "Waiting room, 2 of 4 seats taken: Alice (host, ready), Bob (not ready)"
+        );
+
+        let rejection = Message::Rejected(Rejection::NotReady(vec![
+            PlayerName("Bob".to_owned()),
+            PlayerName("Carol".to_owned()),
+        ]));
+        assert_eq!(rejection.to_string(), "Waiting for Bob, Carol to be ready");
+    }
+
+    #[test]
+    fn test_series() {
+        assert_eq!(
+            sample(MessageDiscriminants::Series).to_string(),
+            "Games won after 3 game(s): Alice 2 of 3, Bob 0 of 1"
+        );
+    }
+
+    #[test]
+    fn test_round_announcements() {
+        let start = Message::RoundStart {
+            round: 11,
+            rounds: 19,
+            hand_size: 1,
+            dealer: player().public(),
+        };
+        assert_eq!(start.to_string(), "Round 11 of 19: 1 card(s), Alice deals");
+
+        let end = Message::RoundEnd {
+            round: 11,
+            made: vec![],
+            missed: vec![
+                player().public(),
+                PublicPlayer {
+                    name: PlayerName("Bob".to_owned()),
+                    human: false,
+                },
+            ],
+        };
+        assert_eq!(
+            end.to_string(),
+            "Round 11 is over. Made their guess: nobody. Missed: Alice, Bob."
+        );
+    }
+
+    #[test]
+    fn test_score_sheet() {
+        let sheet = score_sheet();
+        assert_eq!(sheet.totals(), vec![40, 90]);
+
+        let table = Message::ScoreSheet(sheet.clone()).to_string();
+        assert_eq!(
+            table,
+            [
+                "Round | Cards |  Alice |    Bob",
+                "    1 |    10 | 3/3 30 |  7/4 0",
+                "    2 |     9 | 0/0 10 | 9/9 90",
+                "Total |       |     40 |     90",
+            ]
+            .join("\n")
+        );
+
+        let options = RenderOptions {
+            profile: Profile::Accessible,
+            ..RenderOptions::default()
+        };
+        let text = Message::ScoreSheet(sheet).render(options);
+        assert!(text.starts_with(
+            "Round 1, 10 cards: Alice guessed 3 and took 3, 30 points; \
+             Bob guessed 4 and took 7, 0 points."
+        ));
+        assert!(text.ends_with("Total: Alice 40, Bob 90."));
+    }
 }
@@ -0,0 +1,201 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::locale::{Language, UnknownLanguage};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Profile {
+    /// Suit symbols, emoji and colours.
+    #[default]
+    Full,
+    /// Suit symbols and emoji, with unplayable cards in brackets instead of
+    /// darkened.
+    Unicode,
+    /// Cards written like `AH` and nothing but ASCII.
+    Ascii,
+    /// Cards and events written out in words, for screen readers.
+    Accessible,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 4] = [
+        Profile::Full,
+        Profile::Unicode,
+        Profile::Ascii,
+        Profile::Accessible,
+    ];
+
+    pub const fn code(self) -> &'static str {
+        match self {
+            Profile::Full => "full",
+            Profile::Unicode => "unicode",
+            Profile::Ascii => "ascii",
+            Profile::Accessible => "accessible",
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct UnknownProfile(pub String);
+
+impl Display for UnknownProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let codes = Profile::ALL.iter().map(|profile| profile.code()).join(", ");
+        write!(f, "Unknown profile '{}', expected one of {codes}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownProfile {}
+
+impl FromStr for Profile {
+    type Err = UnknownProfile;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Profile::ALL
+            .into_iter()
+            .find(|profile| profile.code().eq_ignore_ascii_case(text.trim()))
+            .ok_or_else(|| UnknownProfile(text.to_owned()))
+    }
+}
+
+/// Suit colours for the full profile.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Theme {
+    /// Every suit in the terminal's own colour.
+    #[default]
+    Plain,
+    /// Red hearts and diamonds.
+    Classic,
+    /// Red hearts, blue diamonds and green clubs.
+    FourColour,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Plain, Theme::Classic, Theme::FourColour];
+
+    pub const fn code(self) -> &'static str {
+        match self {
+            Theme::Plain => "plain",
+            Theme::Classic => "classic",
+            Theme::FourColour => "four-colour",
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct UnknownTheme(pub String);
+
+impl Display for UnknownTheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let codes = Theme::ALL.iter().map(|theme| theme.code()).join(", ");
+        write!(f, "Unknown theme '{}', expected one of {codes}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownTheme {}
+
+impl FromStr for Theme {
+    type Err = UnknownTheme;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "four-color" | "four" | "4" => Ok(Theme::FourColour),
+            code => Theme::ALL
+                .into_iter()
+                .find(|theme| theme.code() == code)
+                .ok_or_else(|| UnknownTheme(text.to_owned())),
+        }
+    }
+}
+
+/// How to render messages for a text client.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RenderOptions {
+    pub language: Language,
+    pub profile: Profile,
+    pub theme: Theme,
+}
+
+impl RenderOptions {
+    pub const fn apply(&mut self, setting: Setting) {
+        match setting {
+            Setting::Language(language) => self.language = language,
+            Setting::Profile(profile) => self.profile = profile,
+            Setting::Theme(theme) => self.theme = theme,
+        }
+    }
+}
+
+/// A change to the render options of a text client.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Setting {
+    Language(Language),
+    Profile(Profile),
+    Theme(Theme),
+}
+
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub enum InvalidSetting {
+    Language(UnknownLanguage),
+    Profile(UnknownProfile),
+    Theme(UnknownTheme),
+}
+
+impl Setting {
+    /// Returns `None` if there is no setting called `name`.
+    pub fn parse(name: &str, value: &str) -> Option<Result<Setting, InvalidSetting>> {
+        let setting = match name {
+            "language" => value
+                .parse()
+                .map(Setting::Language)
+                .map_err(InvalidSetting::Language),
+            "profile" => value
+                .parse()
+                .map(Setting::Profile)
+                .map_err(InvalidSetting::Profile),
+            "theme" => value
+                .parse()
+                .map(Setting::Theme)
+                .map_err(InvalidSetting::Theme),
+            _ => return None,
+        };
+
+        Some(setting)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_setting() {
+        assert_eq!(
+            Setting::parse("profile", "ASCII"),
+            Some(Ok(Setting::Profile(Profile::Ascii)))
+        );
+        assert_eq!(
+            Setting::parse("language", "sv"),
+            Some(Ok(Setting::Language(Language::Swedish)))
+        );
+        assert_eq!(
+            Setting::parse("profile", "vt100"),
+            Some(Err(InvalidSetting::Profile(UnknownProfile(
+                "vt100".to_owned()
+            ))))
+        );
+        assert_eq!(
+            Setting::parse("theme", "four-color"),
+            Some(Ok(Setting::Theme(Theme::FourColour)))
+        );
+        assert_eq!(
+            Setting::parse("theme", "Classic"),
+            Some(Ok(Setting::Theme(Theme::Classic)))
+        );
+        assert_eq!(Setting::parse("volume", "11"), None);
+    }
+}
@@ -1,13 +1,21 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
 
-use playing_cards::structs::Card;
+use playing_cards::{
+    notation::{parse_cards, ParseCardError},
+    structs::{Card, Suit},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Debug)]
 pub struct PlayerName(pub String);
 
 impl PlayerName {
-    pub fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &str {
         self.0.as_str()
     }
 }
@@ -19,20 +27,160 @@ pub struct Player {
     pub hand: Vec<Card>,
 }
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct Trick(pub Vec<Card>);
+impl Player {
+    pub fn public(&self) -> PublicPlayer {
+        PublicPlayer {
+            name: self.name.clone(),
+            human: self.human,
+        }
+    }
+}
+
+/// The parts of a [`Player`] that every client may see.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PublicPlayer {
+    pub name: PlayerName,
+    pub human: bool,
+}
+
+/// A player in the waiting room before a game.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct LobbyPlayer {
+    pub name: PlayerName,
+    pub ready: bool,
+}
+
+/// How a player has done over the games played at the table so far.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct SeriesScore {
+    pub name: PlayerName,
+    pub played: u32,
+    pub won: u32,
+}
+
+/// A card on the table and who played it.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Play {
+    pub player: PlayerName,
+    pub card: Card,
+}
+
+/// The cards on the table in play order. `led_suit` and `winning` are kept up
+/// to date by [`Trick::play`], and sent along with the plays.
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+#[serde(try_from = "ReceivedTrick")]
+pub struct Trick {
+    plays: Vec<Play>,
+    /// The suit of the first card that isn't a joker.
+    led_suit: Option<Suit>,
+    /// The index in `plays` of the card that wins the trick so far.
+    winning: Option<usize>,
+}
+
+/// A [`Trick`] as it is received, before the led suit and the winning card
+/// are checked against the plays.
+#[derive(Deserialize)]
+#[serde(rename = "Trick")]
+struct ReceivedTrick {
+    plays: Vec<Play>,
+    led_suit: Option<Suit>,
+    winning: Option<usize>,
+}
+
+/// A received trick whose led suit or winning card don't follow from its
+/// plays.
+#[derive(Clone, Copy, Debug)]
+pub struct InconsistentTrick;
+
+impl Display for InconsistentTrick {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the led suit or the winning card don't match the plays")
+    }
+}
+
+impl std::error::Error for InconsistentTrick {}
+
+impl TryFrom<ReceivedTrick> for Trick {
+    type Error = InconsistentTrick;
+
+    fn try_from(received: ReceivedTrick) -> Result<Self, Self::Error> {
+        let mut trick = Trick::new();
+        for Play { player, card } in received.plays {
+            trick.play(player, card);
+        }
+        if trick.led_suit != received.led_suit || trick.winning != received.winning {
+            return Err(InconsistentTrick);
+        }
+        Ok(trick)
+    }
+}
+
+/// Parses the cards of a trick in play order, such as `AH KH 2C`. The
+/// players are named after their seat, from `0`.
+impl FromStr for Trick {
+    type Err = ParseCardError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut trick = Trick::new();
+        for (seat, card) in parse_cards(text)?.into_iter().enumerate() {
+            trick.play(PlayerName(seat.to_string()), card);
+        }
+        Ok(trick)
+    }
+}
 
 impl Trick {
     pub const fn new() -> Self {
-        Self(Vec::new())
+        Self {
+            plays: Vec::new(),
+            led_suit: None,
+            winning: None,
+        }
     }
-}
 
-impl Default for Trick {
-    fn default() -> Self {
-        Self::new()
+    pub fn plays(&self) -> &[Play] {
+        &self.plays
+    }
+
+    pub const fn led_suit(&self) -> Option<Suit> {
+        self.led_suit
+    }
+
+    /// The index in [`Trick::plays`] of the card that wins the trick so far.
+    pub const fn winning(&self) -> Option<usize> {
+        self.winning
+    }
+
+    pub fn cards(&self) -> impl Iterator<Item = Card> + '_ {
+        self.plays.iter().map(|play| play.card)
+    }
+
+    pub fn winner(&self) -> Option<&Play> {
+        self.winning.map(|index| &self.plays[index])
+    }
+
+    /// Jokers beat every other card, otherwise the highest card of the led
+    /// suit wins. When several cards are equally good, which happens with
+    /// jokers and with more than one deck, the one played first wins.
+    pub fn play(&mut self, player: PlayerName, card: Card) {
+        self.plays.push(Play { player, card });
+
+        if self.led_suit.is_none() {
+            self.led_suit = card.suit();
+        }
+
+        let led_suit = self.led_suit;
+        self.winning = self
+            .cards()
+            .enumerate()
+            .max_by_key(|(index, c)| {
+                let following = c.value().filter(|_| c.suit() == led_suit);
+                (c.is_joker(), following, Reverse(*index))
+            })
+            .map(|(index, _)| index);
     }
 }
+
 #[derive(Clone, Copy, Serialize, Deserialize, Debug)]
 pub struct PublicState {
     pub guess: Option<usize>,
@@ -41,3 +189,163 @@ pub struct PublicState {
 }
 
 pub type StatePerPlayer = HashMap<PlayerName, PublicState>;
+
+/// How one player did in one round.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct RoundScore {
+    pub guess: usize,
+    pub wins: usize,
+    pub points: usize,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ScoreSheetRound {
+    pub hand_size: usize,
+    /// In the same order as [`ScoreSheet::players`].
+    pub scores: Vec<RoundScore>,
+}
+
+/// Every round played so far, with the players in seating order.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ScoreSheet {
+    pub players: Vec<PlayerName>,
+    pub rounds: Vec<ScoreSheetRound>,
+}
+
+impl ScoreSheet {
+    pub const fn new(players: Vec<PlayerName>) -> Self {
+        Self {
+            players,
+            rounds: Vec::new(),
+        }
+    }
+
+    /// The points of each player over all rounds.
+    pub fn totals(&self) -> Vec<usize> {
+        (0..self.players.len())
+            .map(|index| {
+                self.rounds
+                    .iter()
+                    .map(|round| round.scores[index].points)
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    NotANumber(String),
+    GuessTooHigh { guess: usize, hand_size: usize },
+    ForbiddenGuess { guess: usize, hand_size: usize },
+    NoSuchCard { index: usize, hand_size: usize },
+    MustFollowSuit { suit: Suit },
+    NotACard(String),
+    CardNotInHand(Card),
+    ChatTooLong { length: usize, max: usize },
+    UnknownPlayer(PlayerName),
+    NotHost(PlayerName),
+    NotReady(Vec<PlayerName>),
+    TooFewPlayers { min: usize },
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Text,
+    Json,
+    Binary,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Capability {
+    /// Rejected answers are followed by a `Rejected` message with the reason.
+    Rejections,
+    /// Requests for a guess or a card list the legal ones.
+    LegalActions,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum HandshakeRejection {
+    UnsupportedVersion { requested: u32, supported: u32 },
+    Malformed(String),
+}
+
+/// Why the server asks for another name.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum NameRejection {
+    Empty,
+    TooLong { max: usize },
+    InvalidCharacter(char),
+    Taken(PlayerName),
+}
+
+/// Why the server closed a connection that sent something it won't read.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum Disconnect {
+    LineTooLong {
+        max: usize,
+    },
+    InvalidUtf8,
+    Malformed(String),
+    /// Nothing was sent in time during the handshake, or in the vote on
+    /// another game.
+    Idle,
+    /// The table is full, or the game has started.
+    NoSeat,
+}
+
+impl std::error::Error for Disconnect {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn trick(text: &str) -> Trick {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_trick_winner() {
+        assert_eq!(trick("").winner(), None);
+        assert_eq!(trick("3H QC 9H").winning(), Some(2));
+        assert_eq!(trick("3C QC 9H").winning(), Some(1));
+        assert_eq!(trick("3S QC 9H").winning(), Some(0));
+        assert_eq!(trick("3H QH KH").winning(), Some(2));
+        assert_eq!(
+            trick("3H QC 9H").winner().map(|play| play.player.as_str()),
+            Some("2")
+        );
+    }
+
+    #[test]
+    fn test_trick_winner_with_jokers_and_duplicates() {
+        assert_eq!(trick("3H AH KH AH").winning(), Some(1));
+        assert_eq!(trick("3H AH JKB 2C").winning(), Some(2));
+        assert_eq!(trick("3H JKR JKB").winning(), Some(1));
+        assert_eq!(trick("JKB 2C 5C 3H").winning(), Some(0));
+        assert_eq!(trick("JKB 2C 5C 3H").led_suit(), Some(Suit::Clubs));
+        assert_eq!(trick("JKB JKR").led_suit(), None);
+    }
+
+    #[test]
+    fn test_parse_trick() {
+        let trick = trick("3H QC");
+        assert_eq!(trick.plays().len(), 2);
+        assert_eq!(trick.plays()[1].player.as_str(), "1");
+        assert_eq!(trick.led_suit(), Some(Suit::Hearts));
+        assert!("3H XX".parse::<Trick>().is_err());
+    }
+
+    #[test]
+    fn test_trick_serde() {
+        let json = serde_json::to_string(&trick("JKB 2C 5C")).unwrap();
+        assert!(json.ends_with(r#""led_suit":1,"winning":0}"#), "{json}");
+
+        let received: Trick = serde_json::from_str(&json).unwrap();
+        assert_eq!(received.led_suit(), Some(Suit::Clubs));
+        assert_eq!(received.winning(), Some(0));
+
+        let forged = json.replace(r#""winning":0"#, r#""winning":2"#);
+        assert!(serde_json::from_str::<Trick>(&forged).is_err());
+    }
+}
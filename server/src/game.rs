@@ -1,17 +1,21 @@
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use playing_cards::{
-    helpers::{create_deck, draw_hand},
-    structs::Card,
+    card_set::CardSet,
+    helpers::DeckBuilder,
+    structs::{Card, Rank, Suit},
 };
-use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{seq::SliceRandom, Rng};
 use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet, VecDeque},
+    cmp::{Ordering, Reverse},
+    collections::{BTreeSet, HashMap, VecDeque},
 };
 
 use protocol::{
     message::Message,
-    structs::{Player, PlayerName, PublicState, StatePerPlayer, Trick},
+    structs::{
+        Player, PlayerName, PublicPlayer, PublicState, Rejection, RoundScore, ScoreSheet,
+        ScoreSheetRound, SeriesScore, StatePerPlayer, Trick,
+    },
 };
 
 pub fn create_players(player_names: Vec<(String, bool)>) -> VecDeque<Player> {
@@ -27,20 +31,93 @@ pub fn create_players(player_names: Vec<(String, bool)>) -> VecDeque<Player> {
 }
 
 pub trait Communicator {
-    fn read(&mut self, player: &PlayerName, prompt: Message) -> String;
+    /// Returns `None` once `player` has left the table.
+    fn read(&mut self, player: &PlayerName, prompt: Message) -> Option<String>;
     fn write_to_all(&mut self, text: Message);
     fn write_to_one(&mut self, player: &PlayerName, text: Message);
     fn wait_for_reconnect(&mut self, player: &str);
+    /// Tells apart the players who have had the same name at the table, as a
+    /// name goes to whoever claims it once its player has left. Bots have
+    /// none, and are told apart by their names.
+    fn session(&self, player: &PlayerName) -> Option<u64>;
+    /// Asks the players whether to play again once a game is over. Returns
+    /// who sits at the next table, or `None` if everyone has left.
+    fn rematch(&mut self) -> Option<Vec<(String, bool)>>;
 }
 
-pub async fn game<C>(communicator: &mut C, players: VecDeque<Player>, num_rounds: usize, ai: bool)
+/// The games played and won by everyone who has sat at the table, with the
+/// most wins first.
+#[derive(Default)]
+struct Series {
+    games: u32,
+    /// Each score with the session of its player, see
+    /// [`Communicator::session`].
+    scores: Vec<(Option<u64>, SeriesScore)>,
+}
+
+impl Series {
+    /// Counts a game for `players`, with `sessions` in the same order.
+    fn record(
+        &mut self,
+        players: &VecDeque<Player>,
+        sessions: &[Option<u64>],
+        winner_indices: &[usize],
+    ) {
+        self.games += 1;
+        for (index, (player, session)) in players.iter().zip(sessions).enumerate() {
+            let position = self
+                .scores
+                .iter()
+                .position(|(other, score)| other == session && score.name == player.name)
+                .unwrap_or_else(|| {
+                    let score = SeriesScore {
+                        name: player.name.clone(),
+                        played: 0,
+                        won: 0,
+                    };
+                    self.scores.push((*session, score));
+                    self.scores.len() - 1
+                });
+            let (_, score) = &mut self.scores[position];
+            score.played += 1;
+            score.won += u32::from(winner_indices.contains(&index));
+        }
+        // Stable, so that players with as many wins keep their order.
+        self.scores.sort_by_key(|(_, score)| Reverse(score.won));
+    }
+
+    fn message(&self) -> Message {
+        Message::Series {
+            games: self.games,
+            scores: self.scores.iter().map(|(_, score)| score.clone()).collect(),
+        }
+    }
+}
+
+/// The largest hand in a game, limited by how many cards there are to go
+/// around. Returns `None` if there isn't a card for every player.
+pub fn num_rounds(deck_size: usize, num_players: usize) -> Option<usize> {
+    const MAX_ROUNDS: usize = 10;
+
+    deck_size
+        .checked_div(num_players)
+        .filter(|rounds| *rounds > 0)
+        .map(|rounds| rounds.min(MAX_ROUNDS))
+}
+
+/// Plays games at the table until everyone has left, or with `ai`, until
+/// 100 games have been played.
+pub async fn game<C>(communicator: &mut C, mut table: VecDeque<Player>, deck: DeckBuilder, ai: bool)
 where
     C: Communicator,
 {
-    let mut games = 0;
+    let mut series = Series::default();
     let game_count = if ai { 100 } else { u32::MAX };
     loop {
-        let mut players = players.clone();
+        // The arguments are checked for a full table, and fewer players
+        // only get more cards each.
+        let num_rounds = num_rounds(deck.size(), table.len()).expect("Too few cards");
+        let mut players = table.clone();
         players.make_contiguous().shuffle(&mut rand::thread_rng());
         let mut down_sets: Vec<usize> = (1..=num_rounds).rev().collect();
         let mut singles: Vec<usize> = (1..players.len()).map(|_| 1).collect();
@@ -50,6 +127,8 @@ where
         sets.append(&mut singles);
         sets.append(&mut up_sets);
 
+        let mut sheet = ScoreSheet::new(players.iter().map(|p| p.name.clone()).collect());
+
         let mut public_state = HashMap::new();
         for player in players.clone() {
             public_state.insert(
@@ -62,15 +141,22 @@ where
             );
         }
 
-        for set in sets {
+        let rounds = sets.len();
+        for (round, set) in (1..).zip(sets) {
+            communicator.write_to_all(Message::RoundStart {
+                round,
+                rounds,
+                hand_size: set,
+                // The first player to guess sits to the left of the dealer.
+                dealer: players.back().unwrap().public(),
+            });
             let mut players_in_set = players.clone();
-            let mut deck = create_deck();
+            let mut shoe = deck.shoe();
             let mut prev_guesses = vec![];
             for player in &mut players_in_set {
-                let hand;
-                (deck, hand) = draw_hand(deck, set);
+                let hand = shoe.draw_hand(set);
                 communicator.write_to_all(Message::Turn {
-                    whose: player.clone(),
+                    whose: player.public(),
                 });
                 let guess = if player.human {
                     request_guess(communicator, player, &hand, &prev_guesses, players.len())
@@ -94,50 +180,99 @@ where
             {
                 let mut trick = Trick::new();
                 for player in &mut players_in_set {
-                    let hand;
                     if player.human {
-                        (hand, trick) =
-                            play_human_card(communicator, player, player.hand.clone(), trick);
+                        play_human_card(communicator, player, &mut trick);
                     } else {
-                        (hand, trick) = play_card(player.hand.clone(), trick);
+                        play_card(player, &mut trick);
                     }
-                    player.hand = hand;
                     communicator.write_to_all(Message::Trick(trick.clone()));
                 }
-                let index = determine_winner(&trick);
+                let index = trick.winning().unwrap();
                 let winner = &players_in_set[index];
                 public_state.get_mut(&winner.name).unwrap().wins += 1;
                 communicator.write_to_all(Message::Scoreboard {
                     state: public_state.clone(),
                 });
-                communicator.write_to_all(Message::Winner(winner.clone()));
+                communicator.write_to_all(Message::Winner(winner.public()));
                 players_in_set.rotate_left(index);
             }
+            sheet.rounds.push(ScoreSheetRound {
+                hand_size: set,
+                scores: sheet
+                    .players
+                    .iter()
+                    .map(|name| {
+                        let state = public_state[name];
+                        RoundScore {
+                            guess: state.guess.unwrap_or_default(),
+                            wins: state.wins,
+                            points: round_points(&state),
+                        }
+                    })
+                    .collect(),
+            });
+            let (made, missed) = players.iter().partition_map(|player| {
+                let state = public_state[&player.name];
+                if round_points(&state) > 0 {
+                    Either::Left(player.public())
+                } else {
+                    Either::Right(player.public())
+                }
+            });
             for player in &players_in_set {
                 let player = public_state.get_mut(&player.name).unwrap();
                 *player = score_round(*player);
             }
+            communicator.write_to_all(Message::RoundEnd {
+                round,
+                made,
+                missed,
+            });
+            communicator.write_to_all(Message::ScoreSheet(sheet.clone()));
+            // Those who left during the round play as bots from now on.
+            for player in &mut players {
+                player.human &= players_in_set
+                    .iter()
+                    .any(|other| other.name == player.name && other.human);
+            }
             players.rotate_left(1);
         }
         let winners = determine_total_winners(&players, &public_state);
 
-        let players_vec: Vec<Player> = players.into_iter().collect_vec();
+        let players_vec: Vec<PublicPlayer> = players.iter().map(Player::public).collect_vec();
+        let sessions: Vec<_> = players
+            .iter()
+            .map(|player| communicator.session(&player.name))
+            .collect();
+        series.record(&players, &sessions, &winners);
         communicator.write_to_all(Message::Winners {
             players: players_vec,
             winner_indices: winners,
         });
-        games += 1;
-        if games == game_count {
+        communicator.write_to_all(series.message());
+        if series.games == game_count {
             communicator.write_to_all(Message::GameOver);
             return;
         }
+
+        let Some(seated) = communicator.rematch() else {
+            communicator.write_to_all(Message::GameOver);
+            return;
+        };
+        table = create_players(seated);
     }
 }
 
-fn make_guess(hand: &Vec<Card>, guesses: &Vec<usize>, players: usize) -> usize {
-    let mut guess = hand.iter().filter(|x| x.value >= 7).count();
-    if !validate_guess(hand.len(), guesses, players, guess) {
-        let new_guess = hand.iter().filter(|x| x.value >= 9).count();
+fn make_guess(hand: &[Card], guesses: &[usize], players: usize) -> usize {
+    let mut guess = hand
+        .iter()
+        .filter(|x| x.value().is_none_or(|value| value >= Rank::Nine))
+        .count();
+    if validate_guess(hand.len(), guesses, players, guess).is_err() {
+        let new_guess = hand
+            .iter()
+            .filter(|x| x.value().is_none_or(|value| value >= Rank::Jack))
+            .count();
         if new_guess == guess {
             guess += 1;
         } else {
@@ -147,47 +282,71 @@ fn make_guess(hand: &Vec<Card>, guesses: &Vec<usize>, players: usize) -> usize {
     guess
 }
 
-fn validate_guess(hand_size: usize, guesses: &Vec<usize>, players: usize, guess: usize) -> bool {
+fn validate_guess(
+    hand_size: usize,
+    guesses: &[usize],
+    players: usize,
+    guess: usize,
+) -> Result<(), Rejection> {
     if guess > hand_size {
-        return false;
+        return Err(Rejection::GuessTooHigh { guess, hand_size });
     }
 
     if guesses.len() == players - 1 && (guess + guesses.iter().sum::<usize>()) == hand_size {
-        return false;
+        return Err(Rejection::ForbiddenGuess { guess, hand_size });
     }
 
-    true
+    Ok(())
+}
+
+fn legal_guesses(hand_size: usize, guesses: &[usize], players: usize) -> BTreeSet<usize> {
+    (0..=hand_size)
+        .filter(|guess| validate_guess(hand_size, guesses, players, *guess).is_ok())
+        .collect()
 }
 
+/// Asks `player` for a guess, or guesses for them if they have left, after
+/// which they play as a bot.
 fn request_guess<C>(
     communicator: &mut C,
-    player: &Player,
-    hand: &Vec<Card>,
-    guesses: &Vec<usize>,
+    player: &mut Player,
+    hand: &[Card],
+    guesses: &[usize],
     players: usize,
 ) -> usize
 where
     C: Communicator,
 {
+    let legal_guesses = legal_guesses(hand.len(), guesses, players);
+
     communicator.write_to_one(
         &player.name,
         Message::RequestGuessContext {
             player: player.clone(),
-            hand: hand.clone(),
-            guesses: guesses.clone(),
+            hand: hand.to_vec(),
+            guesses: guesses.to_vec(),
             players,
+            legal_guesses: Some(legal_guesses.clone()),
         },
     );
 
     loop {
-        let text = communicator.read(&player.name, Message::RequestGuess);
-        let Ok(guess) = text.trim().parse() else {
-            continue;
+        let prompt = Message::RequestGuess {
+            legal_guesses: Some(legal_guesses.clone()),
+        };
+        let Some(text) = communicator.read(&player.name, prompt) else {
+            player.human = false;
+            return make_guess(hand, guesses, players);
+        };
+        let rejection = match text.trim().parse() {
+            Ok(guess) => match validate_guess(hand.len(), guesses, players, guess) {
+                Ok(()) => return guess,
+                Err(rejection) => rejection,
+            },
+            Err(_) => Rejection::NotANumber(text),
         };
 
-        if validate_guess(hand.len(), guesses, players, guess) {
-            return guess;
-        }
+        communicator.write_to_one(&player.name, Message::Rejected(rejection));
     }
 }
 
@@ -198,85 +357,105 @@ fn determine_start_player(guesses: &[usize]) -> usize {
         .unwrap()
 }
 
-fn play_human_card<C>(
-    communicator: &mut C,
-    player: &Player,
-    mut hand: Vec<Card>,
-    trick: Trick,
-) -> (Vec<Card>, Trick)
+/// Asks `player` for a card, or plays one for them if they have left, after
+/// which they play as a bot.
+fn play_human_card<C>(communicator: &mut C, player: &mut Player, trick: &mut Trick)
 where
     C: Communicator,
 {
-    let valid_cards = playable_card_indices(&hand, &trick);
+    let valid_cards = playable_card_indices(&player.hand, trick);
 
     communicator.write_to_all(Message::Turn {
-        whose: player.clone(),
+        whose: player.public(),
     });
 
     communicator.write_to_one(
         &player.name,
         Message::PlayRequestContext {
             player: player.clone(),
-            hand: hand.clone(),
+            hand: player.hand.clone(),
             trick: trick.clone(),
-            valid_cards: valid_cards.clone(),
+            valid_cards: Some(valid_cards.clone()),
         },
     );
 
-    let Trick(mut cards) = trick;
-
     loop {
-        let text = communicator.read(&player.name, Message::PlayRequest(player.clone()));
-
-        let index: usize = match text.trim().parse() {
-            Ok(value) => value,
-            Err(_) => continue,
+        let prompt = Message::PlayRequest {
+            player: player.clone(),
+            valid_cards: Some(valid_cards.clone()),
+        };
+        let Some(text) = communicator.read(&player.name, prompt) else {
+            player.human = false;
+            play_card(player, trick);
+            return;
         };
 
-        if index >= hand.len() {
-            continue;
-        }
+        let hand = &player.hand;
+        let choice = parse_card_choice(hand, &text)
+            .and_then(|index| validate_play(hand, trick, index).map(|()| index));
 
-        if let Some(valid) = valid_cards.as_ref() {
-            if !valid.contains(&index) {
+        let index = match choice {
+            Ok(index) => index,
+            Err(rejection) => {
+                communicator.write_to_one(&player.name, Message::Rejected(rejection));
                 continue;
             }
-        }
-
-        let card = hand[index];
-        hand.retain(|c| *c != card);
-        cards.push(card);
+        };
 
-        return (hand, Trick(cards.clone()));
+        trick.play(player.name.clone(), player.hand.remove(index));
+        return;
     }
 }
 
-fn play_card(mut hand: Vec<Card>, Trick(mut cards): Trick) -> (Vec<Card>, Trick) {
-    let card = hand.iter().choose(&mut rand::thread_rng()).copied();
+/// Accepts either the index of a card in the hand or the card itself, such
+/// as `AH` or `Ace of Hearts`.
+fn parse_card_choice(hand: &[Card], text: &str) -> Result<usize, Rejection> {
+    let text = text.trim();
 
-    if let Some(card) = card {
-        hand.retain(|c| *c != card);
-        cards.push(card);
+    if let Ok(index) = text.parse() {
+        return Ok(index);
     }
 
-    (hand, Trick(cards))
+    let card: Card = text
+        .parse()
+        .map_err(|_| Rejection::NotACard(text.to_owned()))?;
+
+    hand.iter()
+        .position(|c| *c == card)
+        .ok_or(Rejection::CardNotInHand(card))
 }
 
-fn determine_winner(Trick(cards): &Trick) -> usize {
-    let first_card = cards.first().unwrap();
-    let first_suit = first_card.suit;
+fn validate_play(hand: &[Card], trick: &Trick, index: usize) -> Result<(), Rejection> {
+    let Some(card) = hand.get(index) else {
+        return Err(Rejection::NoSuchCard {
+            index,
+            hand_size: hand.len(),
+        });
+    };
 
-    cards
-        .iter()
-        .position_max_by_key(|c| if c.suit == first_suit { c.value } else { 0 })
-        .unwrap()
+    match suit_to_follow(hand, trick) {
+        Some(suit) if !follows(*card, suit) => Err(Rejection::MustFollowSuit { suit }),
+        _ => Ok(()),
+    }
 }
 
-fn score_round(mut player: PublicState) -> PublicState {
-    if let Some(guess) = player.guess.filter(|g| *g == player.wins) {
-        player.score += (10 * guess).max(10);
+fn play_card(player: &mut Player, trick: &mut Trick) {
+    if !player.hand.is_empty() {
+        let index = rand::thread_rng().gen_range(0..player.hand.len());
+        trick.play(player.name.clone(), player.hand.remove(index));
     }
+}
+
+/// Points for making the guess exactly, nothing otherwise.
+fn round_points(player: &PublicState) -> usize {
+    player
+        .guess
+        .filter(|guess| *guess == player.wins)
+        .map_or(0, |guess| (10 * guess).max(10))
+}
 
+fn score_round(mut player: PublicState) -> PublicState {
+    player.score += round_points(&player);
     player.wins = 0;
     player
 }
@@ -302,24 +481,56 @@ fn determine_total_winners(players: &VecDeque<Player>, public: &StatePerPlayer)
     winners
 }
 
-fn playable_card_indices(hand: &[Card], Trick(cards): &Trick) -> Option<HashSet<usize>> {
-    let first_card = cards.first()?;
+/// The led suit, if `hand` holds any card of it. Hands stay a `Vec`, since a
+/// game with several decks can deal identical cards to the same player, so
+/// only the suits are looked up in a [`CardSet`].
+fn suit_to_follow(hand: &[Card], trick: &Trick) -> Option<Suit> {
+    let cards: CardSet = hand.iter().copied().collect();
+    trick
+        .led_suit()
+        .filter(|suit| !cards.suit(*suit).is_empty())
+}
 
-    let indices = hand
-        .iter()
-        .enumerate()
-        .filter_map(|(index, card)| (card.suit == first_card.suit).then_some(index))
-        .collect::<HashSet<_>>();
+/// Players must follow the led suit if they can, but may always play a
+/// joker instead.
+fn follows(card: Card, suit: Suit) -> bool {
+    card.suit() == Some(suit) || card.is_joker()
+}
 
-    (!indices.is_empty()).then_some(indices)
+fn playable_card_indices(hand: &[Card], trick: &Trick) -> BTreeSet<usize> {
+    let Some(suit) = suit_to_follow(hand, trick) else {
+        return (0..hand.len()).collect();
+    };
+
+    hand.iter()
+        .enumerate()
+        .filter_map(|(index, card)| follows(*card, suit).then_some(index))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use playing_cards::notation::parse_cards;
     use proptest::prelude::*;
     use protocol::structs::Trick;
 
+    fn cards(text: &str) -> Vec<Card> {
+        parse_cards(text).unwrap()
+    }
+
+    fn played(cards: Vec<Card>) -> Trick {
+        let mut trick = Trick::new();
+        for card in cards {
+            trick.play(PlayerName("Bob".to_owned()), card);
+        }
+        trick
+    }
+
+    fn trick_of(text: &str) -> Trick {
+        text.parse().unwrap()
+    }
+
     proptest! {
         #[test]
         fn test_create_players(names in any::<Vec<(String, bool)>>()) {
@@ -334,33 +545,258 @@ mod test {
 
     #[test]
     fn test_determine_winner() {
-        let trick = Trick(vec![
-            Card { suit: 0, value: 1 },
-            Card { suit: 1, value: 10 },
-            Card { suit: 0, value: 7 },
+        let trick = played(vec![
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Queen,
+            },
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Nine,
+            },
         ]);
-        let winner = determine_winner(&trick);
+        let winner = trick.winning().unwrap();
         assert_eq!(winner, 2);
-        let trick = Trick(vec![
-            Card { suit: 1, value: 1 },
-            Card { suit: 1, value: 10 },
-            Card { suit: 0, value: 7 },
+        let trick = played(vec![
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Queen,
+            },
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Nine,
+            },
         ]);
-        let winner = determine_winner(&trick);
+        let winner = trick.winning().unwrap();
         assert_eq!(winner, 1);
-        let trick = Trick(vec![
-            Card { suit: 3, value: 1 },
-            Card { suit: 1, value: 10 },
-            Card { suit: 0, value: 7 },
+        let trick = played(vec![
+            Card::Suited {
+                suit: Suit::Spades,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Queen,
+            },
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Nine,
+            },
         ]);
-        let winner = determine_winner(&trick);
+        let winner = trick.winning().unwrap();
         assert_eq!(winner, 0);
-        let trick = Trick(vec![
-            Card { suit: 0, value: 1 },
-            Card { suit: 0, value: 10 },
-            Card { suit: 0, value: 11 },
+        let trick = played(vec![
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Queen,
+            },
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::King,
+            },
         ]);
-        let winner = determine_winner(&trick);
+        let winner = trick.winning().unwrap();
         assert_eq!(winner, 2);
     }
+
+    #[test]
+    fn test_series() {
+        let table = |names: &[&str]| {
+            create_players(
+                names
+                    .iter()
+                    .map(|name| ((*name).to_owned(), true))
+                    .collect(),
+            )
+        };
+        let mut series = Series::default();
+        series.record(&table(&["Alice", "Bob"]), &[Some(0), Some(1)], &[1]);
+        series.record(
+            &table(&["Carol", "Alice", "Bob"]),
+            &[Some(2), Some(0), Some(1)],
+            &[0, 1],
+        );
+
+        let score = |name: &str, played, won| SeriesScore {
+            name: PlayerName(name.to_owned()),
+            played,
+            won,
+        };
+        let scores = |series: &Series| -> Vec<SeriesScore> {
+            series
+                .scores
+                .iter()
+                .map(|(_, score)| score.clone())
+                .collect()
+        };
+        assert_eq!(series.games, 2);
+        assert_eq!(
+            scores(&series),
+            [
+                score("Bob", 2, 1),
+                score("Alice", 2, 1),
+                score("Carol", 1, 1)
+            ]
+        );
+
+        // Someone new who takes the name of a player who left starts over,
+        // while bots are told apart by their names.
+        series.record(&table(&["Bob", "Bot1"]), &[Some(3), None], &[1]);
+        series.record(&table(&["Bob", "Bot1"]), &[Some(3), None], &[1]);
+        assert_eq!(
+            scores(&series),
+            [
+                score("Bot1", 2, 2),
+                score("Bob", 2, 1),
+                score("Alice", 2, 1),
+                score("Carol", 1, 1),
+                score("Bob", 2, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_num_rounds() {
+        assert_eq!(num_rounds(52, 4), Some(10));
+        assert_eq!(num_rounds(52, 6), Some(8));
+        assert_eq!(num_rounds(32, 4), Some(8));
+        assert_eq!(num_rounds(104, 10), Some(10));
+        assert_eq!(num_rounds(32, 40), None);
+        assert_eq!(num_rounds(52, 0), None);
+    }
+
+    #[test]
+    fn test_validate_guess() {
+        assert!(validate_guess(3, &[], 4, 3).is_ok());
+        assert!(matches!(
+            validate_guess(3, &[], 4, 4),
+            Err(Rejection::GuessTooHigh {
+                guess: 4,
+                hand_size: 3
+            })
+        ));
+        assert!(matches!(
+            validate_guess(3, &[1, 0, 1], 4, 1),
+            Err(Rejection::ForbiddenGuess {
+                guess: 1,
+                hand_size: 3
+            })
+        ));
+        assert!(validate_guess(3, &[1, 0, 1], 4, 2).is_ok());
+    }
+
+    #[test]
+    fn test_validate_play() {
+        let hand = [
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Queen,
+            },
+        ];
+        let trick = played(vec![Card::Suited {
+            suit: Suit::Clubs,
+            value: Rank::Five,
+        }]);
+
+        assert!(validate_play(&hand, &trick, 1).is_ok());
+        assert!(matches!(
+            validate_play(&hand, &trick, 0),
+            Err(Rejection::MustFollowSuit { suit: Suit::Clubs })
+        ));
+        assert!(matches!(
+            validate_play(&hand, &trick, 2),
+            Err(Rejection::NoSuchCard {
+                index: 2,
+                hand_size: 2
+            })
+        ));
+        assert!(validate_play(&hand, &Trick::new(), 0).is_ok());
+
+        let hand = cards("3H QC JKB");
+        assert!(validate_play(&hand, &trick, 2).is_ok());
+        assert!(validate_play(&hand, &trick_of("5D"), 0).is_ok());
+    }
+
+    #[test]
+    fn test_parse_card_choice() {
+        let hand = cards("3H QC");
+
+        assert_eq!(parse_card_choice(&hand, "1"), Ok(1));
+        assert_eq!(parse_card_choice(&hand, " 5\n"), Ok(5));
+        assert_eq!(parse_card_choice(&hand, "qc"), Ok(1));
+        assert_eq!(parse_card_choice(&hand, "Three of Hearts"), Ok(0));
+        assert_eq!(
+            parse_card_choice(&hand, "AS"),
+            Err(Rejection::CardNotInHand("AS".parse().unwrap()))
+        );
+        assert_eq!(
+            parse_card_choice(&hand, "x"),
+            Err(Rejection::NotACard("x".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_legal_guesses() {
+        assert_eq!(legal_guesses(2, &[], 3), BTreeSet::from([0, 1, 2]));
+        assert_eq!(legal_guesses(2, &[1, 0], 3), BTreeSet::from([0, 2]));
+        assert_eq!(legal_guesses(2, &[0, 0], 3), BTreeSet::from([0, 1]));
+    }
+
+    #[test]
+    fn test_playable_card_indices() {
+        let hand = [
+            Card::Suited {
+                suit: Suit::Hearts,
+                value: Rank::Three,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Queen,
+            },
+            Card::Suited {
+                suit: Suit::Clubs,
+                value: Rank::Ace,
+            },
+        ];
+        let all = BTreeSet::from([0, 1, 2]);
+
+        assert_eq!(playable_card_indices(&hand, &Trick::new()), all);
+        let trick = played(vec![Card::Suited {
+            suit: Suit::Clubs,
+            value: Rank::Five,
+        }]);
+        assert_eq!(playable_card_indices(&hand, &trick), BTreeSet::from([1, 2]));
+        let trick = played(vec![Card::Suited {
+            suit: Suit::Diamonds,
+            value: Rank::Five,
+        }]);
+        assert_eq!(playable_card_indices(&hand, &trick), all);
+
+        let hand = cards("3H QC JKB");
+        let trick = trick_of("5C");
+        assert_eq!(playable_card_indices(&hand, &trick), BTreeSet::from([1, 2]));
+        let trick = trick_of("JKR");
+        assert_eq!(
+            playable_card_indices(&hand, &trick),
+            BTreeSet::from([0, 1, 2])
+        );
+        let trick = trick_of("JKR 2H");
+        assert_eq!(playable_card_indices(&hand, &trick), BTreeSet::from([0, 2]));
+    }
 }
@@ -0,0 +1,227 @@
+use std::time::Instant;
+
+use protocol::{
+    format::{format_lobby_hint, format_rematch_hint},
+    message::Message,
+    options::RenderOptions,
+    structs::{LobbyPlayer, PlayerName, Rejection},
+};
+
+/// A game needs at least this many players, bots included.
+pub const MIN_PLAYERS: usize = 2;
+
+/// The players waiting for a game in the order they joined. The first one is
+/// the host.
+pub struct WaitingRoom {
+    seats: usize,
+    players: Vec<LobbyPlayer>,
+    /// Whether the players have just played a game, and vote on playing
+    /// another by getting ready.
+    rematch: bool,
+    /// The players of the last game who haven't voted yet.
+    undecided: Vec<PlayerName>,
+    /// When those who haven't voted are taken to have left.
+    vote_deadline: Option<Instant>,
+}
+
+impl WaitingRoom {
+    pub const fn new(seats: usize) -> WaitingRoom {
+        WaitingRoom {
+            seats,
+            players: Vec::new(),
+            rematch: false,
+            undecided: Vec::new(),
+            vote_deadline: None,
+        }
+    }
+
+    /// Seats the players of a game that is over, none of them ready yet,
+    /// who have until `vote_deadline` to vote on another.
+    pub fn after_game(
+        seats: usize,
+        players: Vec<PlayerName>,
+        vote_deadline: Instant,
+    ) -> WaitingRoom {
+        WaitingRoom {
+            seats,
+            players: players
+                .iter()
+                .map(|name| LobbyPlayer {
+                    name: name.clone(),
+                    ready: false,
+                })
+                .collect(),
+            rematch: true,
+            undecided: players,
+            vote_deadline: Some(vote_deadline),
+        }
+    }
+
+    /// When the players who haven't voted yet are taken to have left, if
+    /// anyone is still to vote.
+    pub fn vote_deadline(&self) -> Option<Instant> {
+        self.vote_deadline.filter(|_| !self.undecided.is_empty())
+    }
+
+    /// The players of the last game who haven't voted yet.
+    pub fn undecided(&self) -> &[PlayerName] {
+        &self.undecided
+    }
+
+    /// Whether everyone who played the last game has left.
+    pub const fn is_abandoned(&self) -> bool {
+        self.rematch && self.players.is_empty()
+    }
+
+    /// What text clients are told when they send anything but a command.
+    pub fn hint(&self) -> fn(RenderOptions) -> String {
+        if self.rematch {
+            format_rematch_hint
+        } else {
+            format_lobby_hint
+        }
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.players.len() >= self.seats
+    }
+
+    /// Whether the game can start without waiting for the host, which also
+    /// takes enough players to play.
+    pub fn is_full_and_ready(&self) -> bool {
+        self.is_full()
+            && self.players.len() >= MIN_PLAYERS
+            && self.players.iter().all(|player| player.ready)
+    }
+
+    pub fn join(&mut self, name: PlayerName) {
+        self.players.push(LobbyPlayer { name, ready: false });
+    }
+
+    /// Removes `name`, which makes the next player the host if it was the
+    /// host who left.
+    pub fn leave(&mut self, name: &PlayerName) {
+        self.players.retain(|player| &player.name != name);
+        self.undecided.retain(|player| player != name);
+    }
+
+    /// Getting ready, or not, counts as a vote after a game.
+    pub fn set_ready(&mut self, name: &PlayerName, ready: bool) {
+        self.undecided.retain(|player| player != name);
+        for player in &mut self.players {
+            if &player.name == name {
+                player.ready = ready;
+            }
+        }
+    }
+
+    /// Checks that `name` may start the game now, and returns how many bots
+    /// take the empty seats.
+    pub fn start(&self, name: &PlayerName, bots: bool) -> Result<usize, Rejection> {
+        let host = &self.players[0].name;
+        if host != name {
+            return Err(Rejection::NotHost(host.clone()));
+        }
+
+        let not_ready: Vec<PlayerName> = self
+            .players
+            .iter()
+            .filter(|player| !player.ready)
+            .map(|player| player.name.clone())
+            .collect();
+        if !not_ready.is_empty() {
+            return Err(Rejection::NotReady(not_ready));
+        }
+
+        let bots = if bots {
+            self.seats - self.players.len()
+        } else {
+            0
+        };
+        if self.players.len() + bots < MIN_PLAYERS {
+            return Err(Rejection::TooFewPlayers { min: MIN_PLAYERS });
+        }
+
+        Ok(bots)
+    }
+
+    pub fn message(&self) -> Message {
+        Message::Lobby {
+            players: self.players.clone(),
+            seats: self.seats,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn name(name: &str) -> PlayerName {
+        PlayerName(name.to_owned())
+    }
+
+    #[test]
+    fn test_start() {
+        let mut room = WaitingRoom::new(3);
+        room.join(name("Alice"));
+        room.set_ready(&name("Alice"), true);
+        assert_eq!(
+            room.start(&name("Alice"), false),
+            Err(Rejection::TooFewPlayers { min: MIN_PLAYERS })
+        );
+        assert_eq!(room.start(&name("Alice"), true), Ok(2));
+
+        room.join(name("Bob"));
+        assert_eq!(
+            room.start(&name("Bob"), false),
+            Err(Rejection::NotHost(name("Alice")))
+        );
+        assert_eq!(
+            room.start(&name("Alice"), false),
+            Err(Rejection::NotReady(vec![name("Bob")]))
+        );
+
+        room.set_ready(&name("Bob"), true);
+        assert_eq!(room.start(&name("Alice"), false), Ok(0));
+        assert_eq!(room.start(&name("Alice"), true), Ok(1));
+
+        room.join(name("Carol"));
+        assert!(room.is_full());
+        assert!(!room.is_full_and_ready());
+        room.set_ready(&name("Carol"), true);
+        assert!(room.is_full_and_ready());
+        room.leave(&name("Alice"));
+        assert!(!room.is_full());
+        assert_eq!(room.start(&name("Bob"), false), Ok(0));
+
+        let mut room = WaitingRoom::new(1);
+        room.join(name("Alice"));
+        room.set_ready(&name("Alice"), true);
+        assert!(!room.is_full_and_ready());
+    }
+
+    #[test]
+    fn test_after_game() {
+        let deadline = Instant::now();
+        let mut room = WaitingRoom::after_game(3, vec![name("Alice"), name("Bob")], deadline);
+        assert_eq!(
+            room.start(&name("Alice"), true),
+            Err(Rejection::NotReady(vec![name("Alice"), name("Bob")]))
+        );
+
+        room.set_ready(&name("Alice"), false);
+        assert_eq!(room.undecided(), [name("Bob")]);
+        assert_eq!(room.vote_deadline(), Some(deadline));
+
+        room.leave(&name("Alice"));
+        room.set_ready(&name("Bob"), true);
+        assert_eq!(room.vote_deadline(), None);
+        assert_eq!(room.start(&name("Bob"), true), Ok(2));
+
+        assert!(!room.is_abandoned());
+        room.leave(&name("Bob"));
+        assert!(room.is_abandoned());
+        assert!(!WaitingRoom::new(3).is_abandoned());
+    }
+}
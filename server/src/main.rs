@@ -1,19 +1,33 @@
 mod game;
+mod lobby;
 mod network;
 
-use futures::future::join_all;
-
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap, VecDeque},
+    fmt::{Display, Formatter, Result as FmtResult},
     io::Result as IoResult,
     net::{Shutdown, SocketAddr, TcpListener, TcpStream},
     str::FromStr,
-    sync::mpsc::{channel, Receiver, Sender},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{channel, Receiver, Sender},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
 };
 
-use clap::Parser;
-use game::{create_players, game, Communicator};
-use protocol::{message::Message, structs::PlayerName};
+use clap::{error::ErrorKind, CommandFactory, Parser};
+use game::{create_players, game, num_rounds, Communicator};
+use lobby::{WaitingRoom, MIN_PLAYERS};
+use network::{clean_chat, Command, Input, InputReader, Names, Rejoins, Table};
+use playing_cards::helpers::{DeckBuilder, DeckKind};
+use protocol::{
+    format::{format_lobby_hint, format_muted, format_not_your_turn, format_rematch_hint},
+    message::Message,
+    options::RenderOptions,
+    structs::{Disconnect, PlayerName, Rejection},
+};
 
 #[cfg(windows)]
 fn enable_colors() {
@@ -38,95 +52,665 @@ fn enable_colors() {
     }
 }
 
-struct ReconnectRequest {
-    player: String,
-    return_channel: Sender<TcpStream>,
+/// Everything the server waits for: new connections, the handshakes started
+/// on them, which run side by side so that a slow client holds up nobody
+/// else, and what the players send.
+enum Event {
+    Connected(TcpStream),
+    Joined(IoResult<Box<(network::Client, InputReader, String)>>),
+    Input(Connection, IoResult<Input>),
 }
 
+/// Accepts connections for as long as the server runs, first for the waiting
+/// room and then for players who reconnect.
+fn spawn_acceptor(listener: TcpListener, events: Sender<Event>) {
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            if let Err(error) = stream.set_nodelay(true) {
+                println!("Dropping a connection: {error}");
+                continue;
+            }
+            if events.send(Event::Connected(stream)).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+/// Connections that join at the same time beyond this many are closed at
+/// once, as each handshake takes a thread.
+const MAX_HANDSHAKES: usize = 32;
+
+/// Identifies the connection an input was read from, so that what is left
+/// of an old connection can be told apart after a player reconnects.
+type Connection = (String, u64);
+
 struct CommunicatorImpl {
     sockets: HashMap<String, network::Client>,
-    reconnect: Sender<ReconnectRequest>,
-    return_sender: Sender<TcpStream>,
-    return_receiver: Receiver<TcpStream>,
+    /// The players in the order they joined.
+    players: Vec<PlayerName>,
+    connections: HashMap<String, u64>,
+    next_connection: u64,
+    /// The first connection of each player, which tells them apart from
+    /// anyone who has had their name before.
+    sessions: HashMap<String, u64>,
+    /// The players whose chat each player doesn't want to see.
+    mutes: HashMap<String, BTreeSet<PlayerName>>,
+    events: Receiver<Event>,
+    event_sender: Sender<Event>,
+    /// Events put aside while waiting for a player to reconnect.
+    pending: VecDeque<Event>,
+    names: Names,
+    /// The token each player takes their seat back with after losing the
+    /// connection.
+    tokens: HashMap<String, String>,
+    rejoins: Rejoins,
+    /// How long a player who lost the connection has to join again.
+    reconnect_timeout: Duration,
+    handshake_timeout: Duration,
+    /// How many handshakes are running.
+    handshakes: Arc<AtomicUsize>,
+    /// How long the players have to vote on another game.
+    vote_timeout: Duration,
+    seats: usize,
+    /// Where the players wait until the game starts.
+    lobby: Option<WaitingRoom>,
+    lobby_changed: bool,
+}
+
+impl CommunicatorImpl {
+    fn next_event(&mut self) -> Event {
+        self.pending
+            .pop_front()
+            .unwrap_or_else(|| self.events.recv().unwrap())
+    }
+
+    /// Like [`Self::next_event`], but gives up at `deadline`, if there is one.
+    fn next_event_until(&mut self, deadline: Option<Instant>) -> Option<Event> {
+        let Some(deadline) = deadline else {
+            return Some(self.next_event());
+        };
+        self.pending.pop_front().or_else(|| {
+            let left = deadline.saturating_duration_since(Instant::now());
+            self.events.recv_timeout(left).ok()
+        })
+    }
+
+    /// Starts reading the current connection of `player` with `reader`.
+    fn listen(&mut self, player: &str, reader: IoResult<InputReader>) {
+        let connection = (player.to_owned(), self.next_connection);
+        self.next_connection += 1;
+        self.connections.insert(connection.0.clone(), connection.1);
+
+        match reader {
+            Ok(reader) => reader.spawn(self.event_sender.clone(), move |input| {
+                Event::Input(connection.clone(), input)
+            }),
+            Err(error) => _ = self.event_sender.send(Event::Input(connection, Err(error))),
+        }
+    }
+
+    /// Seats players as they join, until the host starts the game or every
+    /// seat is taken by a player who is ready. Returns the names of the
+    /// players, and whether each of them is human, or `None` if everyone
+    /// left after a game.
+    fn gather(&mut self) -> Option<Vec<(String, bool)>> {
+        let mut bots = None;
+        loop {
+            while std::mem::take(&mut self.lobby_changed) {
+                let message = self.room().message();
+                self.write_to_all(message);
+            }
+            if self.room().is_abandoned() {
+                self.lobby = None;
+                return None;
+            }
+            if self.room().is_full_and_ready() {
+                bots = bots.or(Some(0));
+            }
+            if let Some(bots) = bots {
+                return Some(self.start(bots));
+            }
+
+            let deadline = self.room().vote_deadline();
+            let Some(event) = self.next_event_until(deadline) else {
+                self.end_vote();
+                continue;
+            };
+            bots = match event {
+                Event::Connected(stream) => {
+                    self.handshake(stream);
+                    None
+                }
+                Event::Joined(Ok(joined)) => {
+                    self.seat(*joined);
+                    None
+                }
+                Event::Joined(Err(error)) => {
+                    println!("Handshake failed: {error}");
+                    None
+                }
+                Event::Input((player, connection), input) => {
+                    if self.connections.get(&player) == Some(&connection) {
+                        self.lobby_input(&player, input)
+                    } else {
+                        None
+                    }
+                }
+            };
+        }
+    }
+
+    /// Takes those who haven't voted on another game in time to have left.
+    fn end_vote(&mut self) {
+        for player in self.room().undecided().to_vec() {
+            println!("Player {} didn't vote in time", player.as_str());
+            self.write_to_one(&player, Message::Disconnected(Disconnect::Idle));
+            self.leave(player.as_str());
+        }
+    }
+
+    const fn room(&mut self) -> &mut WaitingRoom {
+        self.lobby.as_mut().expect("The game has already started")
+    }
+
+    fn handshake(&self, stream: TcpStream) {
+        if self.handshakes.load(Ordering::Relaxed) >= MAX_HANDSHAKES {
+            println!("Too many connections joining at once, closing one");
+            _ = stream.shutdown(Shutdown::Both);
+            return;
+        }
+        self.handshakes.fetch_add(1, Ordering::Relaxed);
+
+        let joined = self.event_sender.clone();
+        let names = self.names.clone();
+        let rejoins = self.rejoins.clone();
+        let timeout = self.handshake_timeout;
+        let handshakes = Arc::clone(&self.handshakes);
+        thread::spawn(move || {
+            let accepted = network::Client::accept(stream, timeout, &names, &rejoins).map(Box::new);
+            handshakes.fetch_sub(1, Ordering::Relaxed);
+            _ = joined.send(Event::Joined(accepted));
+        });
+    }
+
+    fn seat(&mut self, (client, reader, name): (network::Client, InputReader, String)) {
+        if self.room().is_full() {
+            self.turn_away(client, &name);
+            return;
+        }
+
+        println!("Player {name} has joined");
+        let player = PlayerName(name.clone());
+        self.sockets.insert(name.clone(), client);
+        self.players.push(player.clone());
+        self.room().join(player.clone());
+        self.sessions.insert(name.clone(), self.next_connection);
+        let token = format!("{:016x}", rand::random::<u64>());
+        self.tokens.insert(name.clone(), token.clone());
+        self.listen(&name, Ok(reader));
+        self.write_to_one(&player, Message::RejoinToken(token));
+        self.notify(&name, format_lobby_hint);
+        self.lobby_changed = true;
+    }
+
+    /// Tells a client that finished its handshake too late that there is no
+    /// seat left for it, and closes the connection. The name stays taken if
+    /// it is a player's who rejoined too late.
+    fn turn_away(&self, mut client: network::Client, name: &str) {
+        _ = client.send(Message::Disconnected(Disconnect::NoSeat));
+        if !self.players.iter().any(|player| player.as_str() == name) {
+            self.names.release(name);
+        }
+    }
+
+    /// Handles what `player` sends in the waiting room, and returns how many
+    /// bots to seat if it starts the game.
+    fn lobby_input(&mut self, player: &str, input: IoResult<Input>) -> Option<usize> {
+        let name = PlayerName(player.to_owned());
+        match input {
+            Ok(Input::Command(Command::Ready(ready))) => {
+                self.room().set_ready(&name, ready);
+                self.lobby_changed = true;
+            }
+            Ok(Input::Command(Command::Start { bots })) => match self.room().start(&name, bots) {
+                Ok(bots) => return Some(bots),
+                Err(rejection) => self.write_to_one(&name, Message::Rejected(rejection)),
+            },
+            Ok(Input::Command(Command::Quit)) => {
+                self.answer(player, Command::Quit, None);
+                self.leave(player);
+            }
+            Ok(Input::Command(command)) => _ = self.answer(player, command, None),
+            Ok(Input::Line(_)) => {
+                let hint = self.room().hint();
+                self.notify(player, hint);
+            }
+            Err(error) => {
+                if let Some(client) = self.sockets.get_mut(player) {
+                    client.close_if_abusive(&error);
+                }
+                self.leave(player);
+            }
+        }
+        None
+    }
+
+    /// Gives a player who left the waiting room up their seat and name.
+    fn leave(&mut self, player: &str) {
+        let Some(mut client) = self.sockets.remove(player) else {
+            return;
+        };
+        println!("Player {player} has left");
+        _ = client.socket_mut().shutdown(Shutdown::Both);
+        let name = PlayerName(player.to_owned());
+        self.players.retain(|other| other != &name);
+        self.connections.remove(player);
+        self.mutes.remove(player);
+        self.sessions.remove(player);
+        self.tokens.remove(player);
+        self.names.release(player);
+        self.room().leave(&name);
+        self.lobby_changed = true;
+    }
+
+    /// Lets `player` leave during a game without waiting for them to come
+    /// back. Their seat plays on as a bot and keeps their name until the game
+    /// is over.
+    fn quit(&mut self, player: &str) {
+        let Some(mut client) = self.sockets.remove(player) else {
+            return;
+        };
+        println!("Player {player} has left, a bot takes the seat");
+        let table = Table {
+            players: &self.players,
+            waiting_for: None,
+        };
+        // Closes the connection, whether the goodbye gets through or not.
+        if client.answer(Command::Quit, table).is_err() {
+            _ = client.socket_mut().shutdown(Shutdown::Both);
+        }
+        self.connections.remove(player);
+        self.mutes.remove(player);
+        self.tokens.remove(player);
+    }
+
+    /// Closes the waiting room and fills the empty seats with `bots`.
+    fn start(&mut self, bots: usize) -> Vec<(String, bool)> {
+        self.lobby = None;
+        let mut seated: Vec<(String, bool)> = self
+            .players
+            .iter()
+            .map(|player| (player.as_str().to_owned(), true))
+            .collect();
+        for _ in 0..bots {
+            let bot = self.names.claim_bot();
+            self.players.push(PlayerName(bot.clone()));
+            seated.push((bot, false));
+        }
+        println!("Starting a game with {} players", seated.len());
+        seated
+    }
+
+    /// Replies to a command from `player` while waiting for `waiting_for`.
+    /// Returns whether anything was sent to `waiting_for`.
+    fn answer(&mut self, player: &str, command: Command, waiting_for: Option<&PlayerName>) -> bool {
+        match command {
+            Command::Chat { to, text } => return self.chat(player, to, &text, waiting_for),
+            Command::Mute(target) => self.mute(player, target, true),
+            Command::Unmute(target) => self.mute(player, target, false),
+            command => {
+                let table = Table {
+                    players: &self.players,
+                    waiting_for,
+                };
+                let Some(client) = self.sockets.get_mut(player) else {
+                    return false;
+                };
+                if client.answer(command, table).is_err() {
+                    self.wait_for_reconnect(player);
+                }
+            }
+        }
+
+        waiting_for.is_some_and(|waiting_for| player == waiting_for.as_str())
+    }
+
+    /// Sends a text-only reply to `player`.
+    fn notify(&mut self, player: &str, reply: impl Fn(RenderOptions) -> String) {
+        while let Some(client) = self.sockets.get_mut(player) {
+            if client.notify(&reply).is_ok() {
+                return;
+            }
+            self.wait_for_reconnect(player);
+        }
+    }
+
+    /// Passes on what `from` says to the table, or only to `to`, leaving out
+    /// the players who have muted `from`. Returns whether it reached
+    /// `waiting_for`.
+    fn chat(
+        &mut self,
+        from: &str,
+        to: Option<PlayerName>,
+        text: &str,
+        waiting_for: Option<&PlayerName>,
+    ) -> bool {
+        let sender = PlayerName(from.to_owned());
+        let text = match clean_chat(text) {
+            Ok(Some(text)) => text,
+            Ok(None) => return false,
+            Err(rejection) => {
+                self.write_to_one(&sender, Message::Rejected(rejection));
+                return waiting_for == Some(&sender);
+            }
+        };
+
+        let (message, recipients) = match to {
+            None => (
+                Message::Chat {
+                    sender: sender.clone(),
+                    text,
+                },
+                self.players.clone(),
+            ),
+            Some(to) if self.sockets.contains_key(to.as_str()) => (
+                Message::Whisper {
+                    sender: sender.clone(),
+                    to: to.clone(),
+                    text,
+                },
+                BTreeSet::from([sender.clone(), to]).into_iter().collect(),
+            ),
+            Some(to) => {
+                self.write_to_one(&sender, Message::Rejected(Rejection::UnknownPlayer(to)));
+                return waiting_for == Some(&sender);
+            }
+        };
+
+        let mut reached = false;
+        for recipient in recipients {
+            let muted = self
+                .mutes
+                .get(recipient.as_str())
+                .is_some_and(|muted| muted.contains(&sender));
+            if muted && recipient != sender {
+                continue;
+            }
+            self.write_to_one(&recipient, message.clone());
+            reached |= waiting_for == Some(&recipient);
+        }
+        reached
+    }
+
+    fn mute(&mut self, player: &str, target: PlayerName, muted: bool) {
+        if !self.sockets.contains_key(target.as_str()) {
+            let rejection = Message::Rejected(Rejection::UnknownPlayer(target));
+            self.write_to_one(&PlayerName(player.to_owned()), rejection);
+            return;
+        }
+
+        let muted_players = self.mutes.entry(player.to_owned()).or_default();
+        if muted {
+            muted_players.insert(target.clone());
+        } else {
+            muted_players.remove(&target);
+        }
+        self.notify(player, |options| format_muted(&target, muted, options));
+    }
 }
 
 impl Communicator for CommunicatorImpl {
-    fn read(&mut self, name: &PlayerName, prompt: Message) -> String {
+    /// Waits for `name` to answer `prompt`, and meanwhile answers the
+    /// commands of every player. Returns `None` if `name` has left.
+    fn read(&mut self, name: &PlayerName, prompt: Message) -> Option<String> {
+        if !self.sockets.contains_key(name.as_str()) {
+            return None;
+        }
+        self.write_to_one(name, prompt.clone());
+
         loop {
-            {
-                let client = self.sockets.get_mut(name.as_str()).unwrap();
-                if let Ok(res) = client.readline_with_prompt(prompt.clone()) {
-                    break res;
+            // A bot has taken the seat of a player who didn't rejoin in time.
+            if !self.sockets.contains_key(name.as_str()) {
+                return None;
+            }
+            let ((player, connection), input) = match self.next_event() {
+                Event::Input(connection, input) => (connection, input),
+                Event::Connected(stream) => {
+                    self.handshake(stream);
+                    continue;
                 }
+                // Those who join during the game and aren't waited for are
+                // turned away.
+                Event::Joined(Ok(joined)) => {
+                    let (client, _, player) = *joined;
+                    self.turn_away(client, &player);
+                    continue;
+                }
+                Event::Joined(Err(_)) => continue,
+            };
+            if self.connections.get(&player) != Some(&connection) {
+                continue;
+            }
+
+            let prompted = player == name.as_str();
+            let reprompt = match input {
+                Ok(Input::Line(line)) if prompted => return Some(line.trim().to_owned()),
+                Ok(Input::Line(_)) => {
+                    self.notify(&player, |options| format_not_your_turn(name, options));
+                    false
+                }
+                Ok(Input::Command(Command::Quit)) => {
+                    self.quit(&player);
+                    if prompted {
+                        return None;
+                    }
+                    false
+                }
+                Ok(Input::Command(command)) => self.answer(&player, command, Some(name)),
+                Err(error) => {
+                    if let Some(client) = self.sockets.get_mut(&player) {
+                        client.close_if_abusive(&error);
+                    }
+                    self.wait_for_reconnect(&player);
+                    prompted
+                }
+            };
+
+            if reprompt {
+                self.write_to_one(name, prompt.clone());
             }
-            self.wait_for_reconnect(name.as_str());
         }
     }
 
     fn write_to_all(&mut self, message: Message) {
+        debug_assert!(!message.is_private(), "Broadcasting a private message");
         for name in self.sockets.keys().cloned().collect::<Vec<String>>() {
-            loop {
-                {
-                    let client = self.sockets.get_mut(name.as_str()).unwrap();
-                    if client.send(message.clone()).is_ok() {
-                        break;
-                    }
+            while let Some(client) = self.sockets.get_mut(name.as_str()) {
+                if client.send(message.clone()).is_ok() {
+                    break;
                 }
                 self.wait_for_reconnect(name.as_str());
             }
         }
     }
 
+    /// Sends `message` to `name`, unless it is a bot or has left.
     fn write_to_one(&mut self, name: &PlayerName, message: Message) {
-        loop {
-            {
-                let client = self.sockets.get_mut(name.as_str()).unwrap();
-                if client.send(message.clone()).is_ok() {
-                    break;
-                }
+        while let Some(client) = self.sockets.get_mut(name.as_str()) {
+            if client.send(message.clone()).is_ok() {
+                break;
             }
             self.wait_for_reconnect(name.as_str());
         }
     }
 
+    /// Waits for `player` to join again with their rejoin token, and goes on
+    /// with the new connection. A player who hasn't rejoined in time quits,
+    /// and one who drops out of the waiting room leaves it instead.
     fn wait_for_reconnect(&mut self, player: &str) {
+        if self.lobby.is_some() {
+            self.leave(player);
+            return;
+        }
+        let Some(token) = self.tokens.get(player).cloned() else {
+            self.quit(player);
+            return;
+        };
+
         println!("Player {player} has disconnected, waiting for rejoin");
-        self.reconnect
-            .send(ReconnectRequest {
-                player: player.to_owned(),
-                return_channel: self.return_sender.clone(),
-            })
-            .unwrap();
-        let remote_client = self.return_receiver.recv().unwrap();
-        if let Some(client) = self.sockets.get_mut(player) {
-            match client {
-                network::Client::RemoteText(socket) => *socket = remote_client,
-                network::Client::RemoteJson(socket) => *socket = remote_client,
+        self.rejoins.expect(&token, player);
+        let deadline = Instant::now() + self.reconnect_timeout;
+        let rejoined = loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            let Ok(event) = self.events.recv_timeout(left) else {
+                break None;
+            };
+            match event {
+                Event::Connected(stream) => self.handshake(stream),
+                Event::Joined(Ok(joined)) => {
+                    let (client, reader, name) = *joined;
+                    if name == player {
+                        break Some((client, reader));
+                    }
+                    self.turn_away(client, &name);
+                }
+                Event::Joined(Err(_)) => {}
+                event => self.pending.push_back(event),
             }
+        };
+        self.rejoins.forget(&token);
+
+        let Some((client, reader)) = rejoined else {
+            println!("Player {player} didn't rejoin in time");
+            self.quit(player);
+            return;
+        };
+        println!("Reconnecting player {player}.");
+        if let Some(old) = self.sockets.get_mut(player) {
+            // Ends the reader thread of the old connection.
+            _ = old.socket_mut().shutdown(Shutdown::Both);
+            old.resume(client);
+        }
+        self.listen(player, Ok(reader));
+    }
+
+    fn session(&self, player: &PlayerName) -> Option<u64> {
+        self.sessions.get(player.as_str()).copied()
+    }
+
+    /// Takes the players back to the waiting room, where they vote on
+    /// another game by getting ready, and leave with `/quit`. The bots leave
+    /// with the game, and their seats go to whoever joins or to new bots.
+    fn rematch(&mut self) -> Option<Vec<(String, bool)>> {
+        let (humans, bots): (Vec<PlayerName>, Vec<PlayerName>) = self
+            .players
+            .drain(..)
+            .partition(|player| self.sockets.contains_key(player.as_str()));
+        for bot in &bots {
+            self.names.release(bot.as_str());
+            self.sessions.remove(bot.as_str());
+        }
+        self.players.clone_from(&humans);
+        let vote_deadline = Instant::now() + self.vote_timeout;
+        self.lobby = Some(WaitingRoom::after_game(
+            self.seats,
+            humans.clone(),
+            vote_deadline,
+        ));
+        self.lobby_changed = true;
+
+        for player in &humans {
+            self.notify(player.as_str(), format_rematch_hint);
         }
+        self.gather()
     }
 }
 
 impl Drop for CommunicatorImpl {
     fn drop(&mut self) {
-        for socket in self.sockets.values() {
-            match socket {
-                network::Client::RemoteText(socket) | network::Client::RemoteJson(socket) => {
-                    _ = socket.shutdown(Shutdown::Both);
-                }
-            };
+        for client in self.sockets.values_mut() {
+            _ = client.socket_mut().shutdown(Shutdown::Both);
         }
     }
 }
 
 #[derive(Parser)]
 struct Args {
+    /// Seats at the table, the game can start with fewer
     #[arg(long, default_value = "4")]
     players: usize,
     #[arg(long, default_value = "9999")]
     port: u16,
     #[arg(long, default_value = "false")]
     ai: bool,
+    /// french, thirty-six or piquet
+    #[arg(long, default_value = "french")]
+    deck: DeckKind,
+    /// How many copies of the deck to shuffle together
+    #[arg(long, default_value = "1")]
+    decks: usize,
+    #[arg(long, default_value = "0")]
+    jokers: usize,
+    /// Seconds a new connection has to join in
+    #[arg(long, default_value = "60")]
+    handshake_timeout: u64,
+    /// Seconds a player who lost the connection has to join again before a
+    /// bot takes the seat
+    #[arg(long, default_value = "120")]
+    reconnect_timeout: u64,
+    /// Seconds the players have to vote on another game before they are
+    /// taken to have left
+    #[arg(long, default_value = "120")]
+    vote_timeout: u64,
+}
+
+/// Settings the server can't start a game with.
+#[derive(Debug)]
+enum InvalidArgs {
+    TooFewSeats { players: usize },
+    NotEnoughCards { players: usize, cards: usize },
+}
+
+impl Display for InvalidArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            InvalidArgs::TooFewSeats { players } => write!(
+                f,
+                "a game takes at least {MIN_PLAYERS} players, not {players}"
+            ),
+            InvalidArgs::NotEnoughCards { players, cards } => write!(
+                f,
+                "{cards} cards are not enough to deal a hand to {players} players"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidArgs {}
+
+impl Args {
+    /// Returns the cards to play with if there are seats enough for a game,
+    /// and every player at a full table can be dealt at least one card.
+    fn validate(&self) -> Result<DeckBuilder, InvalidArgs> {
+        if self.players < MIN_PLAYERS {
+            return Err(InvalidArgs::TooFewSeats {
+                players: self.players,
+            });
+        }
+        let deck = DeckBuilder::new(self.deck)
+            .copies(self.decks)
+            .jokers(self.jokers);
+        if num_rounds(deck.size(), self.players).is_none() {
+            return Err(InvalidArgs::NotEnoughCards {
+                players: self.players,
+                cards: deck.size(),
+            });
+        }
+
+        Ok(deck)
+    }
 }
 
 #[tokio::main]
@@ -136,63 +720,45 @@ async fn main() -> IoResult<()> {
     #[cfg(windows)]
     enable_colors();
 
+    let deck = match args.validate() {
+        Ok(deck) => deck,
+        Err(error) => Args::command()
+            .error(ErrorKind::ValueValidation, error)
+            .exit(),
+    };
     let num_players = args.players;
 
-    let num_rounds = if num_players < 6 {
-        10
-    } else {
-        52 / num_players
-    };
     let address =
         SocketAddr::from_str(&format!("0.0.0.0:{}", args.port)).expect("Unknown socket address");
     let listener = TcpListener::bind(address).expect("Failed to create listener socket");
-    let mut player_names_and_types: Vec<(String, bool)> = vec![];
-
-    let mut client_sockets = HashMap::<String, network::Client>::new();
-
-    for _ in 0..(num_players) {
-        let mut remote_client = match listener.incoming().next().unwrap() {
-            Ok(stream) => {
-                stream.set_nodelay(true).expect("set_nodelay failed");
-                if args.ai {
-                    network::Client::RemoteJson(stream)
-                } else {
-                    network::Client::RemoteText(stream)
-                }
-            }
-            Err(_) => continue,
-        };
-
-        let name = remote_client.get_player_name()?;
-        if name.starts_with('|') {
-            remote_client = remote_client.into_remote_json().unwrap();
-        }
-        client_sockets.insert(name.clone(), remote_client);
-        player_names_and_types.push((name, true));
-    }
 
-    let (reconnect_sender, reconnect_receiver) = channel();
-    let (return_sender, return_receiver) = channel();
+    let (event_sender, events) = channel();
+    spawn_acceptor(listener, event_sender.clone());
     let mut communicator = CommunicatorImpl {
-        sockets: client_sockets,
-        reconnect: reconnect_sender,
-        return_sender,
-        return_receiver,
+        sockets: HashMap::new(),
+        players: vec![],
+        connections: HashMap::new(),
+        next_connection: 0,
+        sessions: HashMap::new(),
+        mutes: HashMap::new(),
+        events,
+        event_sender,
+        pending: VecDeque::new(),
+        names: Names::default(),
+        tokens: HashMap::new(),
+        rejoins: Rejoins::default(),
+        reconnect_timeout: Duration::from_secs(args.reconnect_timeout),
+        handshake_timeout: Duration::from_secs(args.handshake_timeout),
+        handshakes: Arc::default(),
+        vote_timeout: Duration::from_secs(args.vote_timeout),
+        seats: num_players,
+        lobby: Some(WaitingRoom::new(num_players)),
+        lobby_changed: false,
     };
 
-    let players = create_players(player_names_and_types);
-    let running_game =
-        tokio::spawn(async move { game(&mut communicator, players, num_rounds, args.ai).await });
-    let reconnect_handler = tokio::spawn(async move {
-        while let Ok(request) = reconnect_receiver.recv() {
-            println!("Reconnecting player {}.", request.player);
-            let remote_client = match listener.incoming().next().unwrap() {
-                Ok(stream) => stream,
-                Err(_) => continue,
-            };
-            request.return_channel.send(remote_client).unwrap();
-        }
-    });
-    join_all([running_game, reconnect_handler]).await;
+    let Some(seated) = communicator.gather() else {
+        return Ok(());
+    };
+    game(&mut communicator, create_players(seated), deck, args.ai).await;
     Ok(())
 }
@@ -1,11 +1,43 @@
+mod telnet;
+
 use std::{
-    io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
-    net::TcpStream,
+    collections::{BTreeSet, HashMap, HashSet},
+    io::{BufRead, BufReader, Error as IoError, ErrorKind, Read, Result as IoResult, Write},
+    net::{Shutdown, TcpStream},
+    sync::{mpsc::Sender, Arc, Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use protocol::{
+    binary::{read_frame, write_frame},
+    format::{
+        format_empty_table, format_goodbye, format_help, format_invalid_setting,
+        format_no_chat_yet, format_no_hand, format_no_score, format_not_in_lobby, format_rules,
+        format_setting_changed, format_unknown_command, format_who,
+    },
+    message::{ClientMessage, Message, MAX_CHAT_LENGTH, PROTOCOL_VERSION},
+    options::{InvalidSetting, RenderOptions, Setting},
+    structs::{
+        Capability, Disconnect, Encoding, HandshakeRejection, NameRejection, PlayerName, Rejection,
+        Trick,
+    },
 };
+use telnet::{crlf, edit_line, Telnet};
+
+const SUPPORTED_CAPABILITIES: [Capability; 2] = [Capability::Rejections, Capability::LegalActions];
 
-use protocol::message::Message;
+/// Lines longer than this many bytes get the connection closed. It leaves
+/// plenty of room for a chat message of [`MAX_CHAT_LENGTH`] characters sent
+/// as escaped JSON.
+const MAX_LINE_LENGTH: usize = 4096;
 
-fn send_to_remote(socket: &mut TcpStream, text: String) -> IoResult<()> {
+/// Sends `text` to a text client as a line of its own.
+fn send_line(socket: &mut Socket, text: &str) -> IoResult<()> {
+    send_to_remote(socket, crlf(text) + "\r\n")
+}
+
+fn send_to_remote(socket: &mut Socket, text: String) -> IoResult<()> {
     let mut data = text.into_bytes();
     while !data.is_empty() {
         let sent = socket.write(&data)?;
@@ -21,66 +53,1191 @@ fn send_to_remote(socket: &mut TcpStream, text: String) -> IoResult<()> {
     Ok(())
 }
 
-fn readline_from_remote(socket: &mut TcpStream) -> IoResult<String> {
-    const NEWLINE: u8 = 0xA;
-    let mut all = Vec::<u8>::new();
+/// A connection to a client. The telnet filter answers the client from the
+/// reader thread while the game writes messages, so every write is made
+/// whole under a lock shared by all the handles of the connection, and the
+/// two never interleave.
+pub(crate) struct Socket {
+    stream: TcpStream,
+    writing: Arc<Mutex<()>>,
+    /// When reading gives up with a timeout, during the handshake.
+    deadline: Option<Instant>,
+}
+
+impl Socket {
+    pub(crate) fn new(stream: TcpStream) -> Socket {
+        Socket {
+            stream,
+            writing: Arc::default(),
+            deadline: None,
+        }
+    }
+
+    fn try_clone(&self) -> IoResult<Socket> {
+        Ok(Socket {
+            stream: self.stream.try_clone()?,
+            writing: Arc::clone(&self.writing),
+            deadline: self.deadline,
+        })
+    }
+
+    pub(crate) fn shutdown(&self, how: Shutdown) -> IoResult<()> {
+        self.stream.shutdown(how)
+    }
+
+    /// Limits how long reading may take in all, so that a client can't hold
+    /// on by sending a byte now and then.
+    fn set_deadline(&mut self, deadline: Option<Instant>) -> IoResult<()> {
+        self.deadline = deadline;
+        self.stream.set_read_timeout(None)
+    }
+}
+
+impl Read for Socket {
+    fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
+        if let Some(deadline) = self.deadline {
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Err(ErrorKind::TimedOut.into());
+            }
+            self.stream.set_read_timeout(Some(left))?;
+        }
+        self.stream.read(buffer)
+    }
+}
+
+impl Write for Socket {
+    /// Writes all of `buffer` at once, as writing only part of it would let
+    /// the other handles write in between.
+    fn write(&mut self, buffer: &[u8]) -> IoResult<usize> {
+        let _writing = self.writing.lock().unwrap_or_else(PoisonError::into_inner);
+        self.stream.write_all(buffer)?;
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.stream.flush()
+    }
+}
+
+/// Reads a line of at most [`MAX_LINE_LENGTH`] bytes, without the limit
+/// being reached by whatever follows it in the same read.
+fn read_bounded_line<R: BufRead>(reader: &mut R) -> IoResult<String> {
+    let mut line = Vec::new();
     loop {
-        let mut buffer = [0_u8; 1024];
-        let received = socket.read(&mut buffer)?;
-        if received == 0 {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
             return Err(IoError::new(
                 ErrorKind::UnexpectedEof,
                 "Remote socket has been closed",
             ));
         }
-        all.extend_from_slice(&buffer[0..received]);
 
-        if all.last() != Some(&NEWLINE) {
-            continue;
+        let end = buffer.iter().position(|byte| *byte == b'\n');
+        let used = end.map_or(buffer.len(), |end| end + 1);
+        line.extend_from_slice(&buffer[..used]);
+        reader.consume(used);
+
+        if line.len() > MAX_LINE_LENGTH + usize::from(end.is_some()) {
+            let reason = Disconnect::LineTooLong {
+                max: MAX_LINE_LENGTH,
+            };
+            return Err(IoError::new(ErrorKind::InvalidData, reason));
+        }
+        if end.is_some() {
+            return String::from_utf8(line)
+                .map_err(|_| IoError::new(ErrorKind::InvalidData, Disconnect::InvalidUtf8));
+        }
+    }
+}
+
+/// Tells whether `error` came from a client sending something that can't be
+/// read, or nothing in time, rather than from the connection, and why.
+fn disconnect_reason(error: &IoError) -> Option<Disconnect> {
+    match error.kind() {
+        ErrorKind::InvalidData => {}
+        // Only the handshake has a read timeout, which shows up as either
+        // depending on the platform.
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => return Some(Disconnect::Idle),
+        _ => return None,
+    }
+
+    let reason = error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<Disconnect>())
+        .cloned()
+        .unwrap_or_else(|| Disconnect::Malformed(error.to_string()));
+    Some(reason)
+}
+
+/// Names can have at most this many characters.
+const MAX_NAME_LENGTH: usize = 16;
+
+/// Names are shown to everyone at the table and used to whisper, so they are
+/// kept to letters, digits and a few marks that can't be confused with the
+/// rest of a line.
+fn validate_name(name: &str) -> Result<(), NameRejection> {
+    if name.is_empty() {
+        return Err(NameRejection::Empty);
+    }
+    if let Some(character) = name
+        .chars()
+        .find(|character| !character.is_alphanumeric() && !"-_.'".contains(*character))
+    {
+        return Err(NameRejection::InvalidCharacter(character));
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(NameRejection::TooLong {
+            max: MAX_NAME_LENGTH,
+        });
+    }
+
+    Ok(())
+}
+
+/// The names taken at the table, shared by the handshakes that run side by
+/// side. Names that only differ in case count as the same.
+#[derive(Clone, Default)]
+pub(crate) struct Names(Arc<Mutex<HashSet<String>>>);
+
+impl Names {
+    fn claim(&self, name: &str) -> Result<(), NameRejection> {
+        validate_name(name)?;
+        if !self.0.lock().unwrap().insert(name.to_lowercase()) {
+            return Err(NameRejection::Taken(PlayerName(name.to_owned())));
         }
+        Ok(())
+    }
+
+    /// Claims the first of `Bot1`, `Bot2` and so on that is free.
+    pub(crate) fn claim_bot(&self) -> String {
+        (1..)
+            .map(|number| format!("Bot{number}"))
+            .find(|name| self.claim(name).is_ok())
+            .unwrap()
+    }
 
-        return String::from_utf8(all).map_err(|_| IoError::new(ErrorKind::Other, "invalid UTF-8"));
+    /// Frees the name of a player who left.
+    pub(crate) fn release(&self, name: &str) {
+        self.0.lock().unwrap().remove(&name.to_lowercase());
     }
 }
 
+/// The players the server waits for to join again, by the token each of them
+/// got when taking their seat.
+#[derive(Clone, Default)]
+pub(crate) struct Rejoins(Arc<Mutex<HashMap<String, String>>>);
+
+impl Rejoins {
+    /// Lets `player` take their seat back by giving `token` as their name.
+    pub(crate) fn expect(&self, token: &str, player: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(token.to_owned(), player.to_owned());
+    }
+
+    /// Stops waiting for the player with `token`.
+    pub(crate) fn forget(&self, token: &str) {
+        self.0.lock().unwrap().remove(token);
+    }
+
+    /// Returns the player `token` belongs to, for the first handshake that
+    /// gives it only.
+    fn take(&self, token: &str) -> Option<String> {
+        self.0.lock().unwrap().remove(token)
+    }
+}
+
+/// The tricks taken so far this round, pieced together from the messages
+/// sent to the client.
+#[derive(Default)]
+pub(crate) struct RoundLog {
+    /// The trick on the table, as of the last [`Message::Trick`].
+    table: Option<Trick>,
+    taken: Vec<Trick>,
+}
+
+impl RoundLog {
+    fn record(&mut self, message: &Message) {
+        match message {
+            Message::RoundStart { .. } => self.taken.clear(),
+            Message::Trick(trick) => self.table = Some(trick.clone()),
+            Message::Winner(_) => self.taken.extend(self.table.take()),
+            _ => {}
+        }
+    }
+
+    fn review(&self, review: Review) -> Message {
+        match review {
+            Review::LastTrick => Message::LastTrick(self.taken.last().cloned()),
+            Review::TrickHistory => Message::TrickHistory(self.taken.clone()),
+        }
+    }
+}
+
+/// What the server keeps for a JSON or binary client besides its socket.
+#[derive(Default)]
+pub(crate) struct BotSession {
+    log: RoundLog,
+    /// Agreed on in the handshake, see [`BotSession::adapt`].
+    capabilities: Vec<Capability>,
+}
+
+impl BotSession {
+    fn new(capabilities: Vec<Capability>) -> BotSession {
+        BotSession {
+            log: RoundLog::default(),
+            capabilities,
+        }
+    }
+
+    /// Leaves out what the client didn't ask for in the handshake: the
+    /// reasons for rejected answers without [`Capability::Rejections`], and
+    /// the legal guesses and cards without [`Capability::LegalActions`].
+    fn adapt(&self, mut message: Message) -> Option<Message> {
+        if !self.capabilities.contains(&Capability::LegalActions) {
+            match &mut message {
+                Message::RequestGuessContext { legal_guesses, .. }
+                | Message::RequestGuess { legal_guesses } => *legal_guesses = None,
+                Message::PlayRequestContext { valid_cards, .. }
+                | Message::PlayRequest { valid_cards, .. } => *valid_cards = None,
+                _ => {}
+            }
+        }
+
+        match message {
+            Message::Rejected(_) if !self.capabilities.contains(&Capability::Rejections) => None,
+            message => Some(message),
+        }
+    }
+}
+
+/// What the server keeps for a text client besides its socket.
+#[derive(Default)]
+pub(crate) struct TextSession {
+    options: RenderOptions,
+    /// The last message that showed the player's hand, repeated by `/hand`.
+    hand: Option<Message>,
+    /// The last score sheet, repeated by `/score`.
+    score: Option<Message>,
+    log: RoundLog,
+}
+
+/// What the server knows about the table, for `/who`.
+#[derive(Clone, Copy)]
+pub(crate) struct Table<'a> {
+    pub(crate) players: &'a [PlayerName],
+    /// The player whose answer the server is waiting for.
+    pub(crate) waiting_for: Option<&'a PlayerName>,
+}
+
+// The variants are named after the remote end, like `send_to_remote`.
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum Client {
-    RemoteText(TcpStream),
-    RemoteJson(TcpStream),
+    RemoteText(Socket, Box<TextSession>),
+    RemoteJson(Socket, BotSession),
+    RemoteBinary(Socket, BotSession),
+}
+
+/// What a client sent, either an answer or a command to reply to at once.
+pub(crate) enum Input {
+    Line(String),
+    Command(Command),
+}
+
+fn read_input_from_remote<R: Read>(socket: &mut R) -> IoResult<Input> {
+    loop {
+        let message = read_frame(socket)?;
+        if let ClientMessage::Reply(text) = message {
+            return Ok(Input::Line(text));
+        }
+        if let Some(command) = Command::from_request(message) {
+            return Ok(Input::Command(command));
+        }
+    }
+}
+
+/// Returns `None` if `line` isn't a JSON [`ClientMessage`], which means it is
+/// the name of a text client.
+fn parse_hello(line: &str) -> Option<Result<(Encoding, Vec<Capability>), HandshakeRejection>> {
+    let hello = match serde_json::from_str(line).ok()? {
+        ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+            encoding,
+            capabilities,
+        } => Ok((encoding, capabilities)),
+        ClientMessage::Hello { version, .. } => Err(HandshakeRejection::UnsupportedVersion {
+            requested: version,
+            supported: PROTOCOL_VERSION,
+        }),
+        _ => Err(HandshakeRejection::Malformed(
+            "expected a Hello message".to_owned(),
+        )),
+    };
+
+    Some(hello)
+}
+
+/// A look back at the tricks taken this round, which every kind of client
+/// can ask for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Review {
+    LastTrick,
+    TrickHistory,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub(crate) enum Command {
+    Help,
+    Hand,
+    Score,
+    Trick,
+    Review(Review),
+    Who,
+    Rules,
+    Quit,
+    Setting(Result<Setting, InvalidSetting>),
+    Chat {
+        to: Option<PlayerName>,
+        text: String,
+    },
+    Mute(PlayerName),
+    Unmute(PlayerName),
+    Ready(bool),
+    Start {
+        bots: bool,
+    },
+    Unknown(String),
+}
+
+impl Command {
+    /// JSON and binary clients send commands as a [`ClientMessage`].
+    fn from_request(message: ClientMessage) -> Option<Command> {
+        match message {
+            ClientMessage::LastTrick => Some(Command::Review(Review::LastTrick)),
+            ClientMessage::TrickHistory => Some(Command::Review(Review::TrickHistory)),
+            ClientMessage::Chat { to, text } => Some(Command::Chat { to, text }),
+            ClientMessage::Mute(player) => Some(Command::Mute(player)),
+            ClientMessage::Unmute(player) => Some(Command::Unmute(player)),
+            ClientMessage::Ready(ready) => Some(Command::Ready(ready)),
+            ClientMessage::Start { bots } => Some(Command::Start { bots }),
+            ClientMessage::Leave => Some(Command::Quit),
+            ClientMessage::Reply(_) | ClientMessage::Hello { .. } => None,
+        }
+    }
+}
+
+/// Drops control characters, which could move the cursor or change the
+/// colours on the terminals of other players, and surrounding whitespace.
+/// Returns `Ok(None)` if nothing is left to say.
+pub(crate) fn clean_chat(text: &str) -> Result<Option<String>, Rejection> {
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    let text = text.trim();
+    let length = text.chars().count();
+    if length > MAX_CHAT_LENGTH {
+        return Err(Rejection::ChatTooLong {
+            length,
+            max: MAX_CHAT_LENGTH,
+        });
+    }
+
+    Ok((!text.is_empty()).then(|| text.to_owned()))
+}
+
+/// Text clients can use commands at any time, like `/help`, `/hand`, `/who`
+/// or `/language sv`. Every line that starts with a slash is a command, so
+/// a mistyped one is answered rather than taken as a move.
+fn parse_command(line: &str) -> Option<Command> {
+    let command = line.strip_prefix('/')?;
+    let (name, value) = command
+        .split_once(char::is_whitespace)
+        .unwrap_or((command, ""));
+
+    let command = match name {
+        "help" => Command::Help,
+        "hand" => Command::Hand,
+        "score" => Command::Score,
+        "trick" => Command::Trick,
+        "last" => Command::Review(Review::LastTrick),
+        "history" => Command::Review(Review::TrickHistory),
+        "who" => Command::Who,
+        "rules" => Command::Rules,
+        "quit" => Command::Quit,
+        "say" => Command::Chat {
+            to: None,
+            text: value.to_owned(),
+        },
+        "whisper" => {
+            let (to, text) = value
+                .trim_start()
+                .split_once(char::is_whitespace)
+                .unwrap_or((value.trim(), ""));
+            Command::Chat {
+                to: Some(PlayerName(to.to_owned())),
+                text: text.to_owned(),
+            }
+        }
+        "mute" => Command::Mute(PlayerName(value.trim().to_owned())),
+        "unmute" => Command::Unmute(PlayerName(value.trim().to_owned())),
+        "ready" | "rematch" => Command::Ready(true),
+        "unready" => Command::Ready(false),
+        "start" => Command::Start {
+            bots: value.trim() == "bots",
+        },
+        _ => Setting::parse(name, value.trim())
+            .map_or_else(|| Command::Unknown(name.to_owned()), Command::Setting),
+    };
+
+    Some(command)
+}
+
+/// JSON clients answer prompts with plain lines or with a
+/// [`ClientMessage::Reply`], so only a line that is any other JSON
+/// [`ClientMessage`], like `"LastTrick"`, is taken as a request.
+fn parse_request(line: &str) -> Option<Command> {
+    serde_json::from_str(line)
+        .ok()
+        .and_then(Command::from_request)
+}
+
+fn parse_line(encoding: Encoding, line: String) -> Input {
+    let command = match encoding {
+        Encoding::Text => parse_command(line.trim()),
+        Encoding::Json | Encoding::Binary => {
+            if let Ok(ClientMessage::Reply(text)) = serde_json::from_str(line.trim()) {
+                return Input::Line(text);
+            }
+            parse_request(line.trim())
+        }
+    };
+
+    command.map_or(Input::Line(line), Input::Command)
+}
+
+/// Reads what a client sends, first during the handshake and then on a
+/// thread of its own.
+pub(crate) struct InputReader {
+    reader: BufReader<Telnet<Socket>>,
+    encoding: Encoding,
+}
+
+impl InputReader {
+    pub(crate) fn new(socket: &Socket, encoding: Encoding) -> IoResult<InputReader> {
+        let mut reader = InputReader {
+            reader: BufReader::new(Telnet::new(socket.try_clone()?)),
+            encoding: Encoding::Text,
+        };
+        reader.set_encoding(encoding);
+        Ok(reader)
+    }
+
+    fn socket_mut(&mut self) -> &mut Socket {
+        self.reader.get_mut().get_mut()
+    }
+
+    fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+        self.reader
+            .get_mut()
+            .set_enabled(encoding != Encoding::Binary);
+    }
+
+    fn read(&mut self) -> IoResult<Input> {
+        if let Encoding::Binary = self.encoding {
+            return read_input_from_remote(&mut self.reader);
+        }
+
+        let line = read_bounded_line(&mut self.reader)?;
+        Ok(parse_line(self.encoding, edit_line(&line)))
+    }
+
+    /// Reads everything the client sends on a thread of its own, so that
+    /// commands are answered even while the game waits for someone else.
+    /// Each input is sent on `events` as made into an event by `event`, and
+    /// the thread stops after the first error.
+    pub(crate) fn spawn<E, F>(mut self, events: Sender<E>, event: F)
+    where
+        E: Send + 'static,
+        F: Fn(IoResult<Input>) -> E + Send + 'static,
+    {
+        thread::spawn(move || loop {
+            let input = self.read();
+            let failed = input.is_err();
+            if events.send(event(input)).is_err() || failed {
+                return;
+            }
+        });
+    }
 }
 
 impl Client {
+    /// Text clients always get everything, the `capabilities` only matter
+    /// to the other encodings.
+    pub(crate) fn new(socket: Socket, encoding: Encoding, capabilities: Vec<Capability>) -> Client {
+        match encoding {
+            Encoding::Text => Client::RemoteText(socket, Box::default()),
+            Encoding::Json => Client::RemoteJson(socket, BotSession::new(capabilities)),
+            Encoding::Binary => Client::RemoteBinary(socket, BotSession::new(capabilities)),
+        }
+    }
+
+    /// Runs the handshake on a new connection and returns the client in the
+    /// negotiated encoding together with the player name, and the reader to
+    /// go on reading the connection with.
+    ///
+    /// The name prompt is sent as text first. A client that answers with a
+    /// JSON `Hello` gets a JSON reply and continues in the encoding it asked
+    /// for, anything else is taken to be the name of a text client.
+    ///
+    /// A client that hasn't joined within `timeout` is disconnected, and one
+    /// that picks a name from `names` is asked for another. A token from
+    /// `rejoins` given instead of a name joins as the player it belongs to.
+    pub(crate) fn accept(
+        socket: TcpStream,
+        timeout: Duration,
+        names: &Names,
+        rejoins: &Rejoins,
+    ) -> IoResult<(Client, InputReader, String)> {
+        let mut socket = Socket::new(socket);
+        socket.set_deadline(Some(Instant::now() + timeout))?;
+        // Gives the reason for timeouts too, instead of the one of the system.
+        let mut accepted = Client::handshake(socket, names, rejoins).map_err(|error| {
+            let kind = error.kind();
+            disconnect_reason(&error).map_or(error, |reason| IoError::new(kind, reason))
+        })?;
+        accepted.1.socket_mut().set_deadline(None)?;
+        Ok(accepted)
+    }
+
+    fn handshake(
+        socket: Socket,
+        names: &Names,
+        rejoins: &Rejoins,
+    ) -> IoResult<(Client, InputReader, String)> {
+        let mut reader = InputReader::new(&socket, Encoding::Text)?;
+        let mut client = Client::new(socket, Encoding::Text, Vec::new());
+        let line = client.get_player_name(&mut reader)?;
+
+        let Some(hello) = parse_hello(&line) else {
+            let name = client.claim_name(&mut reader, names, rejoins, line)?;
+            return Ok((client, reader, name));
+        };
+
+        // Rejections are all the handshake can send besides its reply.
+        let mut client = Client::new(
+            client.into_socket(),
+            Encoding::Json,
+            vec![Capability::Rejections],
+        );
+        let (encoding, capabilities) = match hello {
+            Ok(hello) => hello,
+            Err(rejection) => {
+                let message = Message::HandshakeRejected(rejection);
+                let reason = message.to_string();
+                client.send(message)?;
+                return Err(IoError::new(ErrorKind::InvalidData, reason));
+            }
+        };
+
+        let capabilities: Vec<_> = SUPPORTED_CAPABILITIES
+            .into_iter()
+            .filter(|capability| capabilities.contains(capability))
+            .collect();
+
+        client.send(Message::HandshakeAccepted {
+            version: PROTOCOL_VERSION,
+            encoding,
+            capabilities: capabilities.clone(),
+        })?;
+
+        reader.set_encoding(encoding);
+        let mut client = Client::new(client.into_socket(), encoding, capabilities);
+        let name = client.get_player_name(&mut reader)?;
+        let name = client.claim_name(&mut reader, names, rejoins, name)?;
+        Ok((client, reader, name))
+    }
+
     pub(crate) fn send(&mut self, msg: Message) -> IoResult<()> {
+        self.log_mut().record(&msg);
+
         match self {
-            Client::RemoteText(socket) => send_to_remote(socket, msg.to_string() + "\n"),
-            Client::RemoteJson(socket) => {
+            Client::RemoteText(socket, session) => {
+                let text = msg.render(session.options);
+                session.remember(msg);
+                send_line(socket, &text)
+            }
+            Client::RemoteJson(socket, session) => {
+                let Some(msg) = session.adapt(msg) else {
+                    return Ok(());
+                };
                 let line = serde_json::to_string(&msg).unwrap();
                 let line = format!("{}\n", line);
                 send_to_remote(socket, line)
             }
+            Client::RemoteBinary(socket, session) => {
+                let Some(msg) = session.adapt(msg) else {
+                    return Ok(());
+                };
+                write_frame(socket, &msg)
+            }
         }
     }
 
-    pub(crate) fn readline(&mut self) -> IoResult<String> {
-        let text = match self {
-            Client::RemoteText(socket) | Client::RemoteJson(socket) => readline_from_remote(socket),
-        }?;
+    /// Asks for a line during the handshake, before there is a reader
+    /// thread.
+    fn readline_with_prompt(
+        &mut self,
+        reader: &mut InputReader,
+        prompt: Message,
+    ) -> IoResult<String> {
+        const NO_TABLE: Table<'static> = Table {
+            players: &[],
+            waiting_for: None,
+        };
 
-        Ok(text.trim().to_owned())
+        loop {
+            self.send(prompt.clone())?;
+            let input = reader
+                .read()
+                .inspect_err(|error| self.close_if_abusive(error))?;
+            match input {
+                Input::Line(line) => return Ok(line.trim().to_owned()),
+                Input::Command(command) => self.answer(command, NO_TABLE)?,
+            }
+        }
     }
 
-    pub(crate) fn readline_with_prompt(&mut self, prompt: Message) -> IoResult<String> {
-        self.send(prompt)?;
-        self.readline()
+    fn get_player_name(&mut self, reader: &mut InputReader) -> IoResult<String> {
+        self.readline_with_prompt(reader, Message::RequestPlayerName)
     }
 
-    pub(crate) fn get_player_name(&mut self) -> IoResult<String> {
-        self.readline_with_prompt(Message::RequestPlayerName)
+    /// Asks for another name until the client gives one that is valid and
+    /// free, or the token of a player who is waited for to join again.
+    fn claim_name(
+        &mut self,
+        reader: &mut InputReader,
+        names: &Names,
+        rejoins: &Rejoins,
+        mut name: String,
+    ) -> IoResult<String> {
+        loop {
+            if let Some(player) = rejoins.take(&name) {
+                return Ok(player);
+            }
+            match names.claim(&name) {
+                Ok(()) => return Ok(name),
+                Err(rejection) => self.send(Message::NameRejected(rejection))?,
+            }
+            name = self.get_player_name(reader)?;
+        }
+    }
+
+    /// Closes the connection with the reason if `error` came from the client
+    /// sending something that can't be read, or nothing in time.
+    pub(crate) fn close_if_abusive(&mut self, error: &IoError) {
+        if let Some(reason) = disconnect_reason(error) {
+            _ = self.send(Message::Disconnected(reason));
+            _ = self.socket().shutdown(Shutdown::Both);
+        }
+    }
+
+    /// Goes on with the connection of `client`, which has joined again
+    /// with the rejoin token of this one. Text clients keep their settings, the
+    /// others start over with what they asked for in the new handshake.
+    pub(crate) fn resume(&mut self, client: Client) {
+        match (self, client) {
+            (Client::RemoteText(socket, _), Client::RemoteText(new, _)) => *socket = new,
+            (this, client) => *this = client,
+        }
+    }
+
+    /// Replies to `command` without disturbing the pending prompt, and
+    /// closes the connection after `/quit`.
+    pub(crate) fn answer(&mut self, command: Command, table: Table) -> IoResult<()> {
+        let quit = command == Command::Quit;
+        match self {
+            Client::RemoteText(socket, session) => {
+                let reply = session.reply(command, table);
+                send_line(socket, &reply)?;
+            }
+            Client::RemoteJson(_, session) | Client::RemoteBinary(_, session) => {
+                if let Command::Review(review) = command {
+                    let message = session.log.review(review);
+                    self.send(message)?;
+                }
+            }
+        }
+        if quit {
+            self.socket_mut().shutdown(Shutdown::Both)?;
+        }
+        Ok(())
+    }
+
+    /// Sends a reply that only text clients get, like a note that their
+    /// answer was ignored. Other clients only answer when asked to, and
+    /// learn about their requests from the messages that follow.
+    pub(crate) fn notify(&mut self, reply: impl FnOnce(RenderOptions) -> String) -> IoResult<()> {
+        match self {
+            Client::RemoteText(socket, session) => send_line(socket, &reply(session.options)),
+            Client::RemoteJson(..) | Client::RemoteBinary(..) => Ok(()),
+        }
+    }
+
+    const fn socket(&self) -> &Socket {
+        match self {
+            Client::RemoteText(socket, _)
+            | Client::RemoteJson(socket, _)
+            | Client::RemoteBinary(socket, _) => socket,
+        }
+    }
+
+    pub(crate) const fn socket_mut(&mut self) -> &mut Socket {
+        match self {
+            Client::RemoteText(socket, _)
+            | Client::RemoteJson(socket, _)
+            | Client::RemoteBinary(socket, _) => socket,
+        }
+    }
+
+    const fn log_mut(&mut self) -> &mut RoundLog {
+        match self {
+            Client::RemoteText(_, session) => &mut session.log,
+            Client::RemoteJson(_, session) | Client::RemoteBinary(_, session) => &mut session.log,
+        }
     }
 
-    pub(crate) fn into_remote_json(self) -> Option<Client> {
+    fn into_socket(self) -> Socket {
         match self {
-            Client::RemoteText(socket) => Some(Client::RemoteJson(socket)),
-            Client::RemoteJson(_) => Some(self),
+            Client::RemoteText(socket, _)
+            | Client::RemoteJson(socket, _)
+            | Client::RemoteBinary(socket, _) => socket,
+        }
+    }
+}
+
+impl TextSession {
+    /// Keeps what `/hand` and `/score` repeat up to date.
+    fn remember(&mut self, msg: Message) {
+        match msg {
+            Message::RequestGuessContext { .. } | Message::PlayRequestContext { .. } => {
+                self.hand = Some(msg);
+            }
+            Message::Trick(trick) => self.played(trick),
+            Message::ScoreSheet(_) => self.score = Some(msg),
+            _ => {}
+        }
+    }
+
+    /// Takes the card the player just played to `trick` out of the hand
+    /// `/hand` shows, where nothing is playable until the next request.
+    fn played(&mut self, trick: Trick) {
+        let Some(Message::PlayRequestContext {
+            player,
+            hand,
+            trick: shown,
+            valid_cards,
+        }) = &mut self.hand
+        else {
+            return;
+        };
+        let Some(play) = trick
+            .plays()
+            .last()
+            .filter(|play| play.player == player.name)
+        else {
+            return;
+        };
+
+        for hand in [hand, &mut player.hand] {
+            if let Some(index) = hand.iter().position(|card| *card == play.card) {
+                hand.remove(index);
+            }
         }
+        *valid_cards = Some(BTreeSet::new());
+        *shown = trick;
+    }
+
+    fn reply(&mut self, command: Command, table: Table) -> String {
+        let options = &mut self.options;
+        let shown = |message: Option<&Message>, missing: fn(RenderOptions) -> String| {
+            message.map_or_else(|| missing(*options), |message| message.render(*options))
+        };
+
+        match command {
+            Command::Help => format_help(*options),
+            Command::Hand => shown(self.hand.as_ref(), format_no_hand),
+            Command::Score => shown(self.score.as_ref(), format_no_score),
+            Command::Trick => shown(
+                self.log.table.clone().map(Message::Trick).as_ref(),
+                format_empty_table,
+            ),
+            Command::Review(review) => self.log.review(review).render(*options),
+            Command::Who => format_who(table.players, table.waiting_for, *options),
+            Command::Rules => format_rules(*options),
+            Command::Quit => format_goodbye(*options),
+            Command::Setting(Ok(setting)) => {
+                options.apply(setting);
+                format_setting_changed(setting, *options)
+            }
+            Command::Setting(Err(error)) => format_invalid_setting(&error, *options),
+            Command::Chat { .. } | Command::Mute(_) | Command::Unmute(_) => {
+                format_no_chat_yet(*options)
+            }
+            Command::Ready(_) | Command::Start { .. } => format_not_in_lobby(*options),
+            Command::Unknown(name) => format_unknown_command(&name, *options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    use playing_cards::notation::parse_cards;
+    use protocol::{
+        locale::{Language, UnknownLanguage},
+        options::Profile,
+        structs::{Player, PlayerName, PublicPlayer},
+    };
+
+    #[test]
+    fn test_parse_hello() {
+        assert!(parse_hello("Alice").is_none());
+
+        let hello =
+            r#"{"Hello":{"version":1,"encoding":"Binary","capabilities":["LegalActions"]}}"#;
+        assert_eq!(
+            parse_hello(hello),
+            Some(Ok((Encoding::Binary, vec![Capability::LegalActions])))
+        );
+
+        let hello = r#"{"Hello":{"version":0,"encoding":"Json","capabilities":[]}}"#;
+        assert_eq!(
+            parse_hello(hello),
+            Some(Err(HandshakeRejection::UnsupportedVersion {
+                requested: 0,
+                supported: PROTOCOL_VERSION
+            }))
+        );
+
+        assert!(matches!(
+            parse_hello(r#""LastTrick""#),
+            Some(Err(HandshakeRejection::Malformed(_)))
+        ));
+
+        // Only lines that are a message are taken as a handshake.
+        assert!(parse_hello(r#"{"Hello":{}}"#).is_none());
+        assert!(parse_hello("{Alice}").is_none());
+    }
+
+    #[test]
+    fn test_names() {
+        let names = Names::default();
+        assert_eq!(names.claim("Åsa"), Ok(()));
+        assert_eq!(names.claim("O'Neil-2"), Ok(()));
+        assert_eq!(
+            names.claim("åsa"),
+            Err(NameRejection::Taken(PlayerName("åsa".to_owned())))
+        );
+        assert_eq!(names.claim(""), Err(NameRejection::Empty));
+        assert_eq!(
+            names.claim("Bob Smith"),
+            Err(NameRejection::InvalidCharacter(' '))
+        );
+        assert_eq!(
+            names.claim("\u{1b}[2JBob"),
+            Err(NameRejection::InvalidCharacter('\u{1b}'))
+        );
+        assert_eq!(
+            names.claim(&"a".repeat(MAX_NAME_LENGTH + 1)),
+            Err(NameRejection::TooLong {
+                max: MAX_NAME_LENGTH
+            })
+        );
+
+        assert_eq!(names.claim("bot1"), Ok(()));
+        assert_eq!(names.claim_bot(), "Bot2");
+        names.release("ÅSA");
+        assert_eq!(names.claim("Åsa"), Ok(()));
+
+        let rejection = Message::NameRejected(NameRejection::InvalidCharacter('\u{1b}'));
+        assert_eq!(
+            rejection.to_string(),
+            "Names can only have letters, digits and - _ . ' in them, not 'U+001B'"
+        );
+    }
+
+    #[test]
+    fn test_rejoins() {
+        let rejoins = Rejoins::default();
+        rejoins.expect("3f9c2a417be05d68", "Åsa");
+        assert_eq!(rejoins.take("Åsa"), None);
+        assert_eq!(rejoins.take("3f9c2a417be05d68"), Some("Åsa".to_owned()));
+        assert_eq!(rejoins.take("3f9c2a417be05d68"), None);
+
+        rejoins.expect("3f9c2a417be05d68", "Åsa");
+        rejoins.forget("3f9c2a417be05d68");
+        assert_eq!(rejoins.take("3f9c2a417be05d68"), None);
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("Alice"), None);
+        assert_eq!(
+            parse_command("/languages"),
+            Some(Command::Unknown("languages".to_owned()))
+        );
+        assert_eq!(parse_command("/hand"), Some(Command::Hand));
+        assert_eq!(parse_command("/who"), Some(Command::Who));
+        assert_eq!(parse_command("/quit now"), Some(Command::Quit));
+        assert_eq!(
+            parse_command("/language sv"),
+            Some(Command::Setting(Ok(Setting::Language(Language::Swedish))))
+        );
+        assert_eq!(
+            parse_command("/profile  ascii "),
+            Some(Command::Setting(Ok(Setting::Profile(Profile::Ascii))))
+        );
+        assert_eq!(
+            parse_command("/language"),
+            Some(Command::Setting(Err(InvalidSetting::Language(
+                UnknownLanguage(String::new())
+            ))))
+        );
+        assert_eq!(
+            parse_command("/history"),
+            Some(Command::Review(Review::TrickHistory))
+        );
+        assert_eq!(
+            parse_command("/say  good luck"),
+            Some(Command::Chat {
+                to: None,
+                text: " good luck".to_owned()
+            })
+        );
+        assert_eq!(
+            parse_command("/whisper Bob lead hearts"),
+            Some(Command::Chat {
+                to: Some(PlayerName("Bob".to_owned())),
+                text: "lead hearts".to_owned()
+            })
+        );
+        assert_eq!(
+            parse_command("/whisper Bob"),
+            Some(Command::Chat {
+                to: Some(PlayerName("Bob".to_owned())),
+                text: String::new()
+            })
+        );
+        assert_eq!(
+            parse_command("/mute Bob "),
+            Some(Command::Mute(PlayerName("Bob".to_owned())))
+        );
+        assert_eq!(parse_command("/unready"), Some(Command::Ready(false)));
+        assert_eq!(parse_command("/rematch"), Some(Command::Ready(true)));
+        assert_eq!(
+            parse_command("/start bots"),
+            Some(Command::Start { bots: true })
+        );
+    }
+
+    #[test]
+    fn test_clean_chat() {
+        assert_eq!(clean_chat(" hi\x1b[2J "), Ok(Some("hi[2J".to_owned())));
+        assert_eq!(clean_chat(" \r\n"), Ok(None));
+        assert_eq!(
+            clean_chat(&"å".repeat(MAX_CHAT_LENGTH))
+                .unwrap()
+                .unwrap()
+                .chars()
+                .count(),
+            MAX_CHAT_LENGTH
+        );
+        assert_eq!(
+            clean_chat(&"a".repeat(MAX_CHAT_LENGTH + 1)),
+            Err(Rejection::ChatTooLong {
+                length: MAX_CHAT_LENGTH + 1,
+                max: MAX_CHAT_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_bounded_line() {
+        // Lines that end and start in the middle of a read, and lines split
+        // across reads.
+        let mut reader = BufReader::with_capacity(4, Cursor::new("1\nAlice\n\n2"));
+        assert_eq!(read_bounded_line(&mut reader).unwrap(), "1\n");
+        assert_eq!(read_bounded_line(&mut reader).unwrap(), "Alice\n");
+        assert_eq!(read_bounded_line(&mut reader).unwrap(), "\n");
+        let error = read_bounded_line(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(disconnect_reason(&error), None);
+
+        let longest = format!("{}\n", "a".repeat(MAX_LINE_LENGTH));
+        let mut reader = Cursor::new(format!("{longest}a{longest}"));
+        assert_eq!(read_bounded_line(&mut reader).unwrap(), longest);
+        let error = read_bounded_line(&mut reader).unwrap_err();
+        assert_eq!(
+            disconnect_reason(&error),
+            Some(Disconnect::LineTooLong {
+                max: MAX_LINE_LENGTH
+            })
+        );
+
+        let timeout = IoError::from(ErrorKind::WouldBlock);
+        assert_eq!(disconnect_reason(&timeout), Some(Disconnect::Idle));
+
+        let error = read_bounded_line(&mut Cursor::new(b"\xff\n")).unwrap_err();
+        assert_eq!(disconnect_reason(&error), Some(Disconnect::InvalidUtf8));
+        assert_eq!(
+            Message::Disconnected(Disconnect::InvalidUtf8).to_string(),
+            "Closing the connection, only UTF-8 text is understood"
+        );
+    }
+
+    #[test]
+    fn test_parse_request() {
+        assert_eq!(parse_request("3"), None);
+        assert_eq!(parse_request("LastTrick"), None);
+        assert!(matches!(
+            parse_line(Encoding::Json, r#"{"Reply":"3"}"#.to_owned()),
+            Input::Line(text) if text == "3"
+        ));
+        assert!(matches!(
+            parse_line(Encoding::Json, "3".to_owned()),
+            Input::Line(text) if text == "3"
+        ));
+        assert_eq!(
+            parse_request(r#""LastTrick""#),
+            Some(Command::Review(Review::LastTrick))
+        );
+        assert_eq!(
+            parse_request(&serde_json::to_string(&ClientMessage::TrickHistory).unwrap()),
+            Some(Command::Review(Review::TrickHistory))
+        );
+        assert_eq!(
+            parse_request(r#"{"Chat":{"to":null,"text":"hi"}}"#),
+            Some(Command::Chat {
+                to: None,
+                text: "hi".to_owned()
+            })
+        );
+        assert_eq!(
+            parse_request(r#"{"Mute":"Bob"}"#),
+            Some(Command::Mute(PlayerName("Bob".to_owned())))
+        );
+    }
+
+    #[test]
+    fn test_round_log() {
+        let mut trick = Trick::new();
+        trick.play(PlayerName("Alice".to_owned()), "AH".parse().unwrap());
+        let winner = PublicPlayer {
+            name: PlayerName("Alice".to_owned()),
+            human: true,
+        };
+
+        let mut log = RoundLog::default();
+        log.record(&Message::Trick(trick.clone()));
+        assert!(matches!(
+            log.review(Review::LastTrick),
+            Message::LastTrick(None)
+        ));
+
+        log.record(&Message::Winner(winner.clone()));
+        log.record(&Message::Winner(winner.clone()));
+        assert!(matches!(
+            log.review(Review::TrickHistory),
+            Message::TrickHistory(tricks) if tricks.len() == 1
+        ));
+
+        log.record(&Message::RoundStart {
+            round: 2,
+            rounds: 3,
+            hand_size: 1,
+            dealer: winner,
+        });
+        assert!(matches!(
+            log.review(Review::LastTrick),
+            Message::LastTrick(None)
+        ));
+    }
+
+    #[test]
+    fn test_hand_after_playing() {
+        let alice = PlayerName("Alice".to_owned());
+        let hand = parse_cards("AD 10S").unwrap();
+        let mut session = TextSession::default();
+        session.options.profile = Profile::Ascii;
+        session.remember(Message::PlayRequestContext {
+            player: Player {
+                name: alice.clone(),
+                human: true,
+                hand: hand.clone(),
+            },
+            hand,
+            trick: Trick::new(),
+            valid_cards: Some(BTreeSet::from([0, 1])),
+        });
+
+        let mut trick = Trick::new();
+        trick.play(PlayerName("Bob".to_owned()), "5D".parse().unwrap());
+        session.remember(Message::Trick(trick.clone()));
+        trick.play(alice, "AD".parse().unwrap());
+        session.remember(Message::Trick(trick));
+
+        let players = [PlayerName("Alice".to_owned())];
+        let table = Table {
+            players: &players,
+            waiting_for: None,
+        };
+        assert_eq!(
+            session.reply(Command::Hand, table),
+            "Alice: Hand: [0|10S], Trick: Bob 5D, Alice AD (winning: Alice)"
+        );
+    }
+
+    #[test]
+    fn test_socket_deadline() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut remote = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let mut socket = Socket::new(listener.accept().unwrap().0);
+        socket.set_deadline(Some(Instant::now())).unwrap();
+
+        // Something to read isn't enough once the time is up.
+        remote.write_all(b"A").unwrap();
+        let error = socket.read(&mut [0; 1]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TimedOut);
+
+        socket.set_deadline(None).unwrap();
+        assert_eq!(socket.read(&mut [0; 1]).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_bot_session_adapt() {
+        let request = Message::RequestGuess {
+            legal_guesses: Some(BTreeSet::from([0, 2])),
+        };
+        let rejected = Message::Rejected(Rejection::NotANumber("two".to_owned()));
+
+        let session = BotSession::new(vec![Capability::LegalActions]);
+        assert!(matches!(
+            session.adapt(request.clone()),
+            Some(Message::RequestGuess { legal_guesses }) if legal_guesses == Some(BTreeSet::from([0, 2]))
+        ));
+        assert!(session.adapt(rejected.clone()).is_none());
+
+        let session = BotSession::new(vec![Capability::Rejections]);
+        assert!(matches!(
+            session.adapt(request),
+            Some(Message::RequestGuess { legal_guesses }) if legal_guesses.is_none()
+        ));
+        assert!(matches!(
+            session.adapt(rejected),
+            Some(Message::Rejected(_))
+        ));
     }
 }
@@ -0,0 +1,410 @@
+//! Just enough of the telnet protocol (RFC 854) for text clients.
+//!
+//! Telnet clients mix commands into what they send, all starting with the
+//! byte `IAC`. They are filtered out here so that only what the player typed
+//! reaches the line reader. The server never starts a negotiation by itself,
+//! as netcat and the JSON and binary clients would get the commands as
+//! garbage, but makes its offers as soon as a client shows that it speaks
+//! telnet.
+
+use std::{
+    collections::HashSet,
+    io::{Read, Result as IoResult, Write},
+};
+
+const IAC: u8 = 255;
+const DONT: u8 = 254;
+const DO: u8 = 253;
+const WONT: u8 = 252;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+/// Erase line.
+const EL: u8 = 248;
+/// Erase character.
+const EC: u8 = 247;
+const SE: u8 = 240;
+
+const SUPPRESS_GO_AHEAD: u8 = 3;
+const NAWS: u8 = 31;
+const CHARSET: u8 = 42;
+
+const CHARSET_REQUEST: u8 = 1;
+const CHARSET_ACCEPTED: u8 = 2;
+const CHARSET_REJECTED: u8 = 3;
+const UTF8: &[u8] = b"UTF-8";
+
+/// What erasing a character and a line turn into for the line reader.
+pub(super) const BACKSPACE: u8 = 0x08;
+pub(super) const ERASE_LINE: u8 = 0x15;
+
+/// Subnegotiations longer than this are cut short, none of the ones
+/// understood here come close.
+const MAX_SUBNEGOTIATION: usize = 64;
+
+enum State {
+    Data,
+    /// After a carriage return, which ends the line by itself. The line feed
+    /// or null byte that telnet sends after it is dropped.
+    CarriageReturn,
+    Command,
+    Negotiation(u8),
+    Subnegotiation,
+    SubnegotiationCommand,
+}
+
+/// Filters the telnet commands out of what `stream` sends, and answers them.
+pub(super) struct Telnet<S> {
+    stream: S,
+    enabled: bool,
+    state: State,
+    offered: bool,
+    /// The options the server has agreed to use.
+    local: HashSet<u8>,
+    /// The options the client has been asked to use.
+    remote: HashSet<u8>,
+    subnegotiation: Vec<u8>,
+    replies: Vec<u8>,
+}
+
+impl<S> Telnet<S> {
+    pub(super) fn new(stream: S) -> Telnet<S> {
+        Telnet {
+            stream,
+            enabled: true,
+            state: State::Data,
+            offered: false,
+            local: HashSet::new(),
+            remote: HashSet::new(),
+            subnegotiation: vec![],
+            replies: vec![],
+        }
+    }
+
+    pub(super) const fn get_mut(&mut self) -> &mut S {
+        &mut self.stream
+    }
+
+    /// Binary clients send `IAC` as part of their frames, so the filter is
+    /// turned off for them after the handshake.
+    pub(super) const fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Decodes a single byte, and returns it if it is part of the input.
+    fn decode(&mut self, byte: u8) -> Option<u8> {
+        match (&self.state, byte) {
+            (State::Data | State::CarriageReturn, IAC) => {
+                self.state = State::Command;
+                self.offer();
+            }
+            (State::Data, b'\r') => {
+                self.state = State::CarriageReturn;
+                return Some(b'\n');
+            }
+            (State::CarriageReturn, b'\n' | b'\0') => self.state = State::Data,
+            (State::Data | State::CarriageReturn, byte) => {
+                self.state = State::Data;
+                return Some(byte);
+            }
+            // A literal 0xFF can't be part of UTF-8 text.
+            (State::Command, IAC) => self.state = State::Data,
+            (State::Command, DO | DONT | WILL | WONT) => self.state = State::Negotiation(byte),
+            (State::Command, SB) => {
+                self.subnegotiation.clear();
+                self.state = State::Subnegotiation;
+            }
+            (State::Command, EC) => {
+                self.state = State::Data;
+                return Some(BACKSPACE);
+            }
+            (State::Command, EL) => {
+                self.state = State::Data;
+                return Some(ERASE_LINE);
+            }
+            // Go ahead, are you there and the rest mean nothing to a line
+            // based game.
+            (State::Command, _) => self.state = State::Data,
+            (State::Negotiation(verb), option) => {
+                let verb = *verb;
+                self.state = State::Data;
+                self.negotiate(verb, option);
+            }
+            (State::Subnegotiation, IAC) => self.state = State::SubnegotiationCommand,
+            (State::Subnegotiation, byte) => {
+                if self.subnegotiation.len() < MAX_SUBNEGOTIATION {
+                    self.subnegotiation.push(byte);
+                }
+            }
+            (State::SubnegotiationCommand, SE) => {
+                self.state = State::Data;
+                self.subnegotiate();
+            }
+            (State::SubnegotiationCommand, byte) => {
+                self.state = State::Subnegotiation;
+                if byte == IAC && self.subnegotiation.len() < MAX_SUBNEGOTIATION {
+                    self.subnegotiation.push(IAC);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Offers what the server would like once the client speaks telnet:
+    /// to go without go-aheads, to be told the window size and to agree on
+    /// UTF-8. Echo stays with the client, which edits its lines locally.
+    fn offer(&mut self) {
+        if std::mem::replace(&mut self.offered, true) {
+            return;
+        }
+
+        for option in [SUPPRESS_GO_AHEAD, CHARSET] {
+            self.local.insert(option);
+            self.replies.extend([IAC, WILL, option]);
+        }
+        self.remote.insert(NAWS);
+        self.replies.extend([IAC, DO, NAWS]);
+    }
+
+    /// Answers a request to turn `option` on or off, but only if it changes
+    /// anything, so that the two sides never keep answering each other.
+    fn negotiate(&mut self, verb: u8, option: u8) {
+        match verb {
+            DO if matches!(option, SUPPRESS_GO_AHEAD | CHARSET) => {
+                if self.local.insert(option) {
+                    self.replies.extend([IAC, WILL, option]);
+                }
+                if option == CHARSET {
+                    self.replies
+                        .extend([IAC, SB, CHARSET, CHARSET_REQUEST, b';']);
+                    self.replies.extend(UTF8);
+                    self.replies.extend([IAC, SE]);
+                }
+            }
+            // Like echo, as the server doesn't echo what is typed.
+            DO => self.replies.extend([IAC, WONT, option]),
+            DONT => {
+                if self.local.remove(&option) {
+                    self.replies.extend([IAC, WONT, option]);
+                }
+            }
+            WILL if matches!(option, SUPPRESS_GO_AHEAD | NAWS | CHARSET) => {
+                if self.remote.insert(option) {
+                    self.replies.extend([IAC, DO, option]);
+                }
+            }
+            WILL => self.replies.extend([IAC, DONT, option]),
+            _ => {
+                if self.remote.remove(&option) {
+                    self.replies.extend([IAC, DONT, option]);
+                }
+            }
+        }
+    }
+
+    /// Answers a client that asks which character set to use. The window
+    /// size is accepted so that clients don't wait for an answer, but nothing
+    /// is laid out by width, and whether the client accepts UTF-8 doesn't
+    /// matter either, as that is all the server sends.
+    fn subnegotiate(&mut self) {
+        let [CHARSET, CHARSET_REQUEST, separator, charsets @ ..] = self.subnegotiation.as_slice()
+        else {
+            return;
+        };
+
+        let utf8 = charsets
+            .split(|byte| byte == separator)
+            .any(|charset| charset.eq_ignore_ascii_case(UTF8));
+        if utf8 {
+            self.replies.extend([IAC, SB, CHARSET, CHARSET_ACCEPTED]);
+            self.replies.extend(UTF8);
+        } else {
+            self.replies.extend([IAC, SB, CHARSET, CHARSET_REJECTED]);
+        }
+        self.replies.extend([IAC, SE]);
+    }
+}
+
+impl<S: Read + Write> Read for Telnet<S> {
+    fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
+        if !self.enabled {
+            return self.stream.read(buffer);
+        }
+
+        loop {
+            let received = self.stream.read(buffer)?;
+            if received == 0 {
+                return Ok(0);
+            }
+
+            let mut length = 0;
+            for index in 0..received {
+                if let Some(byte) = self.decode(buffer[index]) {
+                    buffer[length] = byte;
+                    length += 1;
+                }
+            }
+
+            if !self.replies.is_empty() {
+                self.stream.write_all(&self.replies)?;
+                self.replies.clear();
+            }
+            // Zero would mean the end of the stream.
+            if length > 0 {
+                return Ok(length);
+            }
+        }
+    }
+}
+
+/// Applies the line editing of clients that send every key as it is
+/// pressed: backspace and delete erase a character, and Ctrl-U the line.
+pub(super) fn edit_line(line: &str) -> String {
+    let mut edited = String::with_capacity(line.len());
+    for character in line.chars() {
+        match character {
+            '\u{8}' | '\u{7f}' => {
+                edited.pop();
+            }
+            '\u{15}' => edited.clear(),
+            character => edited.push(character),
+        }
+    }
+    edited
+}
+
+/// Ends every line of `text` with CR LF, the end of line telnet expects,
+/// so that each line starts at the left margin on every client.
+pub(super) fn crlf(text: &str) -> String {
+    text.replace('\n', "\r\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Cursor;
+
+    const ECHO: u8 = 1;
+
+    /// A client that has sent `input`, and what the server answered.
+    struct Remote {
+        input: Cursor<Vec<u8>>,
+        output: Vec<u8>,
+    }
+
+    impl Read for Remote {
+        fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
+            self.input.read(buffer)
+        }
+    }
+
+    impl Write for Remote {
+        fn write(&mut self, buffer: &[u8]) -> IoResult<usize> {
+            self.output.write(buffer)
+        }
+
+        fn flush(&mut self) -> IoResult<()> {
+            Ok(())
+        }
+    }
+
+    fn receive(input: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let mut telnet = Telnet::new(Remote {
+            input: Cursor::new(input.to_vec()),
+            output: vec![],
+        });
+        let mut received = vec![];
+        telnet.read_to_end(&mut received).unwrap();
+        (received, telnet.stream.output)
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(receive(b"Alice\n"), (b"Alice\n".to_vec(), vec![]));
+        assert_eq!(receive(b"Alice\r\n3\r\0"), (b"Alice\n3\n".to_vec(), vec![]));
+        assert_eq!(receive(b"1\r2\n"), (b"1\n2\n".to_vec(), vec![]));
+    }
+
+    #[test]
+    fn test_negotiation() {
+        let offers = [
+            IAC,
+            WILL,
+            SUPPRESS_GO_AHEAD,
+            IAC,
+            WILL,
+            CHARSET,
+            IAC,
+            DO,
+            NAWS,
+        ];
+
+        let (received, replies) = receive(&[IAC, DO, ECHO, b'4', b'\r', b'\n']);
+        assert_eq!(received, b"4\n");
+        assert_eq!(replies, [&offers[..], &[IAC, WONT, ECHO]].concat());
+
+        // Agreeing to what was offered needs no answer.
+        let (_, replies) = receive(&[IAC, WILL, NAWS, IAC, DO, SUPPRESS_GO_AHEAD]);
+        assert_eq!(replies, offers);
+
+        let (received, _) = receive(&[b'8', IAC, SB, NAWS, 0, 80, 0, 24, IAC, SE, b'\n']);
+        assert_eq!(received, b"8\n");
+    }
+
+    #[test]
+    fn test_charset() {
+        let (_, replies) = receive(&[IAC, DO, CHARSET]);
+        assert!(replies.ends_with(&[
+            IAC,
+            SB,
+            CHARSET,
+            CHARSET_REQUEST,
+            b';',
+            b'U',
+            b'T',
+            b'F',
+            b'-',
+            b'8',
+            IAC,
+            SE
+        ]));
+
+        let request = [
+            &[IAC, SB, CHARSET, CHARSET_REQUEST, b' '],
+            &b"ISO-8859-1 utf-8"[..],
+            &[IAC, SE],
+        ]
+        .concat();
+        let (_, replies) = receive(&request);
+        assert!(replies.ends_with(&[
+            IAC,
+            SB,
+            CHARSET,
+            CHARSET_ACCEPTED,
+            b'U',
+            b'T',
+            b'F',
+            b'-',
+            b'8',
+            IAC,
+            SE
+        ]));
+    }
+
+    #[test]
+    fn test_line_editing() {
+        let (received, _) = receive(&[b'A', b'x', IAC, EC, b'l', IAC, EL, b'5', b'\n']);
+        assert_eq!(edit_line(std::str::from_utf8(&received).unwrap()), "5\n");
+        assert_eq!(edit_line("Bpb\u{7f}\u{7f}ob\n"), "Bob\n");
+        assert_eq!(edit_line("Åå\u{8}\n"), "Å\n");
+    }
+
+    #[test]
+    fn test_crlf() {
+        assert_eq!(crlf("Bob: 2\nÅsa: 1\n"), "Bob: 2\r\nÅsa: 1\r\n");
+        assert_eq!(
+            crlf("Please input player name: "),
+            "Please input player name: "
+        );
+    }
+}
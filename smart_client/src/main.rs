@@ -1,15 +1,15 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap},
     io::{Error as IoError, ErrorKind, Result as IoResult},
     net::IpAddr,
     result::Result as StdResult,
 };
 
 use clap::Parser;
-use playing_cards::structs::Card;
+use playing_cards::structs::{Card, Rank};
 use protocol::{
-    message::Message,
-    structs::{PlayerName, PublicState},
+    message::{ClientMessage, Message, PROTOCOL_VERSION},
+    structs::{Capability, Encoding, PlayerName, PublicState},
 };
 use rand::{distributions::Alphanumeric, Rng};
 use tokio::{
@@ -18,6 +18,8 @@ use tokio::{
     task::JoinError,
 };
 
+// The fields are only read through `Debug` when `main` returns an error.
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Error {
     Connect(IoError),
@@ -47,27 +49,36 @@ async fn main() -> Result<()> {
     socket.set_nodelay(true).expect("set_nodelay failed");
 
     if args.ai {
-        let name: String = rand::thread_rng()
-            .sample_iter(&Alphanumeric)
-            .take(7)
-            .map(char::from)
-            .map(|c| c.to_uppercase().to_string())
-            .collect::<String>();
+        let mut name = random_name();
+
+        let hello = ClientMessage::Hello {
+            version: PROTOCOL_VERSION,
+            encoding: Encoding::Json,
+            capabilities: vec![Capability::Rejections, Capability::LegalActions],
+        };
+        send_to_remote(&mut socket, serde_json::to_string(&hello).unwrap() + "\n")
+            .await
+            .unwrap();
+
         let mut guess_achieved = false;
         let mut last_scoreboard: Option<HashMap<PlayerName, PublicState>> = None;
         loop {
             let server_message = readline_from_remote(&mut socket).await.unwrap();
 
             for line in server_message.lines() {
-                let message: Message = serde_json::from_str(line).unwrap();
+                // The name prompt is sent as text before the handshake is answered.
+                let Ok(message) = serde_json::from_str::<Message>(line) else {
+                    continue;
+                };
                 match message {
                     Message::RequestGuessContext {
                         player: _,
                         hand,
-                        guesses,
-                        players,
+                        guesses: _,
+                        players: _,
+                        legal_guesses,
                     } => {
-                        let guess = make_guess(players, hand, guesses);
+                        let guess = make_guess(hand, legal_guesses.unwrap());
                         send_to_remote(&mut socket, guess.to_string() + "\n")
                             .await
                             .unwrap();
@@ -78,7 +89,7 @@ async fn main() -> Result<()> {
                         trick: _,
                         valid_cards,
                     } => {
-                        let play = make_play(hand, valid_cards, guess_achieved);
+                        let play = make_play(hand, valid_cards.unwrap(), guess_achieved);
                         send_to_remote(&mut socket, play.to_string() + "\n")
                             .await
                             .unwrap();
@@ -92,7 +103,7 @@ async fn main() -> Result<()> {
                         players: _,
                         winner_indices: _,
                     } => {
-                        println!("{}", message.to_string());
+                        println!("{message}");
                         guess_achieved = false;
                         print!("Scores: ");
                         for (name, state) in last_scoreboard.clone().unwrap().iter() {
@@ -103,6 +114,22 @@ async fn main() -> Result<()> {
                     Message::GameOver => {
                         return Ok(());
                     }
+                    // Picks another name, the server asks again right away.
+                    Message::NameRejected(_) => name = random_name(),
+                    // The game starts once everyone at a full table is ready.
+                    Message::Lobby { players, seats: _ } => {
+                        let waiting = players
+                            .iter()
+                            .any(|player| player.name.as_str() == name && !player.ready);
+                        if waiting {
+                            let ready = serde_json::to_string(&ClientMessage::Ready(true)).unwrap();
+                            send_to_remote(&mut socket, ready + "\n").await.unwrap();
+                        }
+                    }
+                    Message::HandshakeRejected(_) => {
+                        println!("{message}");
+                        return Ok(());
+                    }
                     Message::Scoreboard { state } => {
                         let my_state = state.get(&PlayerName(name.clone())).unwrap();
                         if my_state.guess.unwrap() == my_state.wins {
@@ -134,36 +161,34 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-fn make_play(hand: Vec<Card>, valid_cards: Option<HashSet<usize>>, guess_achieved: bool) -> usize {
-    valid_cards.map_or_else(
-        || {
-            if guess_achieved {
-                hand.iter()
-                    .position(|&card| card == *hand.iter().min().unwrap())
-                    .unwrap()
-            } else {
-                hand.iter()
-                    .position(|&card| card == *hand.iter().max().unwrap())
-                    .unwrap()
-            }
-        },
-        |choices| {
-            if guess_achieved {
-                *choices.iter().min().unwrap()
-            } else {
-                *choices.iter().max().unwrap()
-            }
-        },
-    )
+fn random_name() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(7)
+        .map(char::from)
+        .map(|c| c.to_uppercase().to_string())
+        .collect::<String>()
 }
 
-fn make_guess(players: usize, hand: Vec<Card>, guesses: Vec<usize>) -> usize {
-    let guess = hand.iter().filter(|x| x.value >= 10).count();
-    if guesses.len() == players - 1 && guess + guesses.iter().sum::<usize>() == hand.len() {
-        return if guess != 0 { guess - 1 } else { guess + 1 };
+fn make_play(hand: Vec<Card>, valid_cards: BTreeSet<usize>, guess_achieved: bool) -> usize {
+    let choices = valid_cards.into_iter();
+
+    if guess_achieved {
+        choices.min_by_key(|index| hand[*index]).unwrap()
+    } else {
+        choices.max_by_key(|index| hand[*index]).unwrap()
     }
+}
 
-    guess
+fn make_guess(hand: Vec<Card>, legal_guesses: BTreeSet<usize>) -> usize {
+    let guess = hand
+        .iter()
+        .filter(|x| x.value().is_none_or(|value| value >= Rank::Queen))
+        .count();
+    legal_guesses
+        .into_iter()
+        .min_by_key(|legal| legal.abs_diff(guess))
+        .unwrap()
 }
 
 async fn send_to_remote(socket: &mut TcpStream, text: String) -> IoResult<()> {
@@ -200,6 +225,6 @@ async fn readline_from_remote(socket: &mut TcpStream) -> IoResult<String> {
             continue;
         }
 
-        return String::from_utf8(all).map_err(|_| IoError::new(ErrorKind::Other, "invalid UTF-8"));
+        return String::from_utf8(all).map_err(|_| IoError::other("invalid UTF-8"));
     }
 }